@@ -0,0 +1,371 @@
+use crate::color::Color;
+use crate::colored_property::ColoredProperty;
+use crate::movement::{Movement, MovementMode};
+use crate::piece_definition::PieceDefinition;
+use crate::vector::Vector;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BetzaError {
+    Empty,
+    UnknownAtom(char),
+    UnknownModifier(char),
+    UnexpectedChar(char),
+    // Modifiers at the end of the notation, which are not followed by an atom
+    DanglingModifiers(String),
+}
+
+impl Display for BetzaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Betza notation is empty"),
+            Self::UnknownAtom(atom) => write!(f, "Unknown Betza atom: {}", atom),
+            Self::UnknownModifier(modifier) => write!(f, "Unknown Betza modifier: {}", modifier),
+            Self::UnexpectedChar(char) => write!(f, "Unexpected char in Betza notation: {}", char),
+            Self::DanglingModifiers(modifiers) => {
+                write!(
+                    f,
+                    "Betza modifiers are not followed by an atom: {}",
+                    modifiers
+                )
+            }
+        }
+    }
+}
+
+// Parses Betza notation(e.g. "NB" for the archbishop or "fmWfcF" for the pawn without its
+// initial double step) into movements of the white piece. Supported atoms are W, F, N, D, A, H,
+// C, Z and G along with R, B, Q and K shorthands. Doubled atom(e.g. "NN") makes a rider out of a
+// leaper. Atoms can be prefixed with m(move only), c(capture only), n(lame leap), p(hop over a
+// screen, e.g. "mRcpR" for the xiangqi cannon) and f, b, l, r, v, s directional modifiers.
+pub fn parse(notation: &str) -> Result<Vec<Movement>, BetzaError> {
+    if notation.is_empty() {
+        return Err(BetzaError::Empty);
+    }
+    let chars = notation.chars().collect::<Vec<_>>();
+    let mut movements: Vec<Movement> = vec![];
+    let mut modifiers = vec![];
+    let mut idx = 0;
+    while idx < chars.len() {
+        let char = chars[idx];
+        idx += 1;
+        if char.is_ascii_lowercase() {
+            if !"mcnpfblrvs".contains(char) {
+                return Err(BetzaError::UnknownModifier(char));
+            }
+            modifiers.push(char);
+            continue;
+        }
+        if !char.is_ascii_uppercase() {
+            return Err(BetzaError::UnexpectedChar(char));
+        }
+        let doubled = chars.get(idx) == Some(&char);
+        if doubled {
+            idx += 1;
+        }
+        for movement in atom_movements(char, doubled, &modifiers)? {
+            if !movements.contains(&movement) {
+                movements.push(movement);
+            }
+        }
+        modifiers.clear();
+    }
+    if !modifiers.is_empty() {
+        return Err(BetzaError::DanglingModifiers(
+            modifiers.into_iter().collect(),
+        ));
+    }
+    Ok(movements)
+}
+
+fn atom_movements(
+    atom: char,
+    doubled: bool,
+    modifiers: &[char],
+) -> Result<Vec<Movement>, BetzaError> {
+    let (vectors, rides) = match atom {
+        'W' => (Vector::line_vectors(), doubled),
+        'F' => (Vector::diagonal_vectors(), doubled),
+        'N' => (Vector::jump_vectors(), doubled),
+        'K' => (Vector::diagonal_and_line_vectors(), doubled),
+        'D' => (Vector::leap_vectors(0, 2), doubled),
+        'A' => (Vector::leap_vectors(2, 2), doubled),
+        'H' => (Vector::leap_vectors(0, 3), doubled),
+        'C' => (Vector::leap_vectors(1, 3), doubled),
+        'Z' => (Vector::leap_vectors(2, 3), doubled),
+        'G' => (Vector::leap_vectors(3, 3), doubled),
+        'R' => (Vector::line_vectors(), true),
+        'B' => (Vector::diagonal_vectors(), true),
+        'Q' => (Vector::diagonal_and_line_vectors(), true),
+        _ => return Err(BetzaError::UnknownAtom(atom)),
+    };
+    let mode = match (modifiers.contains(&'m'), modifiers.contains(&'c')) {
+        (true, false) => MovementMode::MoveOnly,
+        (false, true) => MovementMode::CaptureOnly,
+        _ => MovementMode::MoveAndCapture,
+    };
+    let lame = modifiers.contains(&'n');
+    let hops = modifiers.contains(&'p');
+    let directions = direction_groups(modifiers);
+    Ok(vectors
+        .iter()
+        .filter(|vector| {
+            directions.is_empty()
+                || directions
+                    .iter()
+                    .any(|group| group_matches(group, vector, &vectors))
+        })
+        .map(|vector| {
+            let movement = Movement::new(*vector, rides, mode, lame);
+            if hops { movement.hopping() } else { movement }
+        })
+        .collect())
+}
+
+// Directional modifiers, split into groups. Directions of the same group intersect(e.g. "fl" is
+// forward-left), while the groups themselves are united(e.g. "fb" is forward and backward).
+// Doubled modifier(e.g. "ff") selects the narrow directions of the oblique atom.
+fn direction_groups(modifiers: &[char]) -> Vec<Vec<(char, bool)>> {
+    let directions = modifiers
+        .iter()
+        .filter(|modifier| "fblrvs".contains(**modifier))
+        .copied()
+        .collect::<Vec<_>>();
+    let mut groups = vec![];
+    let mut idx = 0;
+    let mut group: Vec<(char, bool)> = vec![];
+    while idx < directions.len() {
+        let direction = directions[idx];
+        let narrow = directions.get(idx + 1) == Some(&direction);
+        idx += if narrow { 2 } else { 1 };
+        let is_horizontal = "lrs".contains(direction);
+        // Vertical direction, followed by the horizontal one, forms a single group with it
+        let continues_group = is_horizontal && group.len() == 1 && "fbv".contains(group[0].0);
+        if !continues_group && !group.is_empty() {
+            groups.push(group);
+            group = vec![];
+        }
+        group.push((direction, narrow));
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+    groups
+}
+
+fn group_matches(group: &[(char, bool)], vector: &Vector, atom_vectors: &[Vector]) -> bool {
+    let intersects = |vector: &Vector| {
+        group
+            .iter()
+            .all(|(direction, narrow)| direction_matches(*direction, *narrow, vector))
+    };
+    // Intersection, which leaves nothing to the atom(e.g. "fs" of W), means the union of the
+    // directions instead
+    if atom_vectors.iter().any(intersects) {
+        intersects(vector)
+    } else {
+        group
+            .iter()
+            .any(|(direction, narrow)| direction_matches(*direction, *narrow, vector))
+    }
+}
+
+fn direction_matches(direction: char, narrow: bool, vector: &Vector) -> bool {
+    let (dx, dy) = vector.delta();
+    let vertical = dy.abs() > dx.abs();
+    let horizontal = dx.abs() > dy.abs();
+    match direction {
+        'f' => dy > 0 && (!narrow || vertical),
+        'b' => dy < 0 && (!narrow || vertical),
+        'l' => dx < 0 && (!narrow || horizontal),
+        'r' => dx > 0 && (!narrow || horizontal),
+        'v' => vertical,
+        's' => horizontal,
+        _ => false,
+    }
+}
+
+// A piece, defined by Betza notation
+#[derive(Debug, Clone)]
+pub struct BetzaPiece {
+    name: String,
+    symbols: ColoredProperty<char>,
+    value: i16,
+    movements: Vec<Movement>,
+}
+
+impl BetzaPiece {
+    // Directions of the notation are relative to the white side. Other sides turn them towards
+    // their own orientation.
+    pub fn new(
+        name: &str,
+        symbols: ColoredProperty<char>,
+        value: i16,
+        notation: &str,
+    ) -> Result<Self, BetzaError> {
+        Ok(Self {
+            name: name.to_string(),
+            symbols,
+            value,
+            movements: parse(notation)?,
+        })
+    }
+}
+
+impl PieceDefinition for BetzaPiece {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn symbol(&self, color: &Color) -> char {
+        self.symbols[&color.appearance()]
+    }
+
+    fn value(&self) -> i16 {
+        self.value
+    }
+
+    fn movements(&self, color: &Color) -> Vec<Movement> {
+        self.movements
+            .iter()
+            .map(|movement| movement.oriented(color.orientation()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point;
+    use crate::vector::diagonal_vector::DiagonalVector;
+    use crate::vector::jump_vector::JumpVector;
+    use crate::vector::leap_vector::LeapVector;
+    use crate::vector::line_vector::LineVector;
+
+    #[test]
+    fn test_parse_leaper() {
+        let movements = parse("N").unwrap();
+        assert_eq!(movements.len(), 8);
+        assert!(movements.iter().all(|movement| !movement.rides()));
+    }
+
+    #[test]
+    fn test_parse_doubled_atom_as_rider() {
+        let movements = parse("NN").unwrap();
+        assert_eq!(movements.len(), 8);
+        assert!(movements.iter().all(|movement| movement.rides()));
+    }
+
+    #[test]
+    fn test_parse_oblique_leapers() {
+        assert_eq!(parse("C").unwrap().len(), 8);
+        assert_eq!(parse("Z").unwrap().len(), 8);
+        assert_eq!(parse("D").unwrap().len(), 4);
+        assert_eq!(
+            parse("ffC").unwrap(),
+            vec![
+                Movement::leap(Vector::Leap(LeapVector::new(-1, 3))),
+                Movement::leap(Vector::Leap(LeapVector::new(1, 3))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_compound_piece() {
+        assert_eq!(parse("BR").unwrap(), parse("Q").unwrap());
+        assert_eq!(parse("FW").unwrap(), parse("K").unwrap());
+    }
+
+    #[test]
+    fn test_parse_move_and_capture_modifiers() {
+        assert_eq!(
+            parse("fmWfcF").unwrap(),
+            vec![
+                Movement::new(
+                    Vector::Line(LineVector::Top),
+                    false,
+                    MovementMode::MoveOnly,
+                    false
+                ),
+                Movement::new(
+                    Vector::Diagonal(DiagonalVector::TopLeft),
+                    false,
+                    MovementMode::CaptureOnly,
+                    false
+                ),
+                Movement::new(
+                    Vector::Diagonal(DiagonalVector::TopRight),
+                    false,
+                    MovementMode::CaptureOnly,
+                    false
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_quadrant() {
+        assert_eq!(
+            parse("flF").unwrap(),
+            vec![Movement::leap(Vector::Diagonal(DiagonalVector::TopLeft))]
+        );
+    }
+
+    #[test]
+    fn test_parse_narrow_directions() {
+        assert_eq!(
+            parse("ffN").unwrap(),
+            vec![
+                Movement::leap(Vector::Jump(JumpVector::TopLeftRight)),
+                Movement::leap(Vector::Jump(JumpVector::TopRightLeft)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_hopper() {
+        let movements = parse("mRcpR").unwrap();
+        assert_eq!(movements.len(), 8);
+        assert!(movements.iter().all(|movement| movement.rides()));
+        assert_eq!(
+            movements.iter().filter(|movement| movement.hops()).count(),
+            4
+        );
+        assert!(
+            movements
+                .iter()
+                .filter(|movement| movement.hops())
+                .all(|movement| !movement.can_move())
+        );
+    }
+
+    #[test]
+    fn test_lame_diagonal_leap_leg() {
+        let movement = parse("nA").unwrap()[0];
+        let from = Point::new(5, 5);
+        let (dx, dy) = movement.vector().delta();
+        assert_eq!(
+            movement.leg(&from),
+            Some(Point::new(5 + dx.signum(), 5 + dy.signum()))
+        );
+    }
+
+    #[test]
+    fn test_parse_union_of_directions() {
+        assert_eq!(parse("fsW").unwrap().len(), 3);
+        assert_eq!(parse("fbN").unwrap().len(), 8);
+        assert_eq!(parse("sN").unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse(""), Err(BetzaError::Empty));
+        assert_eq!(parse("X"), Err(BetzaError::UnknownAtom('X')));
+        assert_eq!(parse("xN"), Err(BetzaError::UnknownModifier('x')));
+        assert_eq!(parse("N2"), Err(BetzaError::UnexpectedChar('2')));
+        assert_eq!(
+            parse("Nfm"),
+            Err(BetzaError::DanglingModifiers("fm".to_string()))
+        );
+    }
+}
@@ -0,0 +1,734 @@
+pub mod attacks;
+
+use crate::board::Board;
+use crate::board_config::BoardConfig;
+use crate::board_map::BoardMap;
+use crate::board_stats::BoardStats;
+use crate::board_summary::BoardSummary;
+use crate::buff::Buff;
+use crate::buffs_map::BuffsMap;
+use crate::castle_points::CastlePoints;
+use crate::collections::HashMap;
+use crate::color::Color;
+use crate::colored_property::ColoredProperty;
+use crate::debuff::Debuff;
+use crate::debuffs_map::DebuffsMap;
+use crate::dimension::Dimension;
+use crate::game_board::GameBoard;
+use crate::heat_map::HeatMap;
+use crate::ids_generator::IdsGenerator;
+use crate::move_score::MoveScore;
+use crate::piece::Piece;
+use crate::piece_id::PieceId;
+use crate::piece_move::PieceMove;
+use crate::point::Point;
+use crate::squares_map::SquaresMap;
+use crate::utils::pretty_print::PrettyPrint;
+use crate::variant::Variant;
+use attacks::{KING_ATTACKS, KNIGHT_ATTACKS, PAWN_ATTACKS, between, bishop_attacks, rook_attacks};
+use rustc_hash::FxBuildHasher;
+
+const PAWN: usize = 0;
+const KNIGHT: usize = 1;
+const BISHOP: usize = 2;
+const ROOK: usize = 3;
+const QUEEN: usize = 4;
+const KING: usize = 5;
+
+// Board of classic 8x8 dimension, which generates moves with bitboards instead of incremental
+// strategy points. It implements the same GameBoard interface as Board, uses the same pieces ids,
+// stats and Zobrist keys, thus both boards can be cross-checked against each other.
+#[derive(Clone)]
+pub struct BitBoard<HT: HeatMap, SQ: SquaresMap> {
+    board_map: BoardMap,
+    // Squares occupied by the pieces of the given color, one bitboard per piece kind
+    pieces: ColoredProperty<[u64; 6]>,
+    occupancy: ColoredProperty<u64>,
+    pawns_with_en_passant: ColoredProperty<Vec<PieceId>>,
+    ids_generator: ColoredProperty<IdsGenerator>,
+    debuffs_map: ColoredProperty<DebuffsMap>,
+    buffs_map: ColoredProperty<BuffsMap>,
+    current_turn: Color,
+    // Determines board's point of view. Debugging purpose only.
+    pov: Color,
+    config: BoardConfig<HT, SQ>,
+    board_summary: BoardSummary,
+}
+
+impl<HT: HeatMap, SQ: SquaresMap> BitBoard<HT, SQ> {
+    pub fn empty(config: BoardConfig<HT, SQ>) -> Self {
+        let dimension = config.dimension();
+        if *dimension.min_point() != Point::new(1, 1) || *dimension.max_point() != Point::new(8, 8)
+        {
+            panic!("BitBoard only supports 8x8 dimension!");
+        }
+        if config.is_ride_distance_limited() {
+            panic!("BitBoard does not support limited ride distance!");
+        }
+        if !config.has_classic_royals() {
+            panic!("BitBoard only supports the king as the royal piece!");
+        }
+        if config.has_drops() {
+            panic!("BitBoard does not support piece drops!");
+        }
+        if config.variant() != &Variant::Classic {
+            panic!("BitBoard only supports the classic variant!");
+        }
+        if config.has_promotions() {
+            panic!("BitBoard does not support promotions of pieces other than pawns!");
+        }
+        if config.has_piece_regions() || config.is_flying_general() {
+            panic!("BitBoard does not support board regions nor the flying general rule!");
+        }
+        if config.has_optional_pawn_promotions() || config.has_promotion_limits() {
+            panic!("BitBoard does not support optional nor limited pawn promotions!");
+        }
+        let mut board = Self {
+            board_map: BoardMap::empty(2),
+            pieces: ColoredProperty::from([[0; 6], [0; 6]]),
+            occupancy: ColoredProperty::from([0, 0]),
+            pawns_with_en_passant: ColoredProperty::from([vec![], vec![]]),
+            ids_generator: ColoredProperty::from([IdsGenerator::init(), IdsGenerator::init()]),
+            debuffs_map: ColoredProperty::from([DebuffsMap::empty(), DebuffsMap::empty()]),
+            buffs_map: ColoredProperty::from([BuffsMap::empty(), BuffsMap::empty()]),
+            current_turn: Color::White,
+            pov: Color::White,
+            config,
+            board_summary: BoardSummary::new(2),
+        };
+        for y in board.dimension().get_rows_range() {
+            for x in board.dimension().get_columns_range() {
+                let point = Point::new(x, y);
+                if let Some(square) = board.config.squares_map().square(&point) {
+                    board.board_map.add_square(point, square);
+                }
+            }
+        }
+        board
+    }
+
+    pub fn config(&self) -> &BoardConfig<HT, SQ> {
+        &self.config
+    }
+
+    pub fn dimension(&self) -> &Dimension {
+        self.config.dimension()
+    }
+
+    pub fn active_pieces(&self, color: &Color) -> &HashMap<PieceId, Piece, FxBuildHasher> {
+        self.board_map.active_pieces(color)
+    }
+
+    pub fn find_piece_by_id(&self, piece_id: &PieceId) -> Option<&Piece> {
+        self.board_map.maybe_find_piece_by_id(piece_id)
+    }
+
+    pub fn piece_at(&self, point: &Point) -> Option<&Piece> {
+        self.board_map.piece_at(point)
+    }
+
+    pub fn piece_id_at(&self, point: &Point) -> Option<&PieceId> {
+        self.board_map.piece_id_at(point)
+    }
+
+    pub fn king(&self, color: &Color) -> Option<&Piece> {
+        self.board_map.king(color)
+    }
+
+    pub fn current_turn(&self) -> &Color {
+        &self.current_turn
+    }
+
+    pub fn pass_turn(&mut self, color: &Color) {
+        self.current_turn = *color;
+    }
+
+    pub fn set_pov(&mut self, color: Color) {
+        self.pov = color;
+    }
+
+    pub fn stats(&'_ self) -> BoardStats<'_> {
+        self.board_summary.stats()
+    }
+
+    pub fn buffs(&self, color: &Color) -> &BuffsMap {
+        &self.buffs_map[color]
+    }
+
+    pub fn debuffs(&self, color: &Color) -> &DebuffsMap {
+        &self.debuffs_map[color]
+    }
+
+    pub fn add_piece(
+        &mut self,
+        name: &str,
+        color: Color,
+        buffs: Vec<Buff>,
+        debuffs: Vec<Debuff>,
+        position: Point,
+    ) -> PieceId {
+        if !self.board_map.board_square(&position).is_empty_square() {
+            panic!(
+                "Can't add {} piece. Position {:?} is not empty!",
+                name, position
+            )
+        }
+        let id = self.ids_generator[&color].next_val(&color);
+        let piece = self
+            .config
+            .piece_registry()
+            .init_piece(name, color, position, id);
+        if let Piece::Custom(_) = piece {
+            panic!("BitBoard only supports classic pieces, got {}!", name);
+        }
+        let buffs_map = &self.buffs_map[piece.color()];
+        self.board_summary.add_piece(
+            &piece,
+            buffs_map.has_castle(piece.id()),
+            buffs_map.has_en_passant(piece.id()),
+        );
+        if let Piece::King(_) = piece {
+            self.board_map.set_king(&color, Some(id));
+        }
+        self.place(&piece, Self::square_index(&position));
+        self.board_map.add_piece(piece, position);
+        self.buffs_map[&color].add_from_vec(&id, buffs);
+        self.debuffs_map[&color].add_from_vec(&id, debuffs);
+        id
+    }
+
+    pub fn is_checked(&self, color: &Color) -> bool {
+        match self.king_square(color) {
+            Some(king_square) => Self::is_attacked(
+                king_square,
+                color,
+                &self.pieces[&color.inverse()],
+                self.all_occupancy(),
+            ),
+            None => false,
+        }
+    }
+
+    pub fn has_no_moves(&self, color: &Color) -> bool {
+        let mut has_moves = false;
+        for piece in self.active_pieces(color).values() {
+            self.moves_of_piece(piece, |_| has_moves = true);
+            if has_moves {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub fn legal_moves(&self, color: &Color) -> Vec<(PieceId, PieceMove)> {
+        let mut moves = vec![];
+        for piece in self.active_pieces(color).values() {
+            self.moves_of_piece(piece, |piece_move| moves.push((*piece.id(), piece_move)));
+        }
+        moves
+    }
+
+    pub fn moves_of(&self, piece_id: &PieceId) -> Vec<PieceMove> {
+        let mut moves = vec![];
+        if let Some(piece) = self.find_piece_by_id(piece_id) {
+            self.moves_of_piece(piece, |piece_move| moves.push(piece_move));
+        }
+        moves
+    }
+
+    pub fn move_piece(&mut self, piece_id: &PieceId, piece_move: &PieceMove) -> Option<MoveScore> {
+        if self.current_turn != piece_id.color() {
+            return None;
+        }
+        if !self.moves_of(piece_id).contains(piece_move) {
+            return None;
+        }
+        let move_score = Board::evaluate_move(
+            &self.config,
+            &self.board_map,
+            piece_move,
+            self.board_map.find_piece_by_id(piece_id),
+        );
+        self.move_piece_unchecked(piece_id, piece_move);
+        let enemy_color = piece_id.color().inverse();
+        self.pass_turn(&enemy_color);
+        self.board_summary.next_turn(&piece_id.color(), &enemy_color);
+        Some(move_score)
+    }
+
+    fn move_piece_unchecked(&mut self, piece_id: &PieceId, piece_move: &PieceMove) {
+        self.clear_en_passant();
+        let piece = self.board_map.find_piece_by_id(piece_id);
+        match piece {
+            Piece::King(_) | Piece::Rook(_)
+                if self.buffs_map[piece.color()].has_castle(piece.id()) =>
+            {
+                self.buffs_map[&piece_id.color()].remove_castle(piece_id);
+                self.board_summary.update_piece_castle(piece_id, false);
+            }
+            Piece::Pawn(_) => {
+                self.board_summary.pawn_moved();
+                self.buffs_map[&piece_id.color()].remove_additional_point(piece_id);
+            }
+            _ => (),
+        }
+
+        match piece_move {
+            PieceMove::Point(new_position) => {
+                self.capture_at(new_position);
+                self.relocate(piece_id, new_position);
+            }
+            PieceMove::LongMove(new_position) => {
+                let initial_position = *piece.current_position();
+                self.relocate(piece_id, new_position);
+                self.calc_en_passant(&initial_position, new_position, &piece_id.color());
+            }
+            PieceMove::EnPassant(new_position, enemy_position) => {
+                self.capture_at(enemy_position);
+                self.relocate(piece_id, new_position);
+            }
+            PieceMove::Castle(castle_points) => {
+                let &rook_id = self
+                    .piece_id_at(castle_points.initial_rook_point())
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Logical mistake: expect the rook to be present at {} position during the castle move.",
+                            castle_points.initial_rook_point()
+                        )
+                    });
+                if self.buffs_map[&rook_id.color()].has_castle(&rook_id) {
+                    self.buffs_map[&rook_id.color()].remove_castle(&rook_id);
+                    self.board_summary.update_piece_castle(&rook_id, false);
+                }
+                // Lift both pieces before placing any of them, because the king and the rook may
+                // land on the initial points of each other
+                let moves = [
+                    (*piece_id, *castle_points.king_point()),
+                    (rook_id, *castle_points.rook_point()),
+                ];
+                let pieces =
+                    moves.map(|(piece_id, _)| self.board_map.find_piece_by_id(&piece_id).clone());
+                for piece in pieces.iter() {
+                    self.lift(piece, Self::square_index(piece.current_position()));
+                }
+                self.board_map.change_pieces_positions(&moves);
+                for (piece, (piece_id, point)) in pieces.iter().zip(moves.iter()) {
+                    self.place(piece, Self::square_index(point));
+                    self.board_summary.update_piece_position(piece_id, point);
+                }
+            }
+            PieceMove::Promote(new_position, promote_piece) => {
+                let pawn = self.remove_piece(piece_id);
+                let promoted_piece_id = self.add_piece(
+                    &promote_piece.name(),
+                    piece_id.color(),
+                    vec![],
+                    vec![],
+                    *pawn.current_position(),
+                );
+                self.board_summary.piece_promoted();
+                self.capture_at(new_position);
+                self.relocate(&promoted_piece_id, new_position);
+            }
+            PieceMove::Drop(_) => {
+                panic!("Logical mistake: drops are not generated by BitBoard!");
+            }
+        }
+    }
+
+    fn relocate(&mut self, piece_id: &PieceId, new_position: &Point) {
+        let piece = self.board_map.find_piece_by_id(piece_id).clone();
+        if piece.current_position() == new_position {
+            return;
+        }
+        self.lift(&piece, Self::square_index(piece.current_position()));
+        self.place(&piece, Self::square_index(new_position));
+        self.board_map.change_piece_position(new_position, piece_id);
+        self.board_summary
+            .update_piece_position(piece_id, new_position);
+    }
+
+    fn capture_at(&mut self, position: &Point) {
+        if let Some(&piece_id) = self.piece_id_at(position) {
+            let piece = self.remove_piece(&piece_id);
+            self.board_summary.piece_captured(piece);
+        }
+    }
+
+    fn remove_piece(&mut self, piece_id: &PieceId) -> Piece {
+        let piece = self.board_map.remove_piece(piece_id);
+        self.lift(&piece, Self::square_index(piece.current_position()));
+        self.board_summary.remove_piece(&piece);
+        piece
+    }
+
+    fn calc_en_passant(
+        &mut self,
+        initial_position: &Point,
+        position: &Point,
+        caused_by_color: &Color,
+    ) {
+        let enemy_color = caused_by_color.inverse();
+        let x = *position.x().value();
+        let (from_y, to_y) = (*initial_position.y().value(), *position.y().value());
+        for y in (from_y.min(to_y) + 1)..from_y.max(to_y) {
+            let en_passant_position = Point::new(x, y);
+            let mut enemy_pawns = PAWN_ATTACKS[Self::color_index(caused_by_color)]
+                [Self::square_index(&en_passant_position)]
+                & self.pieces[&enemy_color][PAWN];
+            while enemy_pawns != 0 {
+                let square = enemy_pawns.trailing_zeros() as usize;
+                enemy_pawns &= enemy_pawns - 1;
+                let &pawn_id = self.piece_id_at(&Self::point_of(square)).unwrap();
+                self.buffs_map[&enemy_color]
+                    .add(&pawn_id, Buff::EnPassant(en_passant_position, *position));
+                self.board_summary.update_piece_en_passant(&pawn_id, true);
+                self.pawns_with_en_passant[&enemy_color].push(pawn_id);
+            }
+        }
+    }
+
+    fn clear_en_passant(&mut self) {
+        for color in [Color::White, Color::Black].iter() {
+            for pawn_id in self.pawns_with_en_passant[color].drain(..) {
+                self.buffs_map[color].remove_en_passant(&pawn_id);
+                self.board_summary.update_piece_en_passant(&pawn_id, false);
+            }
+        }
+    }
+
+    // Generates legal moves of the given piece
+    fn moves_of_piece<F: FnMut(PieceMove)>(&self, piece: &Piece, mut consumer: F) {
+        let color = piece.color();
+        let from = Self::square_index(piece.current_position());
+        let own = self.occupancy[color];
+        let enemy = self.occupancy[&color.inverse()];
+        let occupancy = own | enemy;
+        let add_targets = |targets: u64, consumer: &mut F| {
+            let mut targets = targets;
+            while targets != 0 {
+                let to = targets.trailing_zeros() as usize;
+                targets &= targets - 1;
+                if self.is_legal(color, from, to, Some(to)) {
+                    consumer(PieceMove::Point(Self::point_of(to)));
+                }
+            }
+        };
+
+        match piece {
+            Piece::Pawn(_) => self.pawn_moves(piece, from, occupancy, &mut consumer),
+            Piece::Knight(_) => add_targets(KNIGHT_ATTACKS[from] & !own, &mut consumer),
+            Piece::Bishop(_) => add_targets(bishop_attacks(from, occupancy) & !own, &mut consumer),
+            Piece::Rook(_) => add_targets(rook_attacks(from, occupancy) & !own, &mut consumer),
+            Piece::Queen(_) => add_targets(
+                (bishop_attacks(from, occupancy) | rook_attacks(from, occupancy)) & !own,
+                &mut consumer,
+            ),
+            Piece::King(_) => {
+                add_targets(KING_ATTACKS[from] & !own, &mut consumer);
+                self.castle_moves(piece, from, occupancy, &mut consumer);
+            }
+            Piece::Custom(_) | Piece::UnknownPiece(_) => {
+                panic!("{} piece can't be placed on the bitboard!", piece.name())
+            }
+        }
+    }
+
+    fn pawn_moves<F: FnMut(PieceMove)>(
+        &self,
+        piece: &Piece,
+        from: usize,
+        occupancy: u64,
+        consumer: &mut F,
+    ) {
+        let color = piece.color();
+        let enemy = self.occupancy[&color.inverse()];
+        let add_move = |to: usize, captured: Option<usize>, consumer: &mut F| {
+            if !self.is_legal(color, from, to, captured) {
+                return;
+            }
+            let point = Self::point_of(to);
+            if self.config.is_promotion_square(&point, color) {
+                for variant in self.config.promote_pieces() {
+                    consumer(PieceMove::Promote(point, *variant));
+                }
+            } else {
+                consumer(PieceMove::Point(point));
+            }
+        };
+
+        let forward = |square: usize| {
+            if *color == Color::White {
+                square.checked_add(8).filter(|square| *square < 64)
+            } else {
+                square.checked_sub(8)
+            }
+        };
+        if let Some(one_step) = forward(from)
+            && occupancy & (1 << one_step) == 0
+        {
+            add_move(one_step, None, consumer);
+            if self.buffs_map[color].has_additional_point(piece.id()) {
+                let mut square = one_step;
+                for _ in 1..self.config.pawn_initial_steps() {
+                    match forward(square) {
+                        Some(next_square) if occupancy & (1 << next_square) == 0 => {
+                            square = next_square
+                        }
+                        _ => break,
+                    }
+                    if self.is_legal(color, from, square, None) {
+                        consumer(PieceMove::LongMove(Self::point_of(square)));
+                    }
+                }
+            }
+        }
+
+        let mut captures = PAWN_ATTACKS[Self::color_index(color)][from] & enemy;
+        while captures != 0 {
+            let to = captures.trailing_zeros() as usize;
+            captures &= captures - 1;
+            add_move(to, Some(to), consumer);
+        }
+
+        if let Some(Buff::EnPassant(en_passant, enemy_position)) =
+            self.buffs_map[color].en_passant(piece.id())
+        {
+            let to = Self::square_index(en_passant);
+            if PAWN_ATTACKS[Self::color_index(color)][from] & (1 << to) != 0
+                && self.is_legal(color, from, to, Some(Self::square_index(enemy_position)))
+            {
+                consumer(PieceMove::EnPassant(*en_passant, *enemy_position));
+            }
+        }
+    }
+
+    fn castle_moves<F: FnMut(PieceMove)>(
+        &self,
+        king: &Piece,
+        from: usize,
+        occupancy: u64,
+        consumer: &mut F,
+    ) {
+        let color = king.color();
+        if !self.buffs_map[color].has_castle(king.id()) || self.is_checked(color) {
+            return;
+        }
+        let y = *king.current_position().y().value();
+        for rule in self.config.castle_rules() {
+            let (step, _) = rule.side().direction(color.orientation()).delta();
+            // The rook to castle with is the first piece met in the castle direction
+            let mut rook = None;
+            let mut x = *king.current_position().x().value() + step;
+            while (1..=8).contains(&x) {
+                if let Some(piece) = self.piece_at(&Point::new(x, y)) {
+                    if let Piece::Rook(_) = piece
+                        && !piece.is_enemy(color)
+                        && self.buffs_map[color].has_castle(piece.id())
+                        && (rule.non_corner_rook() || !(1..=8).contains(&(x + step)))
+                    {
+                        rook = Some(piece);
+                    }
+                    break;
+                }
+                x += step;
+            }
+            let Some(rook) = rook else {
+                continue;
+            };
+
+            let (king_point, rook_point) =
+                rule.castle_points(king.current_position(), color.orientation());
+            if !self.config.dimension().is_in_boundaries(&king_point)
+                || !self.config.dimension().is_in_boundaries(&rook_point)
+            {
+                continue;
+            }
+            let rook_from = Self::square_index(rook.current_position());
+            let king_to = Self::square_index(&king_point);
+            let rook_to = Self::square_index(&rook_point);
+            let others = occupancy & !(1 << from) & !(1 << rook_from);
+            let paths = between(from, king_to)
+                | (1 << king_to)
+                | between(rook_from, rook_to)
+                | (1 << rook_to);
+            if paths & others != 0 {
+                continue;
+            }
+            let mut king_path = between(from, king_to) | (1 << king_to);
+            let enemy_pieces = &self.pieces[&color.inverse()];
+            let occupancy_after = others | (1 << king_to) | (1 << rook_to);
+            let mut is_safe = !Self::is_attacked(king_to, color, enemy_pieces, occupancy_after);
+            while is_safe && king_path != 0 {
+                let square = king_path.trailing_zeros() as usize;
+                king_path &= king_path - 1;
+                is_safe = !Self::is_attacked(square, color, enemy_pieces, occupancy);
+            }
+            if is_safe {
+                consumer(PieceMove::Castle(CastlePoints::new(
+                    king_point,
+                    rook_point,
+                    *king.current_position(),
+                    *rook.current_position(),
+                )));
+            }
+        }
+    }
+
+    // Checks whether the king of the given color stays safe after the piece goes from one square
+    // to another, capturing an enemy piece on the captured square, if any
+    fn is_legal(&self, color: &Color, from: usize, to: usize, captured: Option<usize>) -> bool {
+        let Some(king_square) = self.king_square(color) else {
+            return true;
+        };
+        let king_square = if king_square == from { to } else { king_square };
+        let captured_mask = captured.map_or(0, |square| 1 << square);
+        let occupancy = (self.all_occupancy() & !(1 << from) & !captured_mask) | (1 << to);
+        let mut enemy_pieces = self.pieces[&color.inverse()];
+        for bitboard in enemy_pieces.iter_mut() {
+            *bitboard &= !captured_mask;
+        }
+        !Self::is_attacked(king_square, color, &enemy_pieces, occupancy)
+    }
+
+    fn is_attacked(square: usize, color: &Color, enemy_pieces: &[u64; 6], occupancy: u64) -> bool {
+        let diagonal_sliders = enemy_pieces[BISHOP] | enemy_pieces[QUEEN];
+        let line_sliders = enemy_pieces[ROOK] | enemy_pieces[QUEEN];
+        KNIGHT_ATTACKS[square] & enemy_pieces[KNIGHT] != 0
+            || KING_ATTACKS[square] & enemy_pieces[KING] != 0
+            || PAWN_ATTACKS[Self::color_index(color)][square] & enemy_pieces[PAWN] != 0
+            || bishop_attacks(square, occupancy) & diagonal_sliders != 0
+            || rook_attacks(square, occupancy) & line_sliders != 0
+    }
+
+    fn king_square(&self, color: &Color) -> Option<usize> {
+        let kings = self.pieces[color][KING];
+        if kings == 0 {
+            None
+        } else {
+            Some(kings.trailing_zeros() as usize)
+        }
+    }
+
+    fn all_occupancy(&self) -> u64 {
+        self.occupancy[&Color::White] | self.occupancy[&Color::Black]
+    }
+
+    fn place(&mut self, piece: &Piece, square: usize) {
+        self.pieces[piece.color()][Self::kind_index(piece)] |= 1 << square;
+        self.occupancy[piece.color()] |= 1 << square;
+    }
+
+    fn lift(&mut self, piece: &Piece, square: usize) {
+        self.pieces[piece.color()][Self::kind_index(piece)] &= !(1 << square);
+        self.occupancy[piece.color()] &= !(1 << square);
+    }
+
+    fn kind_index(piece: &Piece) -> usize {
+        match piece {
+            Piece::Pawn(_) => PAWN,
+            Piece::Knight(_) => KNIGHT,
+            Piece::Bishop(_) => BISHOP,
+            Piece::Rook(_) => ROOK,
+            Piece::Queen(_) => QUEEN,
+            Piece::King(_) => KING,
+            Piece::Custom(_) | Piece::UnknownPiece(_) => {
+                panic!("{} piece can't be placed on the bitboard!", piece.name())
+            }
+        }
+    }
+
+    fn color_index(color: &Color) -> usize {
+        color.index()
+    }
+
+    fn square_index(point: &Point) -> usize {
+        ((*point.y().value() - 1) * 8 + (*point.x().value() - 1)) as usize
+    }
+
+    fn point_of(square: usize) -> Point {
+        Point::new((square % 8) as i16 + 1, (square / 8) as i16 + 1)
+    }
+}
+
+impl<HT: HeatMap, SQ: SquaresMap> GameBoard<HT, SQ> for BitBoard<HT, SQ> {
+    fn empty(config: BoardConfig<HT, SQ>) -> Self {
+        BitBoard::empty(config)
+    }
+
+    fn config(&self) -> &BoardConfig<HT, SQ> {
+        BitBoard::config(self)
+    }
+
+    fn dimension(&self) -> &Dimension {
+        BitBoard::dimension(self)
+    }
+
+    fn add_piece(
+        &mut self,
+        name: &str,
+        color: Color,
+        buffs: Vec<Buff>,
+        debuffs: Vec<Debuff>,
+        position: Point,
+    ) -> PieceId {
+        BitBoard::add_piece(self, name, color, buffs, debuffs, position)
+    }
+
+    fn piece_id_at(&self, point: &Point) -> Option<&PieceId> {
+        BitBoard::piece_id_at(self, point)
+    }
+
+    fn active_pieces(&self, color: &Color) -> &HashMap<PieceId, Piece, FxBuildHasher> {
+        BitBoard::active_pieces(self, color)
+    }
+
+    fn king(&self, color: &Color) -> Option<&Piece> {
+        BitBoard::king(self, color)
+    }
+
+    fn is_checked(&self, color: &Color) -> bool {
+        BitBoard::is_checked(self, color)
+    }
+
+    // The king can't be captured on the BitBoard
+    fn is_out_of_royals(&self, _color: &Color) -> bool {
+        false
+    }
+
+    // The BitBoard does not count checks
+    fn is_check_limit_reached(&self, _color: &Color) -> bool {
+        false
+    }
+
+    fn is_king_on_the_hill(&self, color: &Color) -> bool {
+        BitBoard::king(self, color)
+            .is_some_and(|king| self.config().is_hill_square(king.current_position()))
+    }
+
+    fn current_turn(&self) -> &Color {
+        BitBoard::current_turn(self)
+    }
+
+    fn has_no_moves(&self, color: &Color) -> bool {
+        BitBoard::has_no_moves(self, color)
+    }
+
+    fn legal_moves(&self, color: &Color) -> Vec<(PieceId, PieceMove)> {
+        BitBoard::legal_moves(self, color)
+    }
+
+    fn move_piece(&mut self, piece_id: &PieceId, piece_move: &PieceMove) -> Option<MoveScore> {
+        BitBoard::move_piece(self, piece_id, piece_move)
+    }
+
+    fn stats(&self) -> BoardStats<'_> {
+        BitBoard::stats(self)
+    }
+}
+
+impl<HT: HeatMap, SQ: SquaresMap> PrettyPrint for BitBoard<HT, SQ> {
+    fn pp(&self) -> String {
+        self.board_map.pp(self.dimension(), &self.pov)
+    }
+}
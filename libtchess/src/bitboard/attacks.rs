@@ -0,0 +1,179 @@
+// Precomputed attack tables of the 8x8 board. Square index is (y - 1) * 8 + (x - 1), thus a1 is
+// 0 and h8 is 63. Sliding attacks are calculated with the classical approach: a ray is cut by
+// the first blocker on it, found by the lowest or the highest bit, depending on ray direction.
+// No magic numbers or PEXT instructions are involved.
+
+const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+const KING_OFFSETS: [(i8, i8); 8] = [
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+];
+// Rays which go to higher square indexes come first
+const POSITIVE_RAYS: [(i8, i8); 4] = [(0, 1), (1, 0), (1, 1), (-1, 1)];
+const NEGATIVE_RAYS: [(i8, i8); 4] = [(0, -1), (-1, 0), (-1, -1), (1, -1)];
+const LINE_RAYS: [usize; 4] = [0, 1, 4, 5];
+const DIAGONAL_RAYS: [usize; 4] = [2, 3, 6, 7];
+
+pub const KNIGHT_ATTACKS: [u64; 64] = jump_attacks(&KNIGHT_OFFSETS);
+pub const KING_ATTACKS: [u64; 64] = jump_attacks(&KING_OFFSETS);
+// Indexed by color: 0 - white, 1 - black
+pub const PAWN_ATTACKS: [[u64; 64]; 2] = [
+    jump_attacks(&[(-1, 1), (1, 1)]),
+    jump_attacks(&[(-1, -1), (1, -1)]),
+];
+const RAYS: [[u64; 64]; 8] = rays();
+
+const fn shifted_square(square: usize, offset: (i8, i8)) -> Option<usize> {
+    let x = (square % 8) as i8 + offset.0;
+    let y = (square / 8) as i8 + offset.1;
+    if x < 0 || x > 7 || y < 0 || y > 7 {
+        None
+    } else {
+        Some((y * 8 + x) as usize)
+    }
+}
+
+const fn jump_attacks(offsets: &[(i8, i8)]) -> [u64; 64] {
+    let mut attacks = [0; 64];
+    let mut square = 0;
+    while square < 64 {
+        let mut i = 0;
+        while i < offsets.len() {
+            if let Some(target) = shifted_square(square, offsets[i]) {
+                attacks[square] |= 1 << target;
+            }
+            i += 1;
+        }
+        square += 1;
+    }
+    attacks
+}
+
+const fn rays() -> [[u64; 64]; 8] {
+    let mut rays = [[0; 64]; 8];
+    let mut direction = 0;
+    while direction < 8 {
+        let offset = if direction < 4 {
+            POSITIVE_RAYS[direction]
+        } else {
+            NEGATIVE_RAYS[direction - 4]
+        };
+        let mut square = 0;
+        while square < 64 {
+            let mut current = square;
+            while let Some(target) = shifted_square(current, offset) {
+                rays[direction][square] |= 1 << target;
+                current = target;
+            }
+            square += 1;
+        }
+        direction += 1;
+    }
+    rays
+}
+
+fn ray_attacks(square: usize, occupancy: u64, direction: usize) -> u64 {
+    let ray = RAYS[direction][square];
+    let blockers = ray & occupancy;
+    if blockers == 0 {
+        return ray;
+    }
+    let blocker = if direction < 4 {
+        blockers.trailing_zeros() as usize
+    } else {
+        63 - blockers.leading_zeros() as usize
+    };
+    ray ^ RAYS[direction][blocker]
+}
+
+pub fn rook_attacks(square: usize, occupancy: u64) -> u64 {
+    LINE_RAYS.iter().fold(0, |attacks, direction| {
+        attacks | ray_attacks(square, occupancy, *direction)
+    })
+}
+
+pub fn bishop_attacks(square: usize, occupancy: u64) -> u64 {
+    DIAGONAL_RAYS.iter().fold(0, |attacks, direction| {
+        attacks | ray_attacks(square, occupancy, *direction)
+    })
+}
+
+// Squares between two squares, which are on the same line or diagonal. Empty otherwise.
+pub fn between(square1: usize, square2: usize) -> u64 {
+    for rays in RAYS.iter() {
+        if rays[square1] & (1 << square2) != 0 {
+            return rays[square1] & !rays[square2] & !(1 << square2);
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knight_attacks_from_the_corner() {
+        // a1 attacks b3 and c2
+        assert_eq!(KNIGHT_ATTACKS[0], (1 << 17) | (1 << 10));
+    }
+
+    #[test]
+    fn king_attacks_from_the_edge() {
+        // e1 attacks d1, f1, d2, e2, f2
+        assert_eq!(
+            KING_ATTACKS[4],
+            (1 << 3) | (1 << 5) | (1 << 11) | (1 << 12) | (1 << 13)
+        );
+    }
+
+    #[test]
+    fn pawn_attacks_do_not_wrap_around_the_board() {
+        // White pawn on h2 attacks g3 only
+        assert_eq!(PAWN_ATTACKS[0][15], 1 << 22);
+        // Black pawn on a7 attacks b6 only
+        assert_eq!(PAWN_ATTACKS[1][48], 1 << 41);
+    }
+
+    #[test]
+    fn rook_attacks_stop_at_blockers() {
+        // Rook on d4, blockers on d6 and b4
+        let occupancy = (1 << 43) | (1 << 25);
+        let expected = (1 << 35) | (1 << 43) // d5, d6
+            | (1 << 19) | (1 << 11) | (1 << 3) // d3, d2, d1
+            | (1 << 28) | (1 << 29) | (1 << 30) | (1 << 31) // e4..h4
+            | (1 << 26) | (1 << 25); // c4, b4
+        assert_eq!(rook_attacks(27, occupancy), expected);
+    }
+
+    #[test]
+    fn bishop_attacks_stop_at_blockers() {
+        // Bishop on c1, blocker on e3
+        let occupancy = 1 << 20;
+        let expected = (1 << 11) | (1 << 20) | (1 << 9) | (1 << 16);
+        assert_eq!(bishop_attacks(2, occupancy), expected);
+    }
+
+    #[test]
+    fn squares_between() {
+        // Between a1 and d4 are b2 and c3
+        assert_eq!(between(0, 27), (1 << 9) | (1 << 18));
+        // a1 and b3 are not on the same line
+        assert_eq!(between(0, 17), 0);
+    }
+}
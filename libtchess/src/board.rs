@@ -5,6 +5,7 @@ use crate::board_stats::BoardStats;
 use crate::board_summary::BoardSummary;
 use crate::buff::Buff;
 use crate::buffs_map::BuffsMap;
+use crate::collections::{HashMap, HashSet, OnceCell, OrdMap};
 use crate::color::Color;
 use crate::colored_property::ColoredProperty;
 use crate::debuff::Debuff;
@@ -17,16 +18,17 @@ use crate::moves_map::MovesMap;
 use crate::piece::Piece;
 use crate::piece_id::PieceId;
 use crate::piece_move::PieceMove;
+use crate::pocket::Pocket;
 use crate::point::Point;
+use crate::promote_piece::PromotePiece;
+use crate::royal_rule::RoyalRule;
 use crate::squares_map::SquaresMap;
 use crate::strategy_point::StrategyPoint;
 use crate::strategy_points::StrategyPoints;
 use crate::utils::pretty_print::PrettyPrint;
 use crate::vector::Vector;
-use crate::vector::line_vector::LineVector;
 use crate::vector_points::VectorPoints;
 use crate::x_ray_pieces::XRayPieces;
-use im_rc::{HashMap, HashSet, OrdMap};
 use rustc_hash::FxBuildHasher;
 
 // Invert colors of chess symbols so they look more meaningful in the terminal window with black
@@ -40,10 +42,22 @@ pub struct Board<HT: HeatMap, SQ: SquaresMap> {
     x_ray_pieces: ColoredProperty<XRayPieces>,
     pawns_with_en_passant: ColoredProperty<HashSet<PieceId, FxBuildHasher>>,
     moves_map: ColoredProperty<MovesMap>,
+    // Moves maps with all dirty pieces resolved. Filled on demand when lazy moves generation is
+    // enabled and dropped on the next change of the position.
+    resolved_moves: ColoredProperty<OnceCell<MovesMap>>,
     general_constraints: ColoredProperty<Option<MovesMap>>,
     ids_generator: ColoredProperty<IdsGenerator>,
     debuffs_map: ColoredProperty<DebuffsMap>,
     buffs_map: ColoredProperty<BuffsMap>,
+    // Number of the royal pieces of each color, captured during the game
+    captured_royals: ColoredProperty<u16>,
+    // Pieces captured by each side, when the drops are enabled
+    pockets: ColoredProperty<Pocket>,
+    // Names of the pieces, promoted pieces have been promoted from. They turn back into these
+    // pieces when captured into the pocket.
+    promoted_pieces: HashMap<PieceId, String, FxBuildHasher>,
+    // Names of the pieces, captured by each side, when the captures are passed to the other board
+    passed_pieces: ColoredProperty<Vec<String>>,
     current_turn: Color,
     // Determines board's point of view. Debugging purpose only.
     pov: Color,
@@ -76,7 +90,7 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         &self.x_ray_pieces[color]
     }
 
-    fn evaluate_move(
+    pub(crate) fn evaluate_move(
         config: &BoardConfig<HT, SQ>,
         board_map: &BoardMap,
         piece_move: &PieceMove,
@@ -87,8 +101,8 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         let current_position_score;
         match piece_move {
             PieceMove::Promote(_, promote_piece) => {
-                let theoretically_promoted_piece = Piece::init_piece_by_name(
-                    &promote_piece.name(),
+                let theoretically_promoted_piece = config.piece_registry().init_piece(
+                    &config.promoted_name(piece.name(), promote_piece),
                     *piece.color(),
                     *destination_point,
                     PieceId::new(1, piece.color()),
@@ -121,6 +135,11 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
                 new_position_score = new_king_score + new_rook_score;
                 current_position_score = old_king_score + old_rook_score;
             }
+            PieceMove::Drop(_) => {
+                // The piece comes from the pocket, where it has no positional value
+                new_position_score = config.heat_map().positional_value(piece, destination_point);
+                current_position_score = 0;
+            }
             _ => {
                 new_position_score = config
                     .heat_map()
@@ -138,25 +157,102 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         self.board_map.king(color)
     }
 
+    pub fn royals(&self, color: &Color) -> &[PieceId] {
+        self.board_map.royals(color)
+    }
+
+    // Whether all royal pieces of the given color have been captured. It is only possible when
+    // the side has several royal pieces and the royal rule is RoyalRule::AllCaptured, or when the
+    // king explodes in atomic chess.
+    pub fn is_out_of_royals(&self, color: &Color) -> bool {
+        self.captured_royals[color] > 0 && self.royals(color).is_empty()
+    }
+
+    // Whether the side has won by giving the limit of checks, like in three-check
+    pub fn is_check_limit_reached(&self, color: &Color) -> bool {
+        self.config
+            .check_limit()
+            .is_some_and(|check_limit| self.stats().checks_given[color] >= check_limit)
+    }
+
+    // Whether the king of the side has reached the hill, like in King of the Hill
+    pub fn is_king_on_the_hill(&self, color: &Color) -> bool {
+        self.king(color)
+            .is_some_and(|king| self.config.is_hill_square(king.current_position()))
+    }
+
+    pub fn pocket(&self, color: &Color) -> &Pocket {
+        &self.pockets[color]
+    }
+
+    // Puts the piece, passed by the partner from the other board, into the pocket of the given
+    // color
+    pub fn add_to_pocket(&mut self, color: &Color, name: &str) -> PieceId {
+        self.absorb_resolved_moves();
+        let piece_id = self.add_piece_to_pocket(color, name);
+        self.calculate_drop_moves(color);
+        self.recalculate_king_mechanics(color);
+        piece_id
+    }
+
+    // Names of the pieces, captured by the given color since the last call, which must be passed
+    // to the partner on the other board
+    pub fn take_passed_pieces(&mut self, color: &Color) -> Vec<String> {
+        std::mem::take(&mut self.passed_pieces[color])
+    }
+
+    // Takes all the pieces of the side off the board, once the side is out of the game which goes
+    // on for the others. When the pieces must keep blocking the way, their points turn void, so
+    // nobody can go through them.
+    pub fn remove_side(&mut self, color: &Color, leave_void: bool) -> Vec<Piece> {
+        self.absorb_resolved_moves();
+        let piece_ids = self
+            .board_map
+            .active_pieces(color)
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        let mut pieces = vec![];
+        for piece_id in piece_ids {
+            let piece = self.remove_piece(&piece_id);
+            if leave_void {
+                self.board_map
+                    .add_square(*piece.current_position(), BoardSquare::VoidSquare);
+            }
+            self.recalculate_connected_positions(piece.current_position(), color, false);
+            pieces.push(piece);
+        }
+        let current_turn = self.current_turn;
+        self.recalculate_king_mechanics(&current_turn);
+        pieces
+    }
+
     pub fn pawns_with_en_passant(&self, color: &Color) -> &HashSet<PieceId, FxBuildHasher> {
         &self.pawns_with_en_passant[color]
     }
 
     pub fn empty(config: BoardConfig<HT, SQ>) -> Self {
+        let sides_count = config.sides().len();
+        let first_side = config.sides()[0];
         let mut board = Self {
-            board_map: BoardMap::empty(),
-            strategy_points: ColoredProperty([StrategyPoints::empty(), StrategyPoints::empty()]),
-            x_ray_pieces: ColoredProperty([XRayPieces::empty(), XRayPieces::empty()]),
-            pawns_with_en_passant: ColoredProperty([HashSet::default(), HashSet::default()]),
-            moves_map: ColoredProperty([MovesMap::empty(), MovesMap::empty()]),
-            general_constraints: ColoredProperty([None, None]),
-            ids_generator: ColoredProperty([IdsGenerator::init(), IdsGenerator::init()]),
-            debuffs_map: ColoredProperty([DebuffsMap::empty(), DebuffsMap::empty()]),
-            buffs_map: ColoredProperty([BuffsMap::empty(), BuffsMap::empty()]),
-            current_turn: Color::White,
-            pov: Color::White,
+            board_map: BoardMap::empty(sides_count),
+            strategy_points: ColoredProperty::filled(sides_count, StrategyPoints::empty()),
+            x_ray_pieces: ColoredProperty::filled(sides_count, XRayPieces::empty()),
+            pawns_with_en_passant: ColoredProperty::filled(sides_count, HashSet::default()),
+            moves_map: ColoredProperty::filled(sides_count, MovesMap::empty()),
+            resolved_moves: ColoredProperty::filled(sides_count, OnceCell::new()),
+            general_constraints: ColoredProperty::filled(sides_count, None),
+            ids_generator: ColoredProperty::filled(sides_count, IdsGenerator::init()),
+            debuffs_map: ColoredProperty::filled(sides_count, DebuffsMap::empty()),
+            buffs_map: ColoredProperty::filled(sides_count, BuffsMap::empty()),
+            captured_royals: ColoredProperty::filled(sides_count, 0),
+            pockets: ColoredProperty::filled(sides_count, Pocket::empty()),
+            promoted_pieces: HashMap::default(),
+            passed_pieces: ColoredProperty::filled(sides_count, vec![]),
+            current_turn: first_side,
+            pov: first_side,
             config,
-            board_summary: BoardSummary::new(),
+            board_summary: BoardSummary::new(sides_count),
         };
         for y in board.dimension().get_rows_range() {
             for x in board.dimension().get_columns_range() {
@@ -180,45 +276,25 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         caused_by_color: &Color,
     ) -> HashSet<PieceId> {
         let mut pieces: HashSet<PieceId> = HashSet::default();
-        let inverse_color = caused_by_color.inverse();
-
-        if let Some(piece_ids) =
-            self.strategy_points[&inverse_color].get_pieces(&StrategyPoint::Attack(*point))
-        {
-            for piece_id in piece_ids {
-                pieces.insert(*piece_id);
-            }
-        }
-        if let Some(piece_ids) =
-            self.strategy_points[&inverse_color].get_pieces(&StrategyPoint::Move(*point))
-        {
-            for piece_id in piece_ids {
-                pieces.insert(*piece_id);
+        let mut add_pieces = |color: &Color, strategy_point: StrategyPoint| {
+            if let Some(piece_ids) = self.strategy_points[color].get_pieces(&strategy_point) {
+                pieces.extend(piece_ids.iter().copied());
             }
-        }
-
-        if let Some(piece_ids) =
-            self.strategy_points[caused_by_color].get_pieces(&StrategyPoint::Defense(*point))
-        {
-            for piece_id in piece_ids {
-                pieces.insert(*piece_id);
-            }
-        }
-
-        // BlockedMove source cause can originate from both sides. Thus, calculate it for both
-        // colors
-        for color in [Color::White, Color::Black].iter() {
-            if let Some(piece_ids) =
-                self.strategy_points[color].get_pieces(&StrategyPoint::BlockedMove(*point))
-            {
-                for piece_id in piece_ids {
-                    pieces.insert(*piece_id);
-                }
+        };
+        for color in self.config.sides() {
+            // Enemy pieces attack the point or move onto it, while the ally pieces defend it
+            if color.is_enemy(caused_by_color) {
+                add_pieces(color, StrategyPoint::Attack(*point));
+                add_pieces(color, StrategyPoint::Move(*point));
+            } else {
+                add_pieces(color, StrategyPoint::Defense(*point));
             }
+            // BlockedMove source cause can originate from any side
+            add_pieces(color, StrategyPoint::BlockedMove(*point));
         }
 
-        for piece_id in self.pawns_with_en_passant[&inverse_color].iter() {
-            pieces.insert(*piece_id);
+        for color in self.config.enemies_of(caused_by_color) {
+            pieces.extend(self.pawns_with_en_passant[color].iter().copied());
         }
         pieces
     }
@@ -232,26 +308,62 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
     ) {
         let strategy_points = &mut cstrategy_points[piece.color()];
         strategy_points.remove_piece(piece.id());
-        let dimension = config.dimension();
         let add_strategy_point = |strategy_point| {
             strategy_points.add_association(strategy_point, piece.id());
         };
+        Self::piece_strategy_points(piece, board_map, cbuffs_map, config, add_strategy_point);
+    }
 
+    fn piece_strategy_points<F: FnMut(StrategyPoint)>(
+        piece: &Piece,
+        board_map: &BoardMap,
+        cbuffs_map: &ColoredProperty<BuffsMap>,
+        config: &BoardConfig<HT, SQ>,
+        mut add_strategy_point: F,
+    ) {
+        let dimension = config.dimension();
+        let is_confined = config.piece_region(piece.name()).is_some();
+        // The piece neither moves nor attacks outside of its region
+        let add_strategy_point = |strategy_point: StrategyPoint| match strategy_point {
+            StrategyPoint::Attack(point)
+            | StrategyPoint::Defense(point)
+            | StrategyPoint::Move(point)
+                if is_confined
+                    && !config.is_in_piece_region(piece.name(), &point, piece.color()) => {}
+            _ => add_strategy_point(strategy_point),
+        };
         match piece {
             Piece::Pawn(p) => {
-                p.calculate_strategy_points(board_map, cbuffs_map, dimension, add_strategy_point)
+                p.calculate_strategy_points(board_map, cbuffs_map, config, add_strategy_point)
             }
-            Piece::Rook(p) => p.calculate_strategy_points(board_map, dimension, add_strategy_point),
+            Piece::Rook(p) => p.calculate_strategy_points(
+                board_map,
+                dimension,
+                config.max_ride_distance(piece.name()),
+                add_strategy_point,
+            ),
             Piece::Knight(p) => {
                 p.calculate_strategy_points(board_map, dimension, add_strategy_point)
             }
-            Piece::Bishop(p) => {
-                p.calculate_strategy_points(board_map, dimension, add_strategy_point)
-            }
-            Piece::Queen(p) => {
-                p.calculate_strategy_points(board_map, dimension, add_strategy_point)
-            }
+            Piece::Bishop(p) => p.calculate_strategy_points(
+                board_map,
+                dimension,
+                config.max_ride_distance(piece.name()),
+                add_strategy_point,
+            ),
+            Piece::Queen(p) => p.calculate_strategy_points(
+                board_map,
+                dimension,
+                config.max_ride_distance(piece.name()),
+                add_strategy_point,
+            ),
             Piece::King(p) => p.calculate_strategy_points(board_map, dimension, add_strategy_point),
+            Piece::Custom(p) => p.calculate_strategy_points(
+                board_map,
+                dimension,
+                config.max_ride_distance(piece.name()),
+                add_strategy_point,
+            ),
             Piece::UnknownPiece(_) => {
                 panic!("Can't calculate strategy points for an unknown piece!")
             }
@@ -269,12 +381,32 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         cdebuffs_map: &mut ColoredProperty<DebuffsMap>,
         cx_ray_pieces: &mut ColoredProperty<XRayPieces>,
     ) {
-        let opposite_king = match board_map.king(&piece.color().inverse()) {
+        let Some(opponent) = config.opponent(piece.color()) else {
+            return;
+        };
+        let opposite_king = match board_map.king(opponent) {
             Some(piece) => piece,
             None => return,
         };
 
-        if let Some(direction) = Self::x_ray_direction(piece, &opposite_king) {
+        let x_ray_direction = Self::x_ray_direction(piece, &opposite_king, config);
+        // The piece has changed its x-ray direction. A pin, caused by the piece in the previous
+        // direction, is not valid anymore.
+        if let Some(&direction_was) = cx_ray_pieces[piece.color()].direction(piece.id())
+            && Some(direction_was) != x_ray_direction
+        {
+            Self::remove_x_ray_piece(
+                piece.id(),
+                board_map,
+                config,
+                cstrategy_points,
+                cmoves_map,
+                cbuffs_map,
+                cdebuffs_map,
+                cx_ray_pieces,
+            );
+        }
+        if let Some(direction) = x_ray_direction {
             // Get current piece which occupies this direction
             let current_piece = cx_ray_pieces[piece.color()].piece_by_direction(&direction);
             let current_piece = match current_piece {
@@ -368,11 +500,90 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         cstrategy_points: &ColoredProperty<StrategyPoints>,
         cmoves_map: &mut ColoredProperty<MovesMap>,
     ) {
-        cmoves_map[piece.color()].remove_piece(piece.id());
+        if config.is_lazy_moves() {
+            // Moves will be generated when somebody asks for them
+            cmoves_map[piece.color()].mark_dirty(piece.id());
+            return;
+        }
+        Self::generate_moves_for(
+            piece,
+            board_map,
+            cbuffs_map,
+            cdebuffs_map,
+            config,
+            cstrategy_points,
+            &mut cmoves_map[piece.color()],
+        );
+    }
 
-        let add_move = |piece_move: PieceMove| {
+    fn resolve_moves_of(
+        piece: &Piece,
+        board_map: &BoardMap,
+        cbuffs_map: &ColoredProperty<BuffsMap>,
+        cdebuffs_map: &ColoredProperty<DebuffsMap>,
+        config: &BoardConfig<HT, SQ>,
+        cstrategy_points: &ColoredProperty<StrategyPoints>,
+        cmoves_map: &mut ColoredProperty<MovesMap>,
+    ) {
+        if cmoves_map[piece.color()].is_dirty(piece.id()) {
+            Self::generate_moves_for(
+                piece,
+                board_map,
+                cbuffs_map,
+                cdebuffs_map,
+                config,
+                cstrategy_points,
+                &mut cmoves_map[piece.color()],
+            );
+        }
+    }
+
+    fn generate_moves_for(
+        piece: &Piece,
+        board_map: &BoardMap,
+        cbuffs_map: &ColoredProperty<BuffsMap>,
+        cdebuffs_map: &ColoredProperty<DebuffsMap>,
+        config: &BoardConfig<HT, SQ>,
+        cstrategy_points: &ColoredProperty<StrategyPoints>,
+        moves_map: &mut MovesMap,
+    ) {
+        moves_map.remove_piece(piece.id());
+
+        let add_move = |piece_move: PieceMove, move_score: MoveScore| {
+            moves_map.add(piece.id(), piece_move, move_score);
+        };
+        Self::piece_moves(
+            piece,
+            board_map,
+            cbuffs_map,
+            cdebuffs_map,
+            config,
+            cstrategy_points,
+            add_move,
+        );
+    }
+
+    fn piece_moves<F: FnMut(PieceMove, MoveScore)>(
+        piece: &Piece,
+        board_map: &BoardMap,
+        cbuffs_map: &ColoredProperty<BuffsMap>,
+        cdebuffs_map: &ColoredProperty<DebuffsMap>,
+        config: &BoardConfig<HT, SQ>,
+        cstrategy_points: &ColoredProperty<StrategyPoints>,
+        mut consumer: F,
+    ) {
+        let mut add_scored_move = |piece_move: PieceMove| {
             let move_score = Self::evaluate_move(config, board_map, &piece_move, piece);
-            cmoves_map[piece.color()].add(piece.id(), piece_move, move_score);
+            consumer(piece_move, move_score);
+        };
+        let is_confined = config.piece_region(piece.name()).is_some();
+        let add_move = |piece_move: PieceMove| {
+            if is_confined
+                && !config.is_in_piece_region(piece.name(), piece_move.destination(), piece.color())
+            {
+                return;
+            }
+            Self::add_promotions(piece, piece_move, config, &mut add_scored_move)
         };
         match piece {
             Piece::King(k) => {
@@ -382,76 +593,116 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
                     cdebuffs_map,
                     config.dimension(),
                     config,
-                    &cstrategy_points[&piece.color().inverse()],
+                    cstrategy_points,
                     add_move,
                 );
             }
-            Piece::Bishop(p) => {
-                p.calculate_moves(board_map, cdebuffs_map, config.dimension(), add_move)
-            }
-            Piece::Knight(p) => {
-                p.calculate_moves(board_map, cdebuffs_map, config.dimension(), add_move)
-            }
-            Piece::Pawn(p) => p.calculate_moves(
+            Piece::Bishop(p) => p.calculate_moves(
                 board_map,
-                cbuffs_map,
                 cdebuffs_map,
                 config.dimension(),
+                config.max_ride_distance(piece.name()),
                 add_move,
             ),
-            Piece::Queen(p) => {
+            Piece::Knight(p) => {
                 p.calculate_moves(board_map, cdebuffs_map, config.dimension(), add_move)
             }
-            Piece::Rook(p) => {
-                p.calculate_moves(board_map, cdebuffs_map, config.dimension(), add_move)
+            Piece::Pawn(p) => {
+                p.calculate_moves(board_map, cbuffs_map, cdebuffs_map, config, add_move)
             }
+            Piece::Queen(p) => p.calculate_moves(
+                board_map,
+                cdebuffs_map,
+                config.dimension(),
+                config.max_ride_distance(piece.name()),
+                add_move,
+            ),
+            Piece::Rook(p) => p.calculate_moves(
+                board_map,
+                cdebuffs_map,
+                config.dimension(),
+                config.max_ride_distance(piece.name()),
+                add_move,
+            ),
+            Piece::Custom(p) => p.calculate_moves(
+                board_map,
+                cdebuffs_map,
+                config.dimension(),
+                config.max_ride_distance(piece.name()),
+                add_move,
+            ),
             Piece::UnknownPiece(_) => panic!("Unknown piece does not have any moves!"),
         }
     }
 
+    // The piece, which has a promoted form, may promote when it enters, leaves or moves within the
+    // promotion zone. It must promote when it would not be able to move any further otherwise or
+    // when its promotion is compulsory.
+    fn add_promotions<F: FnMut(PieceMove)>(
+        piece: &Piece,
+        piece_move: PieceMove,
+        config: &BoardConfig<HT, SQ>,
+        add_move: &mut F,
+    ) {
+        let PieceMove::Point(point) = piece_move else {
+            return add_move(piece_move);
+        };
+        if !config.has_promotions()
+            || config.promotion_of(piece.name()).is_none()
+            || !(config.is_promotion_square(piece.current_position(), piece.color())
+                || config.is_promotion_square(&point, piece.color()))
+        {
+            return add_move(piece_move);
+        }
+        add_move(PieceMove::Promote(point, PromotePiece::Promoted));
+        if !config.is_promotion_compulsory(piece.name())
+            && Self::can_move_from(piece, &point, config)
+        {
+            add_move(piece_move);
+        }
+    }
+
+    // Whether the piece would have any point to go to from the given point on the empty board
+    fn can_move_from(piece: &Piece, point: &Point, config: &BoardConfig<HT, SQ>) -> bool {
+        let Piece::Custom(custom_piece) = piece else {
+            return true;
+        };
+        custom_piece
+            .definition()
+            .movements(piece.color())
+            .iter()
+            .any(|movement| {
+                config
+                    .dimension()
+                    .is_in_boundaries(&movement.vector().calc_next_point(point))
+            })
+    }
+
     fn calculate_general_constraints(
         king: &Piece,
         board_map: &BoardMap,
+        cbuffs_map: &ColoredProperty<BuffsMap>,
+        cdebuffs_map: &ColoredProperty<DebuffsMap>,
         config: &BoardConfig<HT, SQ>,
         cstrategy_points: &ColoredProperty<StrategyPoints>,
-        cmoves_map: &ColoredProperty<MovesMap>,
+        cmoves_map: &mut ColoredProperty<MovesMap>,
         cgeneral_constraints: &mut ColoredProperty<Option<MovesMap>>,
     ) {
         let mut constraints: Vec<PieceMove> = vec![];
-        let pieces_caused_check = cstrategy_points[&king.color().inverse()]
-            .get_pieces(&StrategyPoint::Attack(*king.current_position()))
-            .unwrap();
+        let mut pawn_caused_check = None;
+        let pieces_caused_check = Self::pieces_caused_check(king, config, cstrategy_points);
         // When the king is in check by more than one piece, no legal moves can be made by
         // any piece except the king itself.
-        if pieces_caused_check.len() == 1 {
-            let piece_caused_check =
-                board_map.find_piece_by_id(pieces_caused_check.iter().next().unwrap());
+        if let [piece_id] = pieces_caused_check[..] {
+            let piece_caused_check = board_map.find_piece_by_id(&piece_id);
             // Add the position of the piece caused check.
             constraints.push(PieceMove::Point(*piece_caused_check.current_position()));
+            if let Piece::Pawn(_) = piece_caused_check {
+                pawn_caused_check = Some(piece_caused_check);
+            }
 
-            let direction = Vector::calc_direction(
-                &piece_caused_check.current_position(),
-                &king.current_position(),
-            )
-            .unwrap();
-            match direction {
-                // Jump checks can't be blocked. The only way to remove it is to eliminate the
-                // knight.
-                Vector::Jump(_) => (),
-                _ => {
-                    let vector_points = VectorPoints::without_initial(
-                        *piece_caused_check.current_position(),
-                        *config.dimension(),
-                        direction,
-                    );
-                    for point in vector_points {
-                        // Exclude king's position
-                        if &point == king.current_position() {
-                            break;
-                        }
-                        constraints.push(PieceMove::Point(point));
-                    }
-                }
+            for point in Self::check_block_points(king, board_map, config, cstrategy_points) {
+                constraints.push(PieceMove::Point(point));
             }
         }
 
@@ -460,12 +711,229 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
                 king.color(),
                 piece_move,
                 board_map,
+                cbuffs_map,
+                cdebuffs_map,
                 config,
                 cmoves_map,
                 cstrategy_points,
                 cgeneral_constraints,
             );
         }
+        // The pawn which caused check by its long move can also be captured en passant. Such
+        // capture does not land on the position of the pawn, thus it is added separately. Pawns,
+        // capturing it, stand next to any of the points the pawn has skipped.
+        if let Some(pawn) = pawn_caused_check {
+            let (&x, &y) = pawn.current_position().to_tuple();
+            let orientation = pawn.color().orientation();
+            let (backward_x, backward_y) = orientation.forward().inverse().delta();
+            let (aside_x, aside_y) = orientation.king_side().delta();
+            let rows_behind = 0..config.pawn_initial_steps().saturating_sub(1) as i16;
+            let points = rows_behind.flat_map(|row| {
+                [-1, 1].map(|shift| {
+                    Point::new(
+                        x + shift * aside_x + row * backward_x,
+                        y + shift * aside_y + row * backward_y,
+                    )
+                })
+            });
+            for point in points {
+                let Some(ally_pawn) = board_map.piece_at(&point) else {
+                    continue;
+                };
+                if let Some(&Buff::EnPassant(en_passant, enemy_piece_point)) =
+                    cbuffs_map[king.color()].en_passant(ally_pawn.id())
+                    && &enemy_piece_point == pawn.current_position()
+                {
+                    Self::resolve_moves_of(
+                        ally_pawn,
+                        board_map,
+                        cbuffs_map,
+                        cdebuffs_map,
+                        config,
+                        cstrategy_points,
+                        cmoves_map,
+                    );
+                    let piece_move = PieceMove::EnPassant(en_passant, enemy_piece_point);
+                    if let Some(move_score) =
+                        cmoves_map[king.color()].move_score(ally_pawn.id(), &piece_move)
+                    {
+                        cgeneral_constraints[king.color()]
+                            .as_mut()
+                            .expect("Logical error: constraints MovesMap must be initialized at this point!")
+                            .add(ally_pawn.id(), piece_move, *move_score);
+                    }
+                }
+            }
+        }
+    }
+
+    // Points between the king and the single piece which checks it. Only checks of sliding pieces
+    // can be blocked. Other checks, like jump checks, can only be removed by eliminating the piece.
+    fn check_block_points(
+        king: &Piece,
+        board_map: &BoardMap,
+        config: &BoardConfig<HT, SQ>,
+        cstrategy_points: &ColoredProperty<StrategyPoints>,
+    ) -> Vec<Point> {
+        let mut points = vec![];
+        let [piece_id] = Self::pieces_caused_check(king, config, cstrategy_points)[..] else {
+            return points;
+        };
+        let piece_caused_check = board_map.find_piece_by_id(&piece_id);
+        if let Some(direction) = Self::x_ray_direction(piece_caused_check, king, config) {
+            let vector_points = VectorPoints::without_initial(
+                *piece_caused_check.current_position(),
+                *config.dimension(),
+                direction,
+            );
+            for point in vector_points {
+                // Exclude king's position
+                if &point == king.current_position() {
+                    break;
+                }
+                points.push(point);
+            }
+        }
+        points
+    }
+
+    // Enemy pieces which attack the king
+    fn pieces_caused_check(
+        king: &Piece,
+        config: &BoardConfig<HT, SQ>,
+        cstrategy_points: &ColoredProperty<StrategyPoints>,
+    ) -> Vec<PieceId> {
+        config
+            .enemies_of(king.color())
+            .filter_map(|enemy| {
+                cstrategy_points[enemy].get_pieces(&StrategyPoint::Attack(*king.current_position()))
+            })
+            .flatten()
+            .copied()
+            .collect()
+    }
+
+    // Pieces from the pocket can block the check by being dropped between the king and the piece
+    // which checks it
+    fn add_drops_to_general_constraints(
+        king: &Piece,
+        board_map: &BoardMap,
+        config: &BoardConfig<HT, SQ>,
+        cstrategy_points: &ColoredProperty<StrategyPoints>,
+        cpockets: &ColoredProperty<Pocket>,
+        cmoves_map: &ColoredProperty<MovesMap>,
+        cgeneral_constraints: &mut ColoredProperty<Option<MovesMap>>,
+    ) {
+        let general_constraints = cgeneral_constraints[king.color()]
+            .as_mut()
+            .expect("Logical error: constraints MovesMap must be initialized at this point!");
+        for point in Self::check_block_points(king, board_map, config, cstrategy_points) {
+            let piece_move = PieceMove::Drop(point);
+            for (piece_id, _) in cpockets[king.color()].droppable() {
+                if let Some(move_score) =
+                    cmoves_map[king.color()].move_score(&piece_id, &piece_move)
+                {
+                    general_constraints.add(&piece_id, piece_move, *move_score);
+                }
+            }
+        }
+    }
+
+    // Drops depend on every empty square of the board, thus they are generated from scratch
+    // whenever the position changes
+    fn calculate_drop_moves(&mut self, color: &Color) {
+        let pocket = &self.pockets[color];
+        let moves_map = &mut self.moves_map[color];
+        for (piece_id, _) in pocket.iter() {
+            moves_map.remove_piece(piece_id);
+        }
+        if pocket.is_empty() {
+            return;
+        }
+        let empty_points = self.board_map.empty_points().copied().collect::<Vec<_>>();
+        for (piece_id, name) in pocket.droppable() {
+            for point in empty_points.iter() {
+                let piece = self
+                    .config
+                    .piece_registry()
+                    .init_piece(name, *color, *point, piece_id);
+                if matches!(piece, Piece::Pawn(_)) && !self.config.is_pawn_drop_allowed(point) {
+                    continue;
+                }
+                if !Self::can_move_from(&piece, point, &self.config) {
+                    continue;
+                }
+                if self.config.is_drop_restricted(name)
+                    && Self::has_piece_on_file(&self.board_map, &piece, point)
+                {
+                    continue;
+                }
+                let piece_move = PieceMove::Drop(*point);
+                let move_score =
+                    Self::evaluate_move(&self.config, &self.board_map, &piece_move, &piece);
+                moves_map.add(&piece_id, piece_move, move_score);
+            }
+        }
+    }
+
+    // Whether the side already has a piece of the same kind on the file of the given point
+    fn has_piece_on_file(board_map: &BoardMap, piece: &Piece, point: &Point) -> bool {
+        board_map
+            .active_pieces(piece.color())
+            .values()
+            .any(|ally_piece| {
+                ally_piece.name() == piece.name() && ally_piece.current_position().x() == point.x()
+            })
+    }
+
+    // Puts the captured piece into the pocket of the given capturing side or passes it to the partner
+    // on the other board. Promoted pieces turn back into the pieces they have been promoted from.
+    fn put_into_pocket(&mut self, captured_piece: &Piece, color: &Color) {
+        let name = self
+            .promoted_pieces
+            .remove(captured_piece.id())
+            .unwrap_or_else(|| captured_piece.name().to_string());
+        if self.config.passes_captures() {
+            self.passed_pieces[color].push(name);
+        } else {
+            self.add_piece_to_pocket(color, &name);
+        }
+    }
+
+    fn add_piece_to_pocket(&mut self, color: &Color, name: &str) -> PieceId {
+        let piece_id = self.ids_generator[color].next_val(color);
+        self.pockets[color].add(piece_id, name);
+        // The position of the piece does not matter while it is in the pocket
+        let pocket_piece = self.config.piece_registry().init_piece(
+            name,
+            *color,
+            *self.config.dimension().min_point(),
+            piece_id,
+        );
+        self.board_summary
+            .toggle_pocket_piece(&pocket_piece, self.pockets[color].count(name));
+        piece_id
+    }
+
+    // Places the piece from the pocket on the given empty point
+    fn drop_piece(&mut self, piece_id: &PieceId, point: &Point) {
+        let color = piece_id.color();
+        let name = self.pockets[&color].name_of(piece_id).unwrap_or_else(|| {
+            panic!("Logical mistake: piece {} must be in the pocket!", piece_id)
+        });
+        let pocket_piece = self
+            .config
+            .piece_registry()
+            .init_piece(name, color, *point, *piece_id);
+        self.board_summary
+            .toggle_pocket_piece(&pocket_piece, self.pockets[&color].count(name));
+        self.pockets[&color].remove(piece_id);
+        self.moves_map[&color].remove_piece(piece_id);
+        self.add_piece_unchecked(pocket_piece.name(), color, vec![], vec![], *point, false);
+        self.recalculate_connected_positions(point, &color, true);
+        for enemy_color in self.enemies_of(&color) {
+            self.recalculate_connected_positions(point, &enemy_color, false);
+        }
     }
 
     fn add_king_moves_to_general_constraints(
@@ -486,8 +954,12 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
     pub fn has_no_moves(&self, color: &Color) -> bool {
         if let Some(general_constraints) = &self.general_constraints[color] {
             general_constraints.is_empty()
+        } else if !self.moves_map[color].is_empty() {
+            // Moves which are not marked as dirty are still actual. There is no need to generate
+            // the rest of them.
+            false
         } else {
-            self.moves_map[color].is_empty()
+            self.resolved_moves(color).is_empty()
         }
     }
 
@@ -497,6 +969,8 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
     ) -> Option<&HashMap<PieceMove, MoveScore, FxBuildHasher>> {
         if let Some(general_constraints) = &self.general_constraints[&piece_id.color()] {
             general_constraints.moves_of(piece_id)
+        } else if self.moves_map[&piece_id.color()].is_dirty(piece_id) {
+            self.resolved_moves(&piece_id.color()).moves_of(piece_id)
         } else {
             self.moves_map[&piece_id.color()].moves_of(piece_id)
         }
@@ -505,24 +979,81 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
     pub fn score_to_moves(
         &self,
         color: &Color,
-    ) -> &OrdMap<MoveScore, OrdMap<PieceId, im_rc::Vector<PieceMove>>> {
+    ) -> &OrdMap<MoveScore, OrdMap<PieceId, crate::collections::Vector<PieceMove>>> {
         if let Some(general_constraints) = &self.general_constraints[color] {
             general_constraints.score_to_moves()
         } else {
-            self.moves_map[color].score_to_moves()
+            self.resolved_moves(color).score_to_moves()
         }
     }
 
-    fn add_general_constraints(
-        color: &Color,
-        piece_move: PieceMove,
+    // Returns moves map of the given color where moves of all dirty pieces are generated. The
+    // result is cached until the position changes.
+    fn resolved_moves(&self, color: &Color) -> &MovesMap {
+        let moves_map = &self.moves_map[color];
+        if !moves_map.has_dirty_pieces() {
+            return moves_map;
+        }
+        self.resolved_moves[color].get_or_init(|| {
+            let mut resolved = moves_map.clone();
+            for piece_id in moves_map.dirty_pieces() {
+                Self::generate_moves_for(
+                    self.board_map.find_piece_by_id(piece_id),
+                    &self.board_map,
+                    &self.buffs_map,
+                    &self.debuffs_map,
+                    &self.config,
+                    &self.strategy_points,
+                    &mut resolved,
+                );
+            }
+            resolved
+        })
+    }
+
+    // Moves, resolved on demand, are still valid for the position they were generated for. Keep
+    // them before the position changes, so they don't have to be generated again.
+    fn absorb_resolved_moves(&mut self) {
+        let moves_maps = self.moves_map.0.iter_mut().zip(self.resolved_moves.0.iter_mut());
+        for (moves_map, resolved_moves) in moves_maps {
+            if let Some(resolved) = resolved_moves.take() {
+                *moves_map = resolved;
+            }
+        }
+    }
+
+    fn add_general_constraints(
+        color: &Color,
+        piece_move: PieceMove,
         board_map: &BoardMap,
+        cbuffs_map: &ColoredProperty<BuffsMap>,
+        cdebuffs_map: &ColoredProperty<DebuffsMap>,
         config: &BoardConfig<HT, SQ>,
-        cmoves_map: &ColoredProperty<MovesMap>,
+        cmoves_map: &mut ColoredProperty<MovesMap>,
         cstrategy_points: &ColoredProperty<StrategyPoints>,
         cgeneral_constraints: &mut ColoredProperty<Option<MovesMap>>,
     ) {
         let destination_to_cover = piece_move.destination();
+        // Moves of the pieces which are able to cover the destination must be generated at this
+        // point
+        for strategy_point in [
+            StrategyPoint::Attack(*destination_to_cover),
+            StrategyPoint::Move(*destination_to_cover),
+        ] {
+            if let Some(piece_ids) = cstrategy_points[color].get_pieces(&strategy_point) {
+                for piece_id in piece_ids {
+                    Self::resolve_moves_of(
+                        board_map.find_piece_by_id(piece_id),
+                        board_map,
+                        cbuffs_map,
+                        cdebuffs_map,
+                        config,
+                        cstrategy_points,
+                        cmoves_map,
+                    );
+                }
+            }
+        }
         let constraints = cgeneral_constraints[color]
             .as_mut()
             .expect("Logical error: constraints MovesMap must be initialized at this point!");
@@ -543,14 +1074,24 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
                         }
                         _ => {
                             // Not taking into account other move variants as it is not possible to
-                            // cover from check with castle move for example
-                            if let Some((defensive_move, _)) =
-                                moves.get_key_value(&PieceMove::Point(*destination_to_cover))
-                            {
-                                let move_score =
-                                    Self::evaluate_move(&config, board_map, defensive_move, piece);
-                                constraints.add(piece_id, *defensive_move, move_score);
-                            };
+                            // cover from check with castle move for example. The piece, which has
+                            // a promoted form, may promote while covering.
+                            for defensive_move in [
+                                PieceMove::Point(*destination_to_cover),
+                                PieceMove::Promote(*destination_to_cover, PromotePiece::Promoted),
+                            ] {
+                                if let Some((defensive_move, _)) =
+                                    moves.get_key_value(&defensive_move)
+                                {
+                                    let move_score = Self::evaluate_move(
+                                        &config,
+                                        board_map,
+                                        defensive_move,
+                                        piece,
+                                    );
+                                    constraints.add(piece_id, *defensive_move, move_score);
+                                };
+                            }
                         }
                     }
                 }
@@ -568,28 +1109,39 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         }
     }
 
+    // Every point, skipped by the long move of the pawn to the given position, can be captured en
+    // passant
     fn calc_en_passant(
+        pawn: &Piece,
         position: &Point,
-        caused_by_color: &Color,
         board_map: &BoardMap,
         cstrategy_points: &ColoredProperty<StrategyPoints>,
         cbuffs_map: &mut ColoredProperty<BuffsMap>,
         board_summary: &mut BoardSummary,
         cpawns_with_en_passant: &mut ColoredProperty<HashSet<PieceId, FxBuildHasher>>,
     ) {
-        let en_passant_position = match caused_by_color {
-            Color::White => Point::new(*position.x().value(), *position.y().value() - 1),
-            Color::Black => Point::new(*position.x().value(), *position.y().value() + 1),
-        };
+        let caused_by_color = pawn.color();
+        let forward = Vector::Line(*caused_by_color.orientation().forward());
+        let mut skipped_points = vec![];
+        let mut skipped_point = forward.calc_next_point(pawn.current_position());
+        while &skipped_point != position {
+            skipped_points.push(skipped_point);
+            skipped_point = forward.calc_next_point(&skipped_point);
+        }
 
         let mut pawns: Vec<&PieceId> = vec![];
-        if let Some(piece_ids) = cstrategy_points[&caused_by_color.inverse()]
-            .get_pieces(&StrategyPoint::Attack(en_passant_position))
-        {
+        for en_passant_position in skipped_points {
+            let piece_ids = cstrategy_points
+                .0
+                .iter()
+                .filter_map(|strategy_points| {
+                    strategy_points.get_pieces(&StrategyPoint::Attack(en_passant_position))
+                })
+                .flatten();
             for piece_id in piece_ids {
                 let piece = board_map.find_piece_by_id(piece_id);
                 match piece {
-                    Piece::Pawn(_) => {
+                    Piece::Pawn(_) if piece.is_enemy(caused_by_color) => {
                         cbuffs_map[&piece_id.color()]
                             .add(piece_id, Buff::EnPassant(en_passant_position, *position));
                         pawns.push(piece.id());
@@ -600,25 +1152,73 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
             }
         }
         for pawn_id in pawns {
-            cpawns_with_en_passant[&caused_by_color.inverse()].insert(*pawn_id);
+            cpawns_with_en_passant[&pawn_id.color()].insert(*pawn_id);
         }
     }
 
-    fn clear_en_passant(&mut self) {
-        for pawn_id in self.pawns_with_en_passant[&Color::White].iter() {
-            self.buffs_map[&pawn_id.color()].remove_en_passant(pawn_id);
-            self.board_summary.update_piece_en_passant(pawn_id, false);
+    // Allows to capture the given pawn en passant, as if it has just made its long move from the
+    // initial position, e.g. when the position is imported
+    pub fn set_en_passant(&mut self, pawn_id: &PieceId, initial_position: &Point) {
+        self.absorb_resolved_moves();
+        let mut pawn = self.board_map.find_piece_by_id(pawn_id).clone();
+        let position = *pawn.current_position();
+        pawn.set_current_position(*initial_position);
+        Self::calc_en_passant(
+            &pawn,
+            &position,
+            &self.board_map,
+            &self.strategy_points,
+            &mut self.buffs_map,
+            &mut self.board_summary,
+            &mut self.pawns_with_en_passant,
+        );
+        for color in self.enemies_of(&pawn_id.color()) {
+            for pawn_id in self.pawns_with_en_passant[&color].iter() {
+                Self::calculate_moves_for(
+                    self.board_map.find_piece_by_id(pawn_id),
+                    &self.board_map,
+                    &self.buffs_map,
+                    &self.debuffs_map,
+                    &self.config,
+                    &self.strategy_points,
+                    &mut self.moves_map,
+                );
+            }
+            self.recalculate_king_mechanics(&color);
         }
-        self.pawns_with_en_passant[&Color::White].clear();
-        for pawn_id in self.pawns_with_en_passant[&Color::Black].iter() {
-            self.buffs_map[&pawn_id.color()].remove_en_passant(pawn_id);
-            self.board_summary.update_piece_en_passant(pawn_id, false);
+    }
+
+    fn clear_en_passant(&mut self) {
+        for color in self.config.sides().to_vec().iter() {
+            for pawn_id in self.pawns_with_en_passant[color].iter() {
+                self.buffs_map[color].remove_en_passant(pawn_id);
+                self.board_summary.update_piece_en_passant(pawn_id, false);
+                // En passant move is not available anymore
+                if let Some(pawn) = self.board_map.maybe_find_piece_by_id(pawn_id) {
+                    Self::calculate_moves_for(
+                        pawn,
+                        &self.board_map,
+                        &self.buffs_map,
+                        &self.debuffs_map,
+                        &self.config,
+                        &self.strategy_points,
+                        &mut self.moves_map,
+                    );
+                }
+            }
+            self.pawns_with_en_passant[color].clear();
         }
-        self.pawns_with_en_passant[&Color::Black].clear();
     }
 
     pub fn pass_turn(&mut self, color: &Color) {
+        if self.current_turn == *color {
+            return;
+        }
+        self.absorb_resolved_moves();
         self.current_turn = *color;
+        // Constraints, which are calculated only for the side to move, are now needed for the
+        // other side
+        self.recalculate_king_mechanics(color);
     }
 
     pub fn piece_at(&self, point: &Point) -> Option<&Piece> {
@@ -654,12 +1254,13 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         }
 
         let enemy_color = pinned_by.color();
-        let x_ray_direction = Self::x_ray_direction(pinned_by, pin_to).unwrap_or_else(|| {
-            panic!(
-                "Logical mistake: {:?} must have a connection to {:?} at this point!",
-                pinned_by, pin_to
-            )
-        });
+        let x_ray_direction =
+            Self::x_ray_direction(pinned_by, pin_to, config).unwrap_or_else(|| {
+                panic!(
+                    "Logical mistake: {:?} must have a connection to {:?} at this point!",
+                    pinned_by, pin_to
+                )
+            });
 
         let mut current_piece_on_the_way: Option<&Piece> = None;
         let vector_points = VectorPoints::without_initial(
@@ -670,7 +1271,7 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         for point in vector_points {
             if let Some(piece) = board_map.piece_at(&point) {
                 // Enemy piece meets his ally
-                if piece.color() == enemy_color {
+                if piece.is_ally(enemy_color) {
                     break;
                 }
                 match current_piece_on_the_way {
@@ -713,6 +1314,7 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
                 name, position
             )
         }
+        self.absorb_resolved_moves();
         self.add_piece_unchecked(name, color, buffs, debuffs, position, true)
     }
 
@@ -726,36 +1328,114 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         calculate_mechanics: bool,
     ) -> PieceId {
         let id = self.ids_generator[&color].next_val(&color);
-        let piece = Piece::init_piece_by_name(name, color, position, id);
+        let piece = self
+            .config
+            .piece_registry()
+            .init_piece(name, color, position, id);
         let buffs_map = &self.buffs_map[piece.color()];
         self.board_summary.add_piece(
             &piece,
             buffs_map.has_castle(piece.id()),
             buffs_map.has_en_passant(piece.id()),
         );
+        let is_royal = self.config.is_royal(piece.name());
         self.board_map.add_piece(piece, position);
+        if is_royal {
+            self.board_map.add_royal(&id);
+        }
         self.buffs_map[&id.color()].add_from_vec(&id, buffs);
         self.debuffs_map[&id.color()].add_from_vec(&id, debuffs);
         if calculate_mechanics {
             self.recalculate_connected_positions(&position, &color, true);
-            self.recalculate_connected_positions(&position, &color.inverse(), false);
-            self.recalculate_king_mechanics(&color);
-            self.recalculate_king_mechanics(&color.inverse());
+            for enemy_color in self.enemies_of(&color) {
+                self.recalculate_connected_positions(&position, &enemy_color, false);
+            }
+            if is_royal {
+                self.update_king(&color);
+            }
+            self.recalculate_mechanics_from(&color);
         }
         id
     }
 
+    // X-ray directions of the enemy pieces depend on the king position. Thus, they are calculated
+    // from scratch when the king appears on the board or changes its position.
+    fn calculate_x_rays_against(&mut self, king_color: &Color) {
+        let Some(&opponent) = self.config.opponent(king_color) else {
+            return;
+        };
+        for piece_id in self.x_ray_pieces[&opponent].pieces_owned() {
+            Self::remove_x_ray_piece(
+                &piece_id,
+                &self.board_map,
+                &self.config,
+                &self.strategy_points,
+                &mut self.moves_map,
+                &mut self.buffs_map,
+                &mut self.debuffs_map,
+                &mut self.x_ray_pieces,
+            );
+        }
+        let x_ray_pieces = self
+            .board_map
+            .active_pieces(&opponent)
+            .values()
+            .filter(|piece| piece.is_slider())
+            .cloned()
+            .collect::<Vec<_>>();
+        for piece in x_ray_pieces {
+            Self::calculate_x_ray(
+                &piece,
+                &self.board_map,
+                &self.config,
+                &self.strategy_points,
+                &mut self.moves_map,
+                &mut self.buffs_map,
+                &mut self.debuffs_map,
+                &mut self.x_ray_pieces,
+            );
+        }
+    }
+
     pub fn move_piece(&mut self, piece_id: &PieceId, piece_move: &PieceMove) -> Option<MoveScore> {
         if self.current_turn != piece_id.color() {
             return None;
         }
 
-        if let Some(&move_score) =
-            self.moves_map[&piece_id.color()].move_score(piece_id, piece_move)
-        {
+        self.absorb_resolved_moves();
+        if let Some(piece) = self.board_map.maybe_find_piece_by_id(piece_id) {
+            Self::resolve_moves_of(
+                piece,
+                &self.board_map,
+                &self.buffs_map,
+                &self.debuffs_map,
+                &self.config,
+                &self.strategy_points,
+                &mut self.moves_map,
+            );
+        }
+        // When the moves are constrained, e.g. the king is in check, only the constrained moves
+        // can be made
+        let moves_map = match &self.general_constraints[&piece_id.color()] {
+            Some(general_constraints) => general_constraints,
+            None => &self.moves_map[&piece_id.color()],
+        };
+        if let Some(&move_score) = moves_map.move_score(piece_id, piece_move) {
+            let next_color = *self.config.next_side(&piece_id.color());
+            // The turn is passed before the move, so the constraints of the side to move are
+            // calculated along with the move
+            self.current_turn = next_color;
             self.move_piece_unchecked(piece_id, piece_move, true);
-            self.pass_turn(&piece_id.color().inverse());
-            self.board_summary.next_turn();
+            if self.config.check_limit().is_some()
+                && self.config.enemies_of(&piece_id.color()).any(|enemy_color| {
+                    self.royals(enemy_color)
+                        .iter()
+                        .any(|royal_id| self.debuffs_map[enemy_color].has_check(royal_id))
+                })
+            {
+                self.board_summary.check_given(&piece_id.color());
+            }
+            self.board_summary.next_turn(&piece_id.color(), &next_color);
             Some(move_score)
         } else {
             None
@@ -769,8 +1449,8 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         calculate_king: bool,
     ) {
         self.clear_en_passant();
-        {
-            let piece = self.board_map.find_piece_by_id(piece_id);
+        // A piece, dropped from the pocket, is not on the board yet
+        if let Some(piece) = self.board_map.maybe_find_piece_by_id(piece_id) {
             match piece {
                 Piece::King(_) | Piece::Rook(_) => {
                     let buffs_map_mut = &mut self.buffs_map[piece.color()];
@@ -785,8 +1465,8 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
                     match piece_move {
                         PieceMove::LongMove(new_position) => {
                             Self::calc_en_passant(
+                                piece,
                                 new_position,
-                                &piece.color(),
                                 &self.board_map,
                                 &self.strategy_points,
                                 &mut self.buffs_map,
@@ -804,15 +1484,25 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
             PieceMove::Point(new_position) | PieceMove::LongMove(new_position) => {
                 let enemy_piece_id = self.board_map.piece_id_at(new_position).copied();
                 self.perform_move(piece_id, &new_position, enemy_piece_id);
+                if self.board_map.is_royal(piece_id) {
+                    self.update_king(&piece_id.color());
+                }
             }
             PieceMove::EnPassant(new_position, enemy_position) => {
-                let captured_pawn_id = self.board_map.piece_id_at(enemy_position).unwrap_or_else(|| {
+                let &captured_pawn_id = self.board_map.piece_id_at(enemy_position).unwrap_or_else(|| {
                     panic!(
                         "Logical mistake: enemy pawn should be present at {:?} during en passant",
                         enemy_position
                     );
                 });
-                self.perform_move(piece_id, &new_position, Some(*captured_pawn_id));
+                self.perform_move(piece_id, &new_position, Some(captured_pawn_id));
+                // Unlike other captures, the captured pawn leaves the square which is not the
+                // destination of the move
+                self.recalculate_connected_positions(
+                    enemy_position,
+                    &captured_pawn_id.color(),
+                    false,
+                );
             }
             PieceMove::Castle(castle_points) => {
                 let &king_id = self.board_map.piece_id_at(
@@ -831,26 +1521,37 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
                         castle_points.initial_rook_point()
                     ).as_str()
                 );
-                // In chess960 king or rook may keep staying on their places during the castling
-                if castle_points.initial_rook_point() != castle_points.rook_point() {
-                    self.move_piece_unchecked(
-                        &rook_id,
-                        &PieceMove::Point(*castle_points.rook_point()),
-                        false,
-                    );
+                let buffs_map_mut = &mut self.buffs_map[&rook_id.color()];
+                if buffs_map_mut.has_castle(&rook_id) {
+                    buffs_map_mut.remove_castle(&rook_id);
+                    self.board_summary.update_piece_castle(&rook_id, false);
                 }
-                if castle_points.initial_king_point() != castle_points.king_point() {
-                    self.move_piece_unchecked(
-                        &king_id,
-                        &PieceMove::Point(*castle_points.king_point()),
-                        false,
-                    );
+                // In chess960 king or rook may keep staying on their places or land on the initial
+                // points of each other, thus both of them are lifted before landing
+                let moves = [
+                    (king_id, *castle_points.king_point()),
+                    (rook_id, *castle_points.rook_point()),
+                ];
+                let old_positions = self.board_map.change_pieces_positions(&moves);
+                for ((piece_id, new_position), old_position) in moves.iter().zip(old_positions) {
+                    self.board_summary.update_piece_position(piece_id, new_position);
+                    self.recalculate_connected_positions(&old_position, &piece_id.color(), false);
+                }
+                for (piece_id, new_position) in moves.iter() {
+                    self.recalculate_connected_positions(new_position, &piece_id.color(), true);
+                    for enemy_color in self.enemies_of(&piece_id.color()) {
+                        self.recalculate_connected_positions(new_position, &enemy_color, false);
+                    }
+                }
+                if self.board_map.is_royal(&king_id) {
+                    self.update_king(&king_id.color());
                 }
             }
             PieceMove::Promote(point, promote_piece) => {
+                let is_royal_pawn = self.board_map.is_royal(piece_id);
                 let pawn = self.remove_piece(piece_id);
                 let promoted_piece_id = self.add_piece_unchecked(
-                    &promote_piece.name(),
+                    &self.config.promoted_name(pawn.name(), promote_piece),
                     piece_id.color(),
                     vec![],
                     vec![],
@@ -858,23 +1559,57 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
                     false,
                 );
                 self.board_summary.piece_promoted();
+                if self.config.has_drops() {
+                    self.promoted_pieces
+                        .insert(promoted_piece_id, pawn.name().to_string());
+                }
                 self.move_piece_unchecked(&promoted_piece_id, &PieceMove::Point(*point), false);
+                if is_royal_pawn {
+                    self.update_king(&piece_id.color());
+                }
             }
+            PieceMove::Drop(point) => self.drop_piece(piece_id, point),
         }
         if calculate_king {
-            self.recalculate_king_mechanics(&piece_id.color());
-            self.recalculate_king_mechanics(&piece_id.color().inverse());
+            self.recalculate_mechanics_from(&piece_id.color());
         }
     }
 
+    // Drops and king mechanics of every side, starting from the given one
+    fn recalculate_mechanics_from(&mut self, color: &Color) {
+        let sides = self.sides_from(color);
+        for color in sides.iter() {
+            self.calculate_drop_moves(color);
+        }
+        for color in sides.iter() {
+            self.recalculate_king_mechanics(color);
+        }
+    }
+
+    // Every side of the game, starting from the given one in the order of the turns
+    fn sides_from(&self, color: &Color) -> Vec<Color> {
+        let sides = self.config.sides();
+        let position = sides.iter().position(|side| side == color).unwrap_or(0);
+        sides[position..].iter().chain(&sides[..position]).copied().collect()
+    }
+
+    fn enemies_of(&self, color: &Color) -> Vec<Color> {
+        self.config.enemies_of(color).copied().collect()
+    }
+
     fn perform_move(
         &mut self,
         piece_id_to_move: &PieceId,
         new_position: &Point,
         enemy_piece_id: Option<PieceId>,
     ) {
+        let mut is_royal_captured = false;
         if let Some(piece_id) = enemy_piece_id {
+            is_royal_captured = self.board_map.is_royal(&piece_id);
             let piece = self.remove_piece(&piece_id);
+            if self.config.has_drops() && !is_royal_captured {
+                self.put_into_pocket(&piece, &piece_id_to_move.color());
+            }
             self.board_summary.piece_captured(piece);
         }
         let old_position = self
@@ -884,11 +1619,63 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
             .update_piece_position(piece_id_to_move, new_position);
         self.recalculate_connected_positions(&old_position, &piece_id_to_move.color(), false);
         self.recalculate_connected_positions(&new_position, &piece_id_to_move.color(), true);
-        self.recalculate_connected_positions(
-            &new_position,
-            &piece_id_to_move.color().inverse(),
-            false,
-        );
+        for enemy_color in self.enemies_of(&piece_id_to_move.color()) {
+            self.recalculate_connected_positions(&new_position, &enemy_color, false);
+        }
+        if is_royal_captured && let Some(piece_id) = enemy_piece_id {
+            self.captured_royals[&piece_id.color()] += 1;
+            self.update_king(&piece_id.color());
+        }
+        if self.config.is_atomic() && enemy_piece_id.is_some() {
+            self.explode(piece_id_to_move, new_position);
+        }
+    }
+
+    // The atomic capture removes the capturing piece and all non-pawn pieces around it from the
+    // board. Royal pieces of both sides can explode this way.
+    fn explode(&mut self, capturing_piece_id: &PieceId, point: &Point) {
+        let mut exploded_pieces = Self::exploded_pieces(&self.board_map, point);
+        exploded_pieces.push(*capturing_piece_id);
+        for piece_id in exploded_pieces {
+            let is_royal = self.board_map.is_royal(&piece_id);
+            let piece = self.remove_piece(&piece_id);
+            self.recalculate_connected_positions(
+                piece.current_position(),
+                &piece_id.color(),
+                false,
+            );
+            if is_royal {
+                self.captured_royals[&piece_id.color()] += 1;
+                self.update_king(&piece_id.color());
+            }
+        }
+    }
+
+    // Pieces around the destination of the atomic capture which explode together with the
+    // capturing piece. Pawns survive the explosion unless they capture or get captured.
+    fn exploded_pieces(board_map: &BoardMap, point: &Point) -> Vec<PieceId> {
+        Self::adjacent_points(point)
+            .filter_map(|point| board_map.piece_at(&point))
+            .filter(|piece| !matches!(piece, Piece::Pawn(_)))
+            .map(|piece| *piece.id())
+            .collect()
+    }
+
+    fn adjacent_points(point: &Point) -> impl Iterator<Item = Point> {
+        let point = *point;
+        Vector::diagonal_and_line_vectors()
+            .into_iter()
+            .map(move |direction| direction.calc_next_point(&point))
+    }
+
+    // Royal pieces standing next to each other can't be attacked in atomic chess, because
+    // capturing one of them would explode the other one as well
+    fn is_next_to_enemy_royal(board_map: &BoardMap, royal: &Piece) -> bool {
+        Self::adjacent_points(royal.current_position()).any(|point| {
+            board_map.piece_id_at(&point).is_some_and(|piece_id| {
+                piece_id.color().is_enemy(royal.color()) && board_map.is_royal(piece_id)
+            })
+        })
     }
 
     // Not every piece removal from the board is capturing. For example, when promoting a pawn - we
@@ -901,7 +1688,9 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
                 Debuff::Pin(v) => v,
                 _ => panic!("Logical error. Expected pin debuff, got: {:?}", debuff),
             };
-            self.x_ray_pieces[&piece_id.color().inverse()].remove_pinned_piece(vector);
+            for enemy_color in self.enemies_of(&piece_id.color()) {
+                self.x_ray_pieces[&enemy_color].remove_pinned_piece(vector);
+            }
         }
         let piece = self.board_map.remove_piece(piece_id);
         Self::remove_x_ray_piece(
@@ -914,7 +1703,7 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
             &mut self.debuffs_map,
             &mut self.x_ray_pieces,
         );
-        self.board_summary.remove_piece(piece_id);
+        self.board_summary.remove_piece(&piece);
         piece
     }
 
@@ -935,34 +1724,15 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
             .map(|piece_id| self.board_map.find_piece_by_id(piece_id))
             .collect::<Vec<_>>();
 
-        for piece in pieces_to_recalculate.iter() {
-            Self::calculate_strategy_points(
-                piece,
-                &self.board_map,
-                &self.buffs_map,
-                &self.config,
-                &mut self.strategy_points,
-            );
-        }
-
-        for piece in pieces_to_recalculate.iter() {
-            match piece {
-                Piece::King(_) => {
-                    // King moves are calculated separately
-                }
-                _ => {
-                    Self::calculate_moves_for(
-                        piece,
-                        &self.board_map,
-                        &self.buffs_map,
-                        &self.debuffs_map,
-                        &self.config,
-                        &self.strategy_points,
-                        &mut self.moves_map,
-                    );
-                }
-            }
-        }
+        Self::recalculate_pieces(
+            &pieces_to_recalculate,
+            &self.board_map,
+            &self.buffs_map,
+            &self.debuffs_map,
+            &self.config,
+            &mut self.strategy_points,
+            &mut self.moves_map,
+        );
 
         // This covers the case when an ally piece moves from the x-ray direction, thus causing
         // another ally piece, standing in front of it, be pinned. Example, white bishop(or black -
@@ -976,33 +1746,16 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         // 2 ▓♝▓ ░░░ ▓▓▓ ░░░ ▓▓▓ ░░░ ▓▓▓ ░░░
         // 1 ░♚░ ▓▓▓ ░♛░ ▓▓▓ ░░░ ▓▓▓ ░♖░ ▓♔▓
         //    a   b   c   d   e   f   g   h
-        for piece_id in self.x_ray_pieces[&caused_by_color.inverse()].pieces_owned() {
-            let piece = self.board_map.find_piece_by_id(&piece_id);
-            if let Some(x_ray_direction) = self
-                .x_ray_pieces(&caused_by_color.inverse())
-                .direction(&piece_id)
-            {
-                if let Some(direction) = Vector::calc_direction(&piece.current_position(), point) {
-                    if x_ray_direction == &direction {
-                        Self::calculate_x_ray(
-                            &piece,
-                            &self.board_map,
-                            &self.config,
-                            &self.strategy_points,
-                            &mut self.moves_map,
-                            &mut self.buffs_map,
-                            &mut self.debuffs_map,
-                            &mut self.x_ray_pieces,
-                        );
-                    }
-                }
-            }
-        }
-
-        // Re-calculate x-rays of x-ray pieces, explicitly affected by the current position
-        for piece in pieces_to_recalculate {
-            match &*piece {
-                Piece::Bishop(_) | Piece::Rook(_) | Piece::Queen(_) => {
+        // The same applies to x-ray pieces of the other side: a piece, leaving the x-ray direction,
+        // may cause an enemy piece, standing behind it, be pinned.
+        let mut sides = self.enemies_of(caused_by_color);
+        sides.push(*caused_by_color);
+        for color in sides {
+            for piece_id in self.x_ray_pieces[&color].pieces_owned() {
+                let piece = self.board_map.find_piece_by_id(&piece_id);
+                if let Some(x_ray_direction) = self.x_ray_pieces(&color).direction(&piece_id)
+                    && x_ray_direction.reaches(piece.current_position(), point)
+                {
                     Self::calculate_x_ray(
                         &piece,
                         &self.board_map,
@@ -1014,22 +1767,199 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
                         &mut self.x_ray_pieces,
                     );
                 }
-                _ => (),
             }
         }
+
+        // Re-calculate x-rays of x-ray pieces, explicitly affected by the current position
+        for piece in pieces_to_recalculate {
+            if piece.is_slider() {
+                Self::calculate_x_ray(
+                    &piece,
+                    &self.board_map,
+                    &self.config,
+                    &self.strategy_points,
+                    &mut self.moves_map,
+                    &mut self.buffs_map,
+                    &mut self.debuffs_map,
+                    &mut self.x_ray_pieces,
+                );
+            }
+        }
+    }
+
+    // Recalculates strategy points and moves of the pieces affected by a position change
+    fn recalculate_pieces(
+        pieces: &[&Piece],
+        board_map: &BoardMap,
+        cbuffs_map: &ColoredProperty<BuffsMap>,
+        cdebuffs_map: &ColoredProperty<DebuffsMap>,
+        config: &BoardConfig<HT, SQ>,
+        cstrategy_points: &mut ColoredProperty<StrategyPoints>,
+        cmoves_map: &mut ColoredProperty<MovesMap>,
+    ) {
+        #[cfg(feature = "sync")]
+        if config
+            .parallel_recalculation_threshold()
+            .is_some_and(|threshold| pieces.len() >= threshold)
+        {
+            Self::recalculate_pieces_in_parallel(
+                pieces,
+                board_map,
+                cbuffs_map,
+                cdebuffs_map,
+                config,
+                cstrategy_points,
+                cmoves_map,
+            );
+            return;
+        }
+
+        for piece in pieces.iter() {
+            Self::calculate_strategy_points(piece, board_map, cbuffs_map, config, cstrategy_points);
+        }
+
+        for piece in pieces.iter() {
+            match piece {
+                Piece::King(_) => {
+                    // King moves are calculated separately
+                }
+                _ => {
+                    Self::calculate_moves_for(
+                        piece,
+                        board_map,
+                        cbuffs_map,
+                        cdebuffs_map,
+                        config,
+                        cstrategy_points,
+                        cmoves_map,
+                    );
+                }
+            }
+        }
+    }
+
+    // Strategy points of the pieces don't depend on each other, so they are calculated
+    // concurrently and merged afterwards. The same goes for the moves, which only depend on the
+    // merged strategy points. Kings are skipped the same way as during sequential recalculation.
+    #[cfg(feature = "sync")]
+    fn recalculate_pieces_in_parallel(
+        pieces: &[&Piece],
+        board_map: &BoardMap,
+        cbuffs_map: &ColoredProperty<BuffsMap>,
+        cdebuffs_map: &ColoredProperty<DebuffsMap>,
+        config: &BoardConfig<HT, SQ>,
+        cstrategy_points: &mut ColoredProperty<StrategyPoints>,
+        cmoves_map: &mut ColoredProperty<MovesMap>,
+    ) {
+        let pieces_strategy_points = Self::map_in_parallel(pieces, |piece| {
+            let mut strategy_points = vec![];
+            Self::piece_strategy_points(piece, board_map, cbuffs_map, config, |strategy_point| {
+                strategy_points.push(strategy_point)
+            });
+            strategy_points
+        });
+        for (piece, strategy_points) in pieces.iter().zip(pieces_strategy_points) {
+            let color_strategy_points = &mut cstrategy_points[piece.color()];
+            color_strategy_points.remove_piece(piece.id());
+            for strategy_point in strategy_points {
+                color_strategy_points.add_association(strategy_point, piece.id());
+            }
+        }
+
+        let pieces = pieces
+            .iter()
+            .filter(|piece| !matches!(piece, Piece::King(_)))
+            .copied()
+            .collect::<Vec<_>>();
+        if config.is_lazy_moves() {
+            for piece in pieces {
+                cmoves_map[piece.color()].mark_dirty(piece.id());
+            }
+            return;
+        }
+        let cstrategy_points = &*cstrategy_points;
+        let pieces_moves = Self::map_in_parallel(&pieces, |piece| {
+            let mut moves = vec![];
+            Self::piece_moves(
+                piece,
+                board_map,
+                cbuffs_map,
+                cdebuffs_map,
+                config,
+                cstrategy_points,
+                |piece_move, move_score| moves.push((piece_move, move_score)),
+            );
+            moves
+        });
+        for (piece, moves) in pieces.iter().zip(pieces_moves) {
+            let moves_map = &mut cmoves_map[piece.color()];
+            moves_map.remove_piece(piece.id());
+            for (piece_move, move_score) in moves {
+                moves_map.add(piece.id(), piece_move, move_score);
+            }
+        }
+    }
+
+    // Calculates the pieces on the rayon thread pool, which threads are spawned once and reused
+    // by every recalculation. Results are returned in the order of the given pieces.
+    #[cfg(feature = "sync")]
+    fn map_in_parallel<T: Send, F: Fn(&Piece) -> T + Sync>(
+        pieces: &[&Piece],
+        calculate: F,
+    ) -> Vec<T> {
+        use rayon::prelude::*;
+
+        pieces.par_iter().map(|piece| calculate(piece)).collect()
     }
 
     fn recalculate_king_mechanics(&mut self, color: &Color) {
-        if let Some(king) = self.board_map.king(color) {
-            self.debuffs_map[king.color()].remove_check(king.id());
-            self.general_constraints[king.color()] = None;
-            if self.strategy_points[&king.color().inverse()]
-                .is_under_attack(&king.current_position())
+        self.general_constraints[color] = None;
+        for royal_id in self.board_map.royals(color) {
+            self.debuffs_map[color].remove_check(royal_id);
+        }
+        let protected_royals = self.protected_royals(color);
+        for royal_id in protected_royals.iter() {
+            let royal = self.board_map.find_piece_by_id(royal_id);
+            if self
+                .config
+                .enemies_of(color)
+                .any(|enemy| self.strategy_points[enemy].is_under_attack(royal.current_position()))
+                && !(self.config.is_atomic()
+                    && Self::is_next_to_enemy_royal(&self.board_map, royal))
             {
-                self.debuffs_map[king.color()].add(king.id(), Debuff::Check);
-                self.general_constraints[king.color()] = Some(MovesMap::empty());
+                self.debuffs_map[color].add(royal_id, Debuff::Check);
             }
-            Self::calculate_moves_for(
+        }
+        // King moves are always generated right away because they are copied into general
+        // constraints when the king is in check
+        let kings = self
+            .board_map
+            .active_pieces(color)
+            .values()
+            .filter(|piece| matches!(piece, Piece::King(_)))
+            .cloned()
+            .collect::<Vec<_>>();
+        for king in kings.iter() {
+            Self::generate_moves_for(
+                king,
+                &self.board_map,
+                &self.buffs_map,
+                &self.debuffs_map,
+                &self.config,
+                &self.strategy_points,
+                &mut self.moves_map[color],
+            );
+        }
+        // There is no check in antichess, but the side has to capture whenever it can
+        if self.config.is_antichess() {
+            self.general_constraints[color] = self.capture_constraints(color);
+            return;
+        }
+        if let Some(king) = self.board_map.king(color)
+            && self.debuffs_map[color].has_check(king.id())
+        {
+            self.general_constraints[color] = Some(MovesMap::empty());
+            Self::calculate_general_constraints(
                 king,
                 &self.board_map,
                 &self.buffs_map,
@@ -1037,24 +1967,474 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
                 &self.config,
                 &self.strategy_points,
                 &mut self.moves_map,
+                &mut self.general_constraints,
             );
-            if self.debuffs_map[king.color()].has_check(king.id()) {
-                Self::calculate_general_constraints(
-                    king,
-                    &self.board_map,
-                    &self.config,
-                    &self.strategy_points,
-                    &self.moves_map,
-                    &mut self.general_constraints,
+            // Copy king moves into general constraints
+            Self::add_king_moves_to_general_constraints(
+                king,
+                &self.moves_map,
+                &mut self.general_constraints,
+            );
+            Self::add_drops_to_general_constraints(
+                king,
+                &self.board_map,
+                &self.config,
+                &self.strategy_points,
+                &self.pockets,
+                &self.moves_map,
+                &mut self.general_constraints,
+            );
+        }
+        // Following constraints try every move on a copy of the board map, thus they are
+        // calculated only for the side to move
+        if *color == self.current_turn {
+            if self.board_map.king(color).is_none() && !protected_royals.is_empty() {
+                self.general_constraints[color] =
+                    Some(self.royal_constraints(color, &protected_royals));
+            }
+            if self.config.is_racing_kings() {
+                self.general_constraints[color] = Some(self.non_checking_constraints(color));
+            }
+            if self.config.has_drop_restrictions() {
+                self.exclude_drop_mates(color);
+            }
+        }
+        if self.config.has_promotion_limits() {
+            self.exclude_exhausted_promotions(color);
+        }
+    }
+
+    // Pawns can't promote to the pieces, which the side already has as many of as the limit
+    // allows, like in Grand chess
+    fn exclude_exhausted_promotions(&mut self, color: &Color) {
+        let stats = self.board_summary.stats();
+        let pieces_stats = &stats.active_pieces_stats[color];
+        let is_exhausted = |promote_piece: &PromotePiece| {
+            let name = promote_piece.name();
+            self.config
+                .promotion_limit(&name)
+                .is_some_and(|limit| pieces_stats.count_of(&name) >= limit as isize)
+        };
+        let moves = self.general_constraints[color]
+            .as_ref()
+            .unwrap_or_else(|| self.resolved_moves(color));
+        let mut constraints = MovesMap::empty();
+        for (move_score, piece_to_moves) in moves.score_to_moves() {
+            for (piece_id, piece_moves) in piece_to_moves {
+                for piece_move in piece_moves {
+                    if let PieceMove::Promote(_, promote_piece) = piece_move
+                        && is_exhausted(promote_piece)
+                    {
+                        continue;
+                    }
+                    constraints.add(piece_id, *piece_move, *move_score);
+                }
+            }
+        }
+        self.general_constraints[color] = Some(constraints);
+    }
+
+    // Drops of the restricted pieces must not checkmate, like the pawn drop in shogi
+    fn exclude_drop_mates(&mut self, color: &Color) {
+        let enemy_royals = self.enemy_royals(color);
+        let moves = self.general_constraints[color]
+            .as_ref()
+            .unwrap_or_else(|| self.resolved_moves(color));
+        let mut drop_mates = vec![];
+        for (piece_id, name) in self.pockets[color].droppable() {
+            if !self.config.is_drop_restricted(name) {
+                continue;
+            }
+            let Some(piece_moves) = moves.moves_of(&piece_id) else {
+                continue;
+            };
+            for piece_move in piece_moves.keys() {
+                let board_map = self.board_map_after(&piece_id, piece_move);
+                let is_mate = enemy_royals.iter().any(|(enemy_color, royals)| {
+                    Self::is_any_royal_attacked(
+                        enemy_color,
+                        royals,
+                        &board_map,
+                        &self.buffs_map,
+                        &self.config,
+                    ) && self.is_mate_after(piece_move.destination(), &board_map, enemy_color)
+                });
+                if is_mate {
+                    drop_mates.push((piece_id, *piece_move));
+                }
+            }
+        }
+        if drop_mates.is_empty() {
+            return;
+        }
+        let mut constraints = MovesMap::empty();
+        for (move_score, piece_to_moves) in moves.score_to_moves() {
+            for (piece_id, piece_moves) in piece_to_moves {
+                for piece_move in piece_moves {
+                    if !drop_mates.contains(&(*piece_id, *piece_move)) {
+                        constraints.add(piece_id, *piece_move, *move_score);
+                    }
+                }
+            }
+        }
+        self.general_constraints[color] = Some(constraints);
+    }
+
+    // Whether the given enemy, checked by the dropped piece, has no moves to escape from the check.
+    // The answers of the enemy are generated on the board map after the drop without pins and attacked
+    // squares, as those are not known yet, and then each of them is tried like the royal
+    // constraints do.
+    fn is_mate_after(&self, drop_point: &Point, board_map: &BoardMap, enemy_color: &Color) -> bool {
+        let enemy_royals = self.protected_royals(enemy_color);
+        let sides_count = self.config.sides().len();
+        let debuffs_map = ColoredProperty::filled(sides_count, DebuffsMap::empty());
+        let strategy_points = ColoredProperty::filled(sides_count, StrategyPoints::empty());
+        let mut answers = vec![];
+        for piece in board_map.active_pieces(enemy_color).values() {
+            Self::piece_moves(
+                piece,
+                board_map,
+                &self.buffs_map,
+                &debuffs_map,
+                &self.config,
+                &strategy_points,
+                |piece_move, _| {
+                    // The castle can't be made in check
+                    if !matches!(piece_move, PieceMove::Castle(_)) {
+                        answers.push((*piece.id(), piece_move));
+                    }
+                },
+            );
+        }
+        let enemy_moves = self.resolved_moves(enemy_color);
+        for (piece_id, _) in self.pockets[enemy_color].droppable() {
+            let Some(piece_moves) = enemy_moves.moves_of(&piece_id) else {
+                continue;
+            };
+            for piece_move in piece_moves.keys() {
+                if piece_move.destination() != drop_point {
+                    answers.push((piece_id, *piece_move));
+                }
+            }
+        }
+        answers.iter().all(|(piece_id, piece_move)| {
+            let next_board_map = self.next_board_map(board_map, piece_id, piece_move);
+            Self::is_any_royal_attacked(
+                enemy_color,
+                &enemy_royals,
+                &next_board_map,
+                &self.buffs_map,
+                &self.config,
+            )
+        })
+    }
+
+    // Legal moves of the given color, which do not give check to any of the enemy royal pieces
+    fn non_checking_constraints(&self, color: &Color) -> MovesMap {
+        let enemy_royals = self.enemy_royals(color);
+        let moves = self.general_constraints[color]
+            .as_ref()
+            .unwrap_or_else(|| self.resolved_moves(color));
+        let mut constraints = MovesMap::empty();
+        for (move_score, piece_to_moves) in moves.score_to_moves() {
+            for (piece_id, piece_moves) in piece_to_moves {
+                for piece_move in piece_moves {
+                    let board_map = self.board_map_after(piece_id, piece_move);
+                    let is_check = enemy_royals.iter().any(|(enemy_color, royals)| {
+                        Self::is_any_royal_attacked(
+                            enemy_color,
+                            royals,
+                            &board_map,
+                            &self.buffs_map,
+                            &self.config,
+                        )
+                    });
+                    if !is_check {
+                        constraints.add(piece_id, *piece_move, *move_score);
+                    }
+                }
+            }
+        }
+        constraints
+    }
+
+    // Capture moves of the given color, if there are any
+    fn capture_constraints(&self, color: &Color) -> Option<MovesMap> {
+        let mut constraints = MovesMap::empty();
+        for (move_score, piece_to_moves) in self.resolved_moves(color).score_to_moves() {
+            for (piece_id, piece_moves) in piece_to_moves {
+                for piece_move in piece_moves {
+                    if self.is_capture(color, piece_move) {
+                        constraints.add(piece_id, *piece_move, *move_score);
+                    }
+                }
+            }
+        }
+        if constraints.is_empty() {
+            None
+        } else {
+            Some(constraints)
+        }
+    }
+
+    fn is_capture(&self, color: &Color, piece_move: &PieceMove) -> bool {
+        match piece_move {
+            PieceMove::EnPassant(_, _) => true,
+            PieceMove::Point(point) | PieceMove::Promote(point, _) => self
+                .board_map
+                .piece_at(point)
+                .is_some_and(|piece| piece.is_enemy(color)),
+            PieceMove::LongMove(_) | PieceMove::Castle(_) | PieceMove::Drop(_) => false,
+        }
+    }
+
+    // Protected royal pieces of each enemy of the given color
+    fn enemy_royals(&self, color: &Color) -> Vec<(Color, Vec<PieceId>)> {
+        self.config
+            .enemies_of(color)
+            .map(|enemy_color| (*enemy_color, self.protected_royals(enemy_color)))
+            .collect()
+    }
+
+    // Royal pieces of the given color which must not be left under attack
+    fn protected_royals(&self, color: &Color) -> Vec<PieceId> {
+        let royals = self.board_map.royals(color);
+        match self.config.royal_rule() {
+            RoyalRule::AllCaptured if royals.len() > 1 => vec![],
+            _ => royals.to_vec(),
+        }
+    }
+
+    // Pins and checks are calculated against the king when it is the only protected royal piece
+    // of the side, which plays against a single opponent. Otherwise, the side has no king and its
+    // legal moves are found by royal_constraints. The same goes for atomic chess, where a pinned
+    // piece or a piece, giving check, can still explode the king, as well as for the flying
+    // general rule and for hopping pieces, which checks and pins are not tracked.
+    fn update_king(&mut self, color: &Color) {
+        let king_id = match self.protected_royals(color)[..] {
+            [royal_id]
+                if self.config.opponent(color).is_some()
+                    && !self.config.is_atomic()
+                    && !self.config.is_flying_general()
+                    && !self.config.piece_registry().has_hoppers()
+                    && matches!(self.board_map.find_piece_by_id(&royal_id), Piece::King(_)) =>
+            {
+                Some(royal_id)
+            }
+            _ => None,
+        };
+        let king_id_was = self.board_map.king_id(color).copied();
+        self.board_map.set_king(color, king_id);
+        // Enemy pieces can't capture the king, but can capture other pieces. Their moves towards
+        // the old and the new king have to be recalculated.
+        if king_id_was != king_id {
+            for royal_id in [king_id_was, king_id].into_iter().flatten() {
+                if let Some(royal) = self.board_map.maybe_find_piece_by_id(&royal_id) {
+                    let position = *royal.current_position();
+                    self.recalculate_connected_positions(&position, color, false);
+                }
+            }
+        }
+        self.calculate_x_rays_against(color);
+    }
+
+    // Legal moves of the side which protects several royal pieces or a royal piece other than the
+    // king. Pins and checks only work against a single king, thus each move is tried on a copy of
+    // the board map to find out whether it leaves any protected royal piece under attack.
+    fn royal_constraints(&self, color: &Color, protected_royals: &[PieceId]) -> MovesMap {
+        let mut constraints = MovesMap::empty();
+        for (move_score, piece_to_moves) in self.resolved_moves(color).score_to_moves() {
+            for (piece_id, piece_moves) in piece_to_moves {
+                for piece_move in piece_moves {
+                    let board_map = self.board_map_after(piece_id, piece_move);
+                    let is_legal = if self.config.is_atomic() {
+                        self.is_atomic_move_legal(color, protected_royals, &board_map)
+                    } else {
+                        !Self::is_any_royal_attacked(
+                            color,
+                            protected_royals,
+                            &board_map,
+                            &self.buffs_map,
+                            &self.config,
+                        )
+                    };
+                    if is_legal {
+                        constraints.add(piece_id, *piece_move, *move_score);
+                    }
+                }
+            }
+        }
+        constraints
+    }
+
+    // The side must not explode any of its own royal pieces. Exploding an enemy royal piece wins the
+    // game, thus such move is legal even when it leaves own royal pieces under attack.
+    fn is_atomic_move_legal(
+        &self,
+        color: &Color,
+        protected_royals: &[PieceId],
+        board_map: &BoardMap,
+    ) -> bool {
+        if board_map.royals(color).len() < self.board_map.royals(color).len() {
+            return false;
+        }
+        if self.config.enemies_of(color).any(|enemy_color| {
+            board_map.royals(enemy_color).len() < self.board_map.royals(enemy_color).len()
+        }) {
+            return true;
+        }
+        let exposed_royals = protected_royals
+            .iter()
+            .filter(|royal_id| {
+                !Self::is_next_to_enemy_royal(board_map, board_map.find_piece_by_id(royal_id))
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        !Self::is_any_royal_attacked(
+            color,
+            &exposed_royals,
+            board_map,
+            &self.buffs_map,
+            &self.config,
+        )
+    }
+
+    // Positions of the pieces after the given move. It is only used to find out which points are
+    // attacked, thus the promoted pawn is not replaced by the new piece.
+    fn board_map_after(&self, piece_id: &PieceId, piece_move: &PieceMove) -> BoardMap {
+        self.next_board_map(&self.board_map, piece_id, piece_move)
+    }
+
+    // Positions of the pieces after the given move is made on the given board map
+    fn next_board_map(
+        &self,
+        board_map: &BoardMap,
+        piece_id: &PieceId,
+        piece_move: &PieceMove,
+    ) -> BoardMap {
+        let mut board_map = board_map.clone();
+        let mut capture_point = None;
+        match piece_move {
+            PieceMove::Point(point) | PieceMove::LongMove(point) | PieceMove::Promote(point, _) => {
+                if let Some(&enemy_piece_id) = board_map.piece_id_at(point) {
+                    board_map.remove_piece(&enemy_piece_id);
+                    capture_point = Some(*point);
+                }
+                board_map.change_piece_position(point, piece_id);
+            }
+            PieceMove::EnPassant(point, enemy_piece_point) => {
+                if let Some(&enemy_piece_id) = board_map.piece_id_at(enemy_piece_point) {
+                    board_map.remove_piece(&enemy_piece_id);
+                    capture_point = Some(*point);
+                }
+                board_map.change_piece_position(point, piece_id);
+            }
+            PieceMove::Castle(castle_points) => {
+                // The king and the rook may land on the initial points of each other in chess960,
+                // so both of them are lifted before landing
+                let &rook_id = board_map
+                    .piece_id_at(castle_points.initial_rook_point())
+                    .expect(
+                        "Logical mistake: expect the rook to be present during the castle move.",
+                    );
+                let king = board_map.remove_piece(piece_id);
+                let rook = board_map.remove_piece(&rook_id);
+                board_map.add_piece(king, *castle_points.king_point());
+                board_map.add_piece(rook, *castle_points.rook_point());
+            }
+            PieceMove::Drop(point) => {
+                let name = self.pockets[&piece_id.color()].name_of(piece_id).expect(
+                    "Logical mistake: expect the piece to be in the pocket during the drop.",
                 );
-                // Copy king moves into general constraints
-                Self::add_king_moves_to_general_constraints(
-                    &king,
-                    &self.moves_map,
-                    &mut self.general_constraints,
+                let piece = self.config.piece_registry().init_piece(
+                    name,
+                    piece_id.color(),
+                    *point,
+                    *piece_id,
                 );
+                board_map.add_piece(piece, *point);
+            }
+        }
+        if self.config.is_atomic()
+            && let Some(point) = capture_point
+        {
+            for exploded_piece_id in Self::exploded_pieces(&board_map, &point) {
+                board_map.remove_piece(&exploded_piece_id);
             }
+            board_map.remove_piece(piece_id);
         }
+        board_map
+    }
+
+    fn is_any_royal_attacked(
+        color: &Color,
+        royals: &[PieceId],
+        board_map: &BoardMap,
+        cbuffs_map: &ColoredProperty<BuffsMap>,
+        config: &BoardConfig<HT, SQ>,
+    ) -> bool {
+        let royal_points = royals
+            .iter()
+            .filter_map(|royal_id| board_map.maybe_find_piece_by_id(royal_id))
+            .map(|royal| *royal.current_position())
+            .collect::<Vec<_>>();
+        if config.is_flying_general()
+            && Self::is_facing_enemy_royal(color, &royal_points, board_map, config)
+        {
+            return true;
+        }
+        config
+            .enemies_of(color)
+            .flat_map(|enemy_color| board_map.active_pieces(enemy_color).values())
+            .any(|piece| {
+                let mut is_attacking = false;
+                Self::piece_strategy_points(
+                    piece,
+                    board_map,
+                    cbuffs_map,
+                    config,
+                    |strategy_point| {
+                        if let StrategyPoint::Attack(point) = strategy_point
+                            && royal_points.contains(&point)
+                        {
+                            is_attacking = true;
+                        }
+                    },
+                );
+                is_attacking
+            })
+    }
+
+    // Whether any of the given points is on the same file with an enemy royal piece and there are
+    // no pieces in between
+    fn is_facing_enemy_royal(
+        color: &Color,
+        royal_points: &[Point],
+        board_map: &BoardMap,
+        config: &BoardConfig<HT, SQ>,
+    ) -> bool {
+        config
+            .enemies_of(color)
+            .flat_map(|enemy_color| board_map.royals(enemy_color))
+            .map(|royal_id| *board_map.find_piece_by_id(royal_id).current_position())
+            .any(|enemy_point| {
+                royal_points.iter().any(|point| {
+                    let x = *point.x().value();
+                    let (y1, y2) = (*point.y().value(), *enemy_point.y().value());
+                    x == *enemy_point.x().value()
+                        && (y1.min(y2) + 1..y1.max(y2))
+                            .all(|y| board_map.board_square(&Point::new(x, y)).is_empty_square())
+                })
+            })
+    }
+
+    // Turn counters of the position, which did not start from the beginning of the game
+    pub fn set_turn_number(&mut self, turn_number: usize, reversible_turns: usize) {
+        self.board_summary.set_turn_number(turn_number, reversible_turns);
+    }
+
+    pub fn set_checks_given(&mut self, color: &Color, checks: u8) {
+        self.board_summary.set_checks_given(color, checks);
     }
 
     pub fn set_pov(&mut self, color: Color) {
@@ -1077,13 +2457,23 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         &self.debuffs_map[color]
     }
 
-    fn x_ray_direction(piece: &Piece, opposite_king: &Piece) -> Option<Vector> {
-        match piece {
-            Piece::Bishop(_) | Piece::Rook(_) | Piece::Queen(_) => {
-                piece.attack_vector(&piece.current_position(), &opposite_king.current_position())
-            }
-            _ => None,
-        }
+    // Direction the piece slides along towards the opposite king. A piece with limited ride
+    // distance has no x-ray when the king is out of its reach.
+    fn x_ray_direction(
+        piece: &Piece,
+        opposite_king: &Piece,
+        config: &BoardConfig<HT, SQ>,
+    ) -> Option<Vector> {
+        let (position, king_position) =
+            (piece.current_position(), opposite_king.current_position());
+        piece
+            .attack_vector(position, king_position)
+            .filter(|vector| piece.slides_along(vector))
+            .filter(|vector| {
+                config
+                    .max_ride_distance(piece.name())
+                    .is_none_or(|max| vector.steps_between(position, king_position) <= max as i16)
+            })
     }
 
     fn remove_x_ray_piece(
@@ -1117,10 +2507,10 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
         cmoves_map: &mut ColoredProperty<MovesMap>,
         cbuffs_map: &mut ColoredProperty<BuffsMap>,
         cdebuffs_map: &mut ColoredProperty<DebuffsMap>,
-        cx_ray_pieces: &ColoredProperty<XRayPieces>,
+        cx_ray_pieces: &mut ColoredProperty<XRayPieces>,
     ) {
-        if let Some(pinned_id) = cx_ray_pieces[&piece_id.color()].pinned_piece(piece_id) {
-            let pinned = board_map.find_piece_by_id(pinned_id);
+        if let Some(pinned_id) = cx_ray_pieces[&piece_id.color()].remove_pin(piece_id) {
+            let pinned = board_map.find_piece_by_id(&pinned_id);
             cdebuffs_map[pinned.color()].remove_pin(pinned.id());
             Self::calculate_moves_for(
                 pinned,
@@ -1137,84 +2527,7 @@ impl<HT: HeatMap, SQ: SquaresMap> Board<HT, SQ> {
 
 impl<HT: HeatMap, SQ: SquaresMap> PrettyPrint for Board<HT, SQ> {
     fn pp(&self) -> String {
-        let mut output = String::new();
-        let mut buf: Vec<String> = vec![];
-
-        let y_range: Vec<i16> = if self.pov == Color::White {
-            (*self.dimension().min_point().y().value()..=*self.dimension().max_point().y().value())
-                .rev()
-                .collect()
-        } else {
-            (*self.dimension().min_point().y().value()..=*self.dimension().max_point().y().value())
-                .collect()
-        };
-        let x_range: Vec<i16> = if self.pov == Color::White {
-            (*self.dimension().min_point().x().value()..=*self.dimension().max_point().x().value())
-                .collect()
-        } else {
-            (*self.dimension().min_point().x().value()..=*self.dimension().max_point().x().value())
-                .rev()
-                .collect()
-        };
-
-        for y in y_range {
-            for x in x_range.clone() {
-                let point = Point::new(x, y);
-                let square = self.board_square(&point);
-                if (self.pov == Color::White && point.x() == self.dimension().min_point().x())
-                    || (self.pov == Color::Black && point.x() == self.dimension().max_point().x())
-                {
-                    output.push_str(point.y().pp().as_str());
-                    output.push_str(" ");
-                }
-                let square_str = match square.get_piece_id() {
-                    Some(piece_id) => {
-                        let piece = self.find_piece_by_id(piece_id).unwrap();
-                        square
-                            .pp()
-                            .replace(&piece_id.to_string(), piece.pp().as_str())
-                    }
-                    None => square.pp(),
-                };
-                output.push_str(square_str.as_str());
-                output.push(' ');
-                if (self.pov == Color::White && point.x() == self.dimension().max_point().x())
-                    || (self.pov == Color::Black && point.x() == self.dimension().min_point().x())
-                {
-                    output.push_str("\n");
-                    buf.push(output.clone());
-                    output = String::new();
-                }
-            }
-        }
-        output.push_str("  ");
-
-        let vector_points = if self.pov == Color::White {
-            VectorPoints::with_initial(
-                Point::new(
-                    *self.dimension().min_point().x().value(),
-                    *self.dimension().max_point().y().value(),
-                ),
-                *self.dimension(),
-                Vector::Line(LineVector::Right),
-            )
-        } else {
-            VectorPoints::with_initial(
-                Point::new(
-                    *self.dimension().max_point().x().value(),
-                    *self.dimension().max_point().y().value(),
-                ),
-                *self.dimension(),
-                Vector::Line(LineVector::Left),
-            )
-        };
-        for point in vector_points {
-            output.push_str(" ");
-            output.push_str(point.x().pp().as_str());
-            output.push_str("  ");
-        }
-        buf.push(output);
-        buf.join("")
+        self.board_map.pp(self.dimension(), &self.pov)
     }
 }
 
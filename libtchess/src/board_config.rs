@@ -1,20 +1,89 @@
+use crate::board_region::BoardRegion;
+use crate::castle_points::CastleSide;
+use crate::castle_rule::CastleRule;
 use crate::castle_x_points::CastleXPoints;
 use crate::color::Color;
+use crate::collections::{HashMap, HashSet};
+use crate::colored_property::ColoredProperty;
 use crate::dimension::Dimension;
 use crate::heat_map::HeatMap;
+use crate::piece_registry::PieceRegistry;
 use crate::player::Player;
+use crate::point::Point;
+use crate::promote_piece::PromotePiece;
+use crate::promotion_zone::PromotionZone;
+use crate::royal_rule::RoyalRule;
 use crate::squares_map::SquaresMap;
+use crate::variant::Variant;
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct BoardConfig<HT: HeatMap, SM: SquaresMap> {
-    king_side_castle_x_points: CastleXPoints,
-    queen_side_castle_x_points: CastleXPoints,
+    // Ways the king can castle. Contains the king side and the queen side rules, given to the
+    // constructor, by default.
+    castle_rules: Vec<CastleRule>,
     heat_map: HT,
     squares_map: SM,
     dimension: Dimension,
-    white_side_player: Player,
-    black_side_player: Player,
+    // Sides of the game in the order of their turns. White and Black by default.
+    sides: Vec<Color>,
+    players: ColoredProperty<Player>,
     evaluation_required: bool,
+    // When enabled, moves of the pieces are not generated right after the position changes, but
+    // only when they are requested.
+    lazy_moves: bool,
+    // Minimal number of pieces, affected by a position change, which strategy points and moves are
+    // recalculated in parallel. Only available with "sync" feature, as the board can't be shared
+    // between threads otherwise.
+    #[cfg(feature = "sync")]
+    parallel_recalculation_threshold: Option<usize>,
+    // Pieces which can be added to the board. Contains classic chess pieces by default.
+    piece_registry: PieceRegistry,
+    // Squares where pawns of each color get promoted. The last rank by default.
+    promotion_zones: ColoredProperty<PromotionZone>,
+    // Pieces a pawn can promote to. When not set, a pawn can promote to any registered piece.
+    promote_pieces: Option<Vec<PromotePiece>>,
+    // Number of squares a pawn with Buff::AdditionalPoint can go on its first move. Each skipped
+    // square can be captured en passant.
+    pawn_initial_steps: u8,
+    // Max number of points a riding piece can go in one move, by the name of the piece. Pieces
+    // which are not listed ride until they meet an obstacle.
+    max_ride_distances: HashMap<String, u16>,
+    // Names of the pieces which are protected from check. Only the king by default.
+    royal_pieces: HashSet<String>,
+    // Whether the side, having several royal pieces, loses when any of them is mated or only when
+    // all of them are captured
+    royal_rule: RoyalRule,
+    // When enabled, captured pieces go into the pocket of the capturing side and can be dropped
+    // back to the board as a move, like in Crazyhouse
+    drops: bool,
+    // When enabled, captured pieces are not kept in the pocket of the capturing side, but passed
+    // to the partner on the other board, like in Bughouse
+    pass_captures: bool,
+    // Rules of the variant on top of the classic ones. Classic by default.
+    variant: Variant,
+    // The side wins when its king reaches any of these squares, like in King of the Hill. Empty
+    // by default.
+    hill_squares: Vec<Point>,
+    // Promoted forms of the pieces by their names, like in shogi. Such piece may promote when it
+    // enters, leaves or moves within the promotion zone of its color.
+    promotions: HashMap<String, String>,
+    // Names of the pieces which can't be dropped on the file, where the side already has a piece
+    // of the same kind, nor be dropped to checkmate, like the pawn in shogi
+    drop_restricted_pieces: HashSet<String>,
+    // Names of the pieces which must promote whenever they can, like the soldier in xiangqi
+    compulsory_promotions: HashSet<String>,
+    // Regions of the board the pieces can't leave, by the names of the pieces
+    piece_regions: HashMap<String, BoardRegion>,
+    // When enabled, royal pieces of both sides must not face each other on the same file without
+    // any piece in between, like the generals in xiangqi
+    flying_general: bool,
+    // When enabled, a pawn may stay unpromoted within the promotion zone, unless it can't go any
+    // further, like in Grand chess
+    optional_pawn_promotions: bool,
+    // Max number of pieces of the same side, a pawn can promote to, by the names of the pieces.
+    // Once the side has that many of them, its pawns can't promote to such piece, like in Grand
+    // chess.
+    promotion_limits: HashMap<String, u8>,
 }
 
 impl<HT, SM> BoardConfig<HT, SM>
@@ -34,23 +103,46 @@ where
         let evaluation_required =
             white_side_player == Player::Computer || black_side_player == Player::Computer;
         Self {
-            king_side_castle_x_points,
-            queen_side_castle_x_points,
+            castle_rules: vec![
+                CastleRule::from_x_points(CastleSide::King, king_side_castle_x_points),
+                CastleRule::from_x_points(CastleSide::Queen, queen_side_castle_x_points),
+            ],
             heat_map,
             squares_map,
             dimension,
-            white_side_player,
-            black_side_player,
+            sides: vec![Color::White, Color::Black],
+            players: ColoredProperty::from([white_side_player, black_side_player]),
             evaluation_required,
+            lazy_moves: false,
+            #[cfg(feature = "sync")]
+            parallel_recalculation_threshold: None,
+            piece_registry: PieceRegistry::classic(),
+            promotion_zones: ColoredProperty::from([PromotionZone::LastRank, PromotionZone::LastRank]),
+            promote_pieces: None,
+            pawn_initial_steps: 2,
+            max_ride_distances: HashMap::new(),
+            royal_pieces: HashSet::unit("King".to_string()),
+            royal_rule: RoyalRule::AnyMated,
+            drops: false,
+            pass_captures: false,
+            variant: Variant::Classic,
+            hill_squares: vec![],
+            promotions: HashMap::new(),
+            drop_restricted_pieces: HashSet::new(),
+            compulsory_promotions: HashSet::new(),
+            piece_regions: HashMap::new(),
+            flying_general: false,
+            optional_pawn_promotions: false,
+            promotion_limits: HashMap::new(),
         }
     }
 
-    pub fn king_side_castle_x_points(&self) -> &CastleXPoints {
-        &self.king_side_castle_x_points
+    pub fn set_castle_rules(&mut self, castle_rules: Vec<CastleRule>) {
+        self.castle_rules = castle_rules;
     }
 
-    pub fn queen_side_castle_x_points(&self) -> &CastleXPoints {
-        &self.queen_side_castle_x_points
+    pub fn castle_rules(&self) -> &[CastleRule] {
+        &self.castle_rules
     }
 
     pub fn heat_map(&self) -> &HT {
@@ -65,14 +157,300 @@ where
         &self.dimension
     }
 
-    pub fn player(&self, color: &Color) -> &Player {
-        match color {
-            Color::White => &self.white_side_player,
-            Color::Black => &self.black_side_player,
+    // Sides must be indexed from zero, but may take their turns in any order. Sides beyond the
+    // classic two are played by humans and get promoted on their last rank by default.
+    pub fn set_sides(&mut self, sides: Vec<Color>) {
+        let mut indices = sides.iter().map(|side| side.index()).collect::<Vec<_>>();
+        indices.sort();
+        if indices != (0..sides.len()).collect::<Vec<_>>() {
+            panic!("Sides {:?} must be indexed from zero without gaps!", sides);
+        }
+        self.players.0.resize(sides.len(), Player::Human);
+        self.promotion_zones.0.resize(sides.len(), PromotionZone::LastRank);
+        self.sides = sides;
+    }
+
+    pub fn sides(&self) -> &[Color] {
+        &self.sides
+    }
+
+    // The side which takes the turn after the given one
+    pub fn next_side(&self, color: &Color) -> &Color {
+        let position = self
+            .sides
+            .iter()
+            .position(|side| side == color)
+            .unwrap_or_else(|| panic!("{:?} does not play on the board!", color));
+        &self.sides[(position + 1) % self.sides.len()]
+    }
+
+    pub fn enemies_of(&self, color: &Color) -> impl Iterator<Item = &Color> {
+        let color = *color;
+        self.sides.iter().filter(move |side| side.is_enemy(&color))
+    }
+
+    // The only enemy of the side in the game of two sides. Pins and checks are tracked against the
+    // king of the opponent, while the sides of bigger games try their moves instead.
+    pub fn opponent(&self, color: &Color) -> Option<&Color> {
+        if self.sides.len() != 2 {
+            return None;
         }
+        self.enemies_of(color).next()
+    }
+
+    pub fn player(&self, color: &Color) -> &Player {
+        &self.players[color]
     }
 
     pub fn is_evaluation_required(&self) -> bool {
         self.evaluation_required
     }
+
+    pub fn set_lazy_moves(&mut self, lazy_moves: bool) {
+        self.lazy_moves = lazy_moves;
+    }
+
+    pub fn is_lazy_moves(&self) -> bool {
+        self.lazy_moves
+    }
+
+    #[cfg(feature = "sync")]
+    pub fn set_parallel_recalculation_threshold(&mut self, threshold: Option<usize>) {
+        self.parallel_recalculation_threshold = threshold;
+    }
+
+    #[cfg(feature = "sync")]
+    pub fn parallel_recalculation_threshold(&self) -> Option<usize> {
+        self.parallel_recalculation_threshold
+    }
+
+    pub fn set_piece_registry(&mut self, piece_registry: PieceRegistry) {
+        self.piece_registry = piece_registry;
+    }
+
+    pub fn piece_registry(&self) -> &PieceRegistry {
+        &self.piece_registry
+    }
+
+    pub fn set_promotion_zone(&mut self, color: &Color, promotion_zone: PromotionZone) {
+        self.promotion_zones[color] = promotion_zone;
+    }
+
+    pub fn promotion_zone(&self, color: &Color) -> &PromotionZone {
+        &self.promotion_zones[color]
+    }
+
+    pub fn is_promotion_square(&self, point: &Point, color: &Color) -> bool {
+        match &self.promotion_zones[color] {
+            // The pawn can't go any further from the last rank
+            PromotionZone::LastRank => !self
+                .dimension
+                .is_in_boundaries(&color.orientation().forward().calc_next_point(point)),
+            PromotionZone::Ranks(ranks) => ranks.contains(point.y().value()),
+            PromotionZone::Squares(squares) => squares.contains(point),
+            PromotionZone::SquaresMap => self.squares_map.is_promotion_square(point, color),
+        }
+    }
+
+    pub fn set_promote_pieces(&mut self, promote_pieces: Vec<PromotePiece>) {
+        self.promote_pieces = Some(promote_pieces);
+    }
+
+    pub fn promote_pieces(&self) -> &[PromotePiece] {
+        match &self.promote_pieces {
+            Some(promote_pieces) => promote_pieces,
+            None => self.piece_registry.promote_pieces(),
+        }
+    }
+
+    pub fn set_pawn_initial_steps(&mut self, pawn_initial_steps: u8) {
+        self.pawn_initial_steps = pawn_initial_steps;
+    }
+
+    pub fn pawn_initial_steps(&self) -> u8 {
+        self.pawn_initial_steps
+    }
+
+    pub fn set_max_ride_distance(&mut self, piece_name: &str, max_ride_distance: u16) {
+        self.max_ride_distances
+            .insert(piece_name.to_string(), max_ride_distance);
+    }
+
+    pub fn max_ride_distance(&self, piece_name: &str) -> Option<u16> {
+        self.max_ride_distances.get(piece_name).copied()
+    }
+
+    pub fn is_ride_distance_limited(&self) -> bool {
+        !self.max_ride_distances.is_empty()
+    }
+
+    pub fn set_royal_pieces(&mut self, piece_names: &[&str]) {
+        self.royal_pieces = piece_names.iter().map(|name| name.to_string()).collect();
+    }
+
+    // There are no royal pieces in antichess
+    pub fn is_royal(&self, piece_name: &str) -> bool {
+        !self.is_antichess() && self.royal_pieces.contains(piece_name)
+    }
+
+    // Whether the king is the only royal piece, like in the classic chess
+    pub fn has_classic_royals(&self) -> bool {
+        self.royal_pieces.len() == 1 && self.is_royal("King")
+    }
+
+    pub fn set_royal_rule(&mut self, royal_rule: RoyalRule) {
+        self.royal_rule = royal_rule;
+    }
+
+    pub fn royal_rule(&self) -> &RoyalRule {
+        &self.royal_rule
+    }
+
+    pub fn set_drops(&mut self, drops: bool) {
+        self.drops = drops;
+    }
+
+    pub fn has_drops(&self) -> bool {
+        self.drops
+    }
+
+    pub fn set_pass_captures(&mut self, pass_captures: bool) {
+        self.pass_captures = pass_captures;
+    }
+
+    pub fn passes_captures(&self) -> bool {
+        self.pass_captures
+    }
+
+    pub fn set_variant(&mut self, variant: Variant) {
+        self.variant = variant;
+    }
+
+    pub fn variant(&self) -> &Variant {
+        &self.variant
+    }
+
+    pub fn is_atomic(&self) -> bool {
+        self.variant == Variant::Atomic
+    }
+
+    pub fn is_antichess(&self) -> bool {
+        self.variant == Variant::Antichess
+    }
+
+    pub fn check_limit(&self) -> Option<u8> {
+        match self.variant {
+            Variant::CheckLimit(check_limit) => Some(check_limit),
+            _ => None,
+        }
+    }
+
+    pub fn set_hill_squares(&mut self, hill_squares: Vec<Point>) {
+        self.hill_squares = hill_squares;
+    }
+
+    pub fn is_hill_square(&self, point: &Point) -> bool {
+        self.hill_squares.contains(point)
+    }
+
+    pub fn is_racing_kings(&self) -> bool {
+        self.variant == Variant::RacingKings
+    }
+
+    pub fn set_promotion(&mut self, piece_name: &str, promoted_name: &str) {
+        self.promotions
+            .insert(piece_name.to_string(), promoted_name.to_string());
+    }
+
+    pub fn promotion_of(&self, piece_name: &str) -> Option<&str> {
+        self.promotions.get(piece_name).map(|name| name.as_str())
+    }
+
+    pub fn has_promotions(&self) -> bool {
+        !self.promotions.is_empty()
+    }
+
+    // Name of the piece, the given piece turns into with the given promotion
+    pub fn promoted_name(&self, piece_name: &str, promote_piece: &PromotePiece) -> String {
+        match promote_piece {
+            PromotePiece::Promoted => self
+                .promotion_of(piece_name)
+                .unwrap_or_else(|| panic!("{piece_name} does not have a promoted form!"))
+                .to_string(),
+            _ => promote_piece.name(),
+        }
+    }
+
+    pub fn set_drop_restricted_pieces(&mut self, piece_names: &[&str]) {
+        self.drop_restricted_pieces = piece_names.iter().map(|name| name.to_string()).collect();
+    }
+
+    pub fn is_drop_restricted(&self, piece_name: &str) -> bool {
+        self.drop_restricted_pieces.contains(piece_name)
+    }
+
+    pub fn has_drop_restrictions(&self) -> bool {
+        !self.drop_restricted_pieces.is_empty()
+    }
+
+    pub fn set_compulsory_promotions(&mut self, piece_names: &[&str]) {
+        self.compulsory_promotions = piece_names.iter().map(|name| name.to_string()).collect();
+    }
+
+    pub fn is_promotion_compulsory(&self, piece_name: &str) -> bool {
+        self.compulsory_promotions.contains(piece_name)
+    }
+
+    pub fn set_piece_region(&mut self, piece_name: &str, region: BoardRegion) {
+        self.piece_regions.insert(piece_name.to_string(), region);
+    }
+
+    pub fn piece_region(&self, piece_name: &str) -> Option<&BoardRegion> {
+        self.piece_regions.get(piece_name)
+    }
+
+    pub fn has_piece_regions(&self) -> bool {
+        !self.piece_regions.is_empty()
+    }
+
+    // Whether the piece of the given name and color can stand on the given point
+    pub fn is_in_piece_region(&self, piece_name: &str, point: &Point, color: &Color) -> bool {
+        self.piece_region(piece_name)
+            .is_none_or(|region| self.squares_map.is_in_region(point, region, color))
+    }
+
+    pub fn set_flying_general(&mut self, flying_general: bool) {
+        self.flying_general = flying_general;
+    }
+
+    pub fn is_flying_general(&self) -> bool {
+        self.flying_general
+    }
+
+    pub fn set_optional_pawn_promotions(&mut self, optional_pawn_promotions: bool) {
+        self.optional_pawn_promotions = optional_pawn_promotions;
+    }
+
+    pub fn has_optional_pawn_promotions(&self) -> bool {
+        self.optional_pawn_promotions
+    }
+
+    pub fn set_promotion_limit(&mut self, piece_name: &str, limit: u8) {
+        self.promotion_limits.insert(piece_name.to_string(), limit);
+    }
+
+    pub fn promotion_limit(&self, piece_name: &str) -> Option<u8> {
+        self.promotion_limits.get(piece_name).copied()
+    }
+
+    pub fn has_promotion_limits(&self) -> bool {
+        !self.promotion_limits.is_empty()
+    }
+
+    // Pawns can't be dropped where pawns of any color get promoted
+    pub fn is_pawn_drop_allowed(&self, point: &Point) -> bool {
+        self.sides
+            .iter()
+            .all(|side| !self.is_promotion_square(point, side))
+    }
 }
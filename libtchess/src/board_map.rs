@@ -1,6 +1,11 @@
-use im_rc::HashMap;
+use crate::collections::HashMap;
 use crate::board_square::{BoardSquare};
 use crate::color::Color;
+use crate::dimension::Dimension;
+use crate::utils::pretty_print::PrettyPrint;
+use crate::vector::Vector;
+use crate::vector::line_vector::LineVector;
+use crate::vector_points::VectorPoints;
 use crate::colored_property::ColoredProperty;
 use crate::piece::Piece;
 use crate::piece_id::PieceId;
@@ -12,15 +17,19 @@ use crate::square::Square;
 pub struct BoardMap {
     point_to_board_square: HashMap<Point, BoardSquare, FxBuildHasher>,
     active_pieces: ColoredProperty<HashMap<PieceId, Piece, FxBuildHasher>>,
+    // The royal piece, which pins and checks are calculated against. There is no such piece when
+    // the side protects several royal pieces or a royal piece other than the king.
     king: ColoredProperty<Option<PieceId>>,
+    royals: ColoredProperty<Vec<PieceId>>,
 }
 
 impl BoardMap {
-    pub fn empty() -> Self {
+    pub fn empty(sides_count: usize) -> Self {
         Self {
             point_to_board_square: HashMap::default(),
-            active_pieces: ColoredProperty([HashMap::default(), HashMap::default()]),
-            king: ColoredProperty([None, None]),
+            active_pieces: ColoredProperty::filled(sides_count, HashMap::default()),
+            king: ColoredProperty::filled(sides_count, None),
+            royals: ColoredProperty::filled(sides_count, vec![]),
         }
     }
 
@@ -44,6 +53,13 @@ impl BoardMap {
             .unwrap_or(&BoardSquare::VoidSquare)
     }
 
+    pub fn empty_points(&self) -> impl Iterator<Item = &Point> {
+        self.point_to_board_square
+            .iter()
+            .filter(|(_, square)| square.is_empty_square())
+            .map(|(point, _)| point)
+    }
+
     pub fn add_square(&mut self, point: Point, square: BoardSquare) {
         self.point_to_board_square.insert(point, square);
     }
@@ -54,12 +70,6 @@ impl BoardMap {
         square.set_piece_id(piece.id());
         piece.set_current_position(point);
 
-        match piece {
-            Piece::King(_) => {
-                self.king[piece.color()] = Some(*piece.id());
-            }
-            _ => (),
-        }
         self.active_pieces[piece.color()].insert(*piece.id(), piece);
     }
 
@@ -70,10 +80,10 @@ impl BoardMap {
         Self::get_square_mut(&piece.current_position(), &mut self.point_to_board_square)
             .remove_piece_id();
 
-        match piece {
-            Piece::King(_) => self.king[&piece_id.color()] = None,
-            _ => (),
+        if self.king[&piece_id.color()] == Some(*piece_id) {
+            self.king[&piece_id.color()] = None;
         }
+        self.royals[&piece_id.color()].retain(|royal_id| royal_id != piece_id);
         piece
     }
 
@@ -89,6 +99,26 @@ impl BoardMap {
         old_position
     }
 
+    // Moves several pieces at once. Every piece is lifted before any of them lands, so a piece may
+    // land on the point another one has left, e.g. when the king and the rook swap their places
+    // during the castle. Returns the old positions of the pieces.
+    pub fn change_pieces_positions(&mut self, moves: &[(PieceId, Point)]) -> Vec<Point> {
+        let old_positions = moves
+            .iter()
+            .map(|(piece_id, _)| {
+                let position = *self.find_piece_by_id(piece_id).current_position();
+                Self::get_square_mut(&position, &mut self.point_to_board_square).remove_piece_id();
+                position
+            })
+            .collect();
+        for (piece_id, to_point) in moves {
+            Self::get_square_mut(to_point, &mut self.point_to_board_square).set_piece_id(piece_id);
+            self.find_piece_by_id_mut(piece_id)
+                .set_current_position(*to_point);
+        }
+        old_positions
+    }
+
     fn get_square_mut<'a>(
         point: &Point,
         point_to_board_square: &'a mut HashMap<Point, BoardSquare, FxBuildHasher>,
@@ -117,6 +147,26 @@ impl BoardMap {
         self.king[color].as_ref()
     }
 
+    pub fn kings(&self) -> &ColoredProperty<Option<PieceId>> {
+        &self.king
+    }
+
+    pub fn set_king(&mut self, color: &Color, king_id: Option<PieceId>) {
+        self.king[color] = king_id;
+    }
+
+    pub fn add_royal(&mut self, piece_id: &PieceId) {
+        self.royals[&piece_id.color()].push(*piece_id);
+    }
+
+    pub fn royals(&self, color: &Color) -> &[PieceId] {
+        &self.royals[color]
+    }
+
+    pub fn is_royal(&self, piece_id: &PieceId) -> bool {
+        self.royals[&piece_id.color()].contains(piece_id)
+    }
+
     pub fn active_pieces(&self, color: &Color) -> &HashMap<PieceId, Piece, FxBuildHasher> {
         &self.active_pieces[color]
     }
@@ -138,4 +188,86 @@ impl BoardMap {
     pub fn maybe_find_piece_by_id_mut(&mut self, piece_id: &PieceId) -> Option<&mut Piece> {
         self.active_pieces[&piece_id.color()].get_mut(piece_id)
     }
+
+    // Renders the board from the given point of view. Debugging purpose only.
+    pub fn pp(&self, dimension: &Dimension, pov: &Color) -> String {
+        let mut output = String::new();
+        let mut buf: Vec<String> = vec![];
+
+        let y_range: Vec<i16> = if *pov == Color::White {
+            (*dimension.min_point().y().value()..=*dimension.max_point().y().value())
+                .rev()
+                .collect()
+        } else {
+            (*dimension.min_point().y().value()..=*dimension.max_point().y().value())
+                .collect()
+        };
+        let x_range: Vec<i16> = if *pov == Color::White {
+            (*dimension.min_point().x().value()..=*dimension.max_point().x().value())
+                .collect()
+        } else {
+            (*dimension.min_point().x().value()..=*dimension.max_point().x().value())
+                .rev()
+                .collect()
+        };
+
+        for y in y_range {
+            for x in x_range.clone() {
+                let point = Point::new(x, y);
+                let square = self.board_square(&point);
+                if (*pov == Color::White && point.x() == dimension.min_point().x())
+                    || (*pov == Color::Black && point.x() == dimension.max_point().x())
+                {
+                    output.push_str(point.y().pp().as_str());
+                    output.push(' ');
+                }
+                let square_str = match square.get_piece_id() {
+                    Some(piece_id) => {
+                        let piece = self.find_piece_by_id(piece_id);
+                        square
+                            .pp()
+                            .replace(&piece_id.to_string(), piece.pp().as_str())
+                    }
+                    None => square.pp(),
+                };
+                output.push_str(square_str.as_str());
+                output.push(' ');
+                if (*pov == Color::White && point.x() == dimension.max_point().x())
+                    || (*pov == Color::Black && point.x() == dimension.min_point().x())
+                {
+                    output.push('\n');
+                    buf.push(output.clone());
+                    output = String::new();
+                }
+            }
+        }
+        output.push_str("  ");
+
+        let vector_points = if *pov == Color::White {
+            VectorPoints::with_initial(
+                Point::new(
+                    *dimension.min_point().x().value(),
+                    *dimension.max_point().y().value(),
+                ),
+                *dimension,
+                Vector::Line(LineVector::Right),
+            )
+        } else {
+            VectorPoints::with_initial(
+                Point::new(
+                    *dimension.max_point().x().value(),
+                    *dimension.max_point().y().value(),
+                ),
+                *dimension,
+                Vector::Line(LineVector::Left),
+            )
+        };
+        for point in vector_points {
+            output.push(' ');
+            output.push_str(point.x().pp().as_str());
+            output.push_str("  ");
+        }
+        buf.push(output);
+        buf.join("")
+    }
 }
@@ -0,0 +1,92 @@
+use crate::collections::HashMap;
+use rustc_hash::FxBuildHasher;
+
+const MAX_NUMBER_OF_EQUAL_POSITIONS: u8 = 3;
+
+// Positions of the game in the order they have occurred, along with whether the side to move was
+// in check in each of them. Used by the games to detect repetitions and perpetual checks.
+#[derive(Clone)]
+pub struct BoardPositions {
+    positions: Vec<(u128, bool)>,
+    occurrences: HashMap<u128, u8, FxBuildHasher>,
+    most_frequent_position: Option<(u128, u8)>,
+}
+
+impl BoardPositions {
+    pub fn empty() -> Self {
+        Self {
+            positions: vec![],
+            occurrences: HashMap::default(),
+            most_frequent_position: None,
+        }
+    }
+
+    // Returns the number of times the position has occurred, including this one
+    pub fn persist_position(&mut self, zkey: &u128, is_check: bool) -> u8 {
+        self.positions.push((*zkey, is_check));
+        let occurrences_num = self.occurrences.entry(*zkey).or_insert(0);
+        *occurrences_num += 1;
+        if self
+            .most_frequent_position
+            .is_none_or(|(_, old_occurrences)| *occurrences_num > old_occurrences)
+        {
+            self.most_frequent_position = Some((*zkey, *occurrences_num));
+        }
+        *occurrences_num
+    }
+
+    pub fn most_frequent_position(&self) -> Option<&(u128, u8)> {
+        self.most_frequent_position.as_ref()
+    }
+
+    // Whether any position has occurred three times, which draws the game by the classic rules
+    pub fn is_threefold_repetition(&self) -> bool {
+        self.most_frequent_position
+            .is_some_and(|(_, occurrences_num)| occurrences_num >= MAX_NUMBER_OF_EQUAL_POSITIONS)
+    }
+
+    // Whether each move of the side, made since the first occurrence of the last position, has
+    // given check. The side is the one which has made the last move when moves_ago is 0, and its
+    // opponent when it is 1.
+    pub fn is_perpetual_check(&self, moves_ago: usize) -> bool {
+        let Some((last_zkey, _)) = self.positions.last() else {
+            return false;
+        };
+        let first_idx = self
+            .positions
+            .iter()
+            .position(|(zkey, _)| zkey == last_zkey)
+            .unwrap();
+        let last_idx = self.positions.len() - 1;
+        let mut checks = (first_idx + 1..=last_idx)
+            .filter(|idx| (last_idx - idx) % 2 == moves_ago)
+            .map(|idx| self.positions[idx].1)
+            .peekable();
+        checks.peek().is_some() && checks.all(|is_check| is_check)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_most_frequent_position() {
+        let mut board_positions = BoardPositions::empty();
+        assert_eq!(board_positions.persist_position(&1, false), 1);
+        assert_eq!(board_positions.persist_position(&2, false), 1);
+        assert_eq!(board_positions.persist_position(&2, false), 2);
+        assert_eq!(board_positions.persist_position(&1, false), 2);
+        assert_eq!(board_positions.most_frequent_position(), Some(&(2, 2)));
+    }
+
+    #[test]
+    fn test_perpetual_check() {
+        let mut board_positions = BoardPositions::empty();
+        for (zkey, is_check) in [(1, false), (2, true), (3, false), (4, true), (1, false)] {
+            board_positions.persist_position(&zkey, is_check);
+        }
+        assert!(!board_positions.is_perpetual_check(0));
+        assert!(board_positions.is_perpetual_check(1));
+    }
+}
@@ -0,0 +1,9 @@
+// Part of the board some pieces can't leave, like the palace in xiangqi. Squares of the region
+// are told by the SquaresMap of the board.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BoardRegion {
+    // Squares around the initial point of the royal piece of the color
+    Palace,
+    // Half of the board on the side of the color, e.g. before the river in xiangqi
+    OwnHalf,
+}
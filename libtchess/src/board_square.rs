@@ -1,4 +1,5 @@
 use crate::color::Color;
+use crate::colored_property::ColoredProperty;
 use crate::piece_id::PieceId;
 use crate::square::Square;
 use crate::utils::pretty_print::PrettyPrint;
@@ -19,11 +20,11 @@ impl BoardSquare {
     // 2 ▓▓▓ ░♚░ ▓▓▓ ░░░
     // 1 ░░░ ▓▓▓ ░░░ ▓▓▓
     //    a   b   c   d
-    pub fn can_look_through(&self, color: &Color, opposite_king_id: Option<&PieceId>) -> bool {
+    pub fn can_look_through(&self, color: &Color, kings: &ColoredProperty<Option<PieceId>>) -> bool {
         match self {
             Self::Square(square) => {
                 if let Some(piece_id) = square.get_piece_id() {
-                    &piece_id.color() != color && Some(piece_id) == opposite_king_id
+                    piece_id.color().is_enemy(color) && kings[&piece_id.color()] == Some(*piece_id)
                 } else {
                     // Empty square
                     true
@@ -37,7 +38,7 @@ impl BoardSquare {
         match self {
             Self::Square(square) => {
                 if let Some(piece_id) = square.get_piece_id() {
-                    &piece_id.color() != color
+                    piece_id.color().is_enemy(color)
                 } else {
                     false
                 }
@@ -49,12 +50,12 @@ impl BoardSquare {
     pub fn is_capturable_enemy_square(
         &self,
         color: &Color,
-        opposite_king_id: Option<&PieceId>,
+        kings: &ColoredProperty<Option<PieceId>>,
     ) -> bool {
         match self {
             Self::Square(square) => {
                 if let Some(piece_id) = square.get_piece_id() {
-                    &piece_id.color() != color && Some(piece_id) != opposite_king_id
+                    piece_id.color().is_enemy(color) && kings[&piece_id.color()] != Some(*piece_id)
                 } else {
                     false
                 }
@@ -67,7 +68,7 @@ impl BoardSquare {
         match self {
             Self::Square(square) => {
                 if let Some(piece_id) = square.get_piece_id() {
-                    &piece_id.color() == color
+                    piece_id.color().is_ally(color)
                 } else {
                     false
                 }
@@ -2,6 +2,9 @@ use crate::board_summary::{ActivePiecesStats, ZKey};
 use crate::colored_property::ColoredProperty;
 use crate::piece::Piece;
 
+// Number of turns of both sides, made without captures and pawn moves, which draw the game
+const FIFTY_MOVE_RULE_TURNS_COUNT: usize = 100;
+
 pub struct BoardStats<'a> {
     pub active_pieces_stats: &'a ColoredProperty<ActivePiecesStats>,
     pub turn_number: &'a usize,
@@ -10,4 +13,12 @@ pub struct BoardStats<'a> {
     pub last_pawn_move_turn_number: &'a usize,
     pub zposition: &'a ZKey,
     pub last_captured_piece: Option<&'a Piece>,
+    pub checks_given: &'a ColoredProperty<u8>,
+}
+
+impl BoardStats<'_> {
+    pub fn is_fifty_move_rule_over(&self) -> bool {
+        self.turn_number - self.last_capture_turn_number >= FIFTY_MOVE_RULE_TURNS_COUNT
+            || self.turn_number - self.last_pawn_move_turn_number >= FIFTY_MOVE_RULE_TURNS_COUNT
+    }
 }
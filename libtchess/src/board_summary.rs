@@ -4,7 +4,7 @@ use crate::colored_property::ColoredProperty;
 use crate::piece::Piece;
 use crate::piece_id::PieceId;
 use crate::point::Point;
-use im_rc::HashMap;
+use crate::collections::{HashMap, OrdMap};
 use rand_xoshiro::SplitMix64;
 use rand_xoshiro::rand_core::{SeedableRng, TryRng};
 use rustc_hash::FxBuildHasher;
@@ -22,6 +22,8 @@ pub struct ActivePiecesStats {
     pub pawns_count: isize,
     pub queens_count: isize,
     pub rooks_count: isize,
+    // Number of custom pieces by their names
+    pub custom_pieces_count: OrdMap<String, isize>,
 }
 
 impl ActivePiecesStats {
@@ -33,24 +35,47 @@ impl ActivePiecesStats {
             pawns_count: 0,
             queens_count: 0,
             rooks_count: 0,
+            custom_pieces_count: OrdMap::new(),
+        }
+    }
+
+    // Number of the pieces of the given name
+    pub fn count_of(&self, name: &str) -> isize {
+        match name {
+            "Bishop" => self.bishops_count,
+            "King" => self.kings_count,
+            "Knight" => self.knights_count,
+            "Pawn" => self.pawns_count,
+            "Queen" => self.queens_count,
+            "Rook" => self.rooks_count,
+            _ => self.custom_pieces_count.get(name).copied().unwrap_or(0),
         }
     }
 }
 
-const BISHOP_REPR: u64 = 0;
-const KING_REPR: u64 = 1;
-const KNIGHT_REPR: u64 = 2;
-const PAWN_REPR: u64 = 3;
-const QUEEN_REPR: u64 = 4;
-const ROOK_REPR: u64 = 5;
+pub(crate) const BISHOP_REPR: u64 = 0;
+pub(crate) const KING_REPR: u64 = 1;
+pub(crate) const KNIGHT_REPR: u64 = 2;
+pub(crate) const PAWN_REPR: u64 = 3;
+pub(crate) const QUEEN_REPR: u64 = 4;
+pub(crate) const ROOK_REPR: u64 = 5;
 
 const PIECE_KIND_OFFSET: u64 = 35;
-const PIECE_KIND_SIZE: u32 = 3;
+pub(crate) const PIECE_KIND_SIZE: u32 = 8;
+const COLOR_HIGH_BITS_OFFSET: u64 = PIECE_KIND_OFFSET + PIECE_KIND_SIZE as u64;
+const COLOR_HIGH_BITS_SIZE: u32 = 4;
+// Pieces in the pockets are not bound to any position. Instead, such piece is represented by its
+// kind, its color and its number among the pieces of the same kind in the pocket, with this bit
+// set.
+const POCKET_OFFSET: u64 = 63;
+// The number of checks, given by the side when the checks are limited, is a part of the
+// position as well. It is represented by the color and the number of checks with this bit set.
+const CHECKS_OFFSET: u64 = 62;
 
 #[derive(Copy, Clone)]
 struct PieceRepr {
     // Layout(high to low bits) and size(bits):
-    // [kind: 3; color: 1; EnPassant: 1; Castle: 1; Ypos: 16; Xpos: 16]
+    // [color high bits: 4; kind: 8; color: 1; EnPassant: 1; Castle: 1; Ypos: 16; Xpos: 16]
     packed: u64,
     initialized: bool,
 }
@@ -77,6 +102,31 @@ impl PieceRepr {
         packed
     }
 
+    pub fn from_pocket_piece(piece: &Piece, number: usize) -> Self {
+        let mut packed = Self {
+            packed: 0,
+            initialized: false,
+        };
+        packed.pack_kind(piece);
+        packed.pack_color(piece.color());
+        packed.pack(0, 32, number as u64, false);
+        packed.pack(POCKET_OFFSET, 1, 1, false);
+        packed.initialized = true;
+        packed
+    }
+
+    pub fn from_checks(color: &Color, checks: u8) -> Self {
+        let mut packed = Self {
+            packed: 0,
+            initialized: false,
+        };
+        packed.pack_color(color);
+        packed.pack(0, 8, checks as u64, false);
+        packed.pack(CHECKS_OFFSET, 1, 1, false);
+        packed.initialized = true;
+        packed
+    }
+
     pub fn pack_kind(&mut self, piece: &Piece) {
         let kind_repr: u64 = match piece {
             Piece::Bishop(_) => BISHOP_REPR,
@@ -85,6 +135,7 @@ impl PieceRepr {
             Piece::Pawn(_) => PAWN_REPR,
             Piece::Queen(_) => QUEEN_REPR,
             Piece::Rook(_) => ROOK_REPR,
+            Piece::Custom(p) => p.kind(),
             Piece::UnknownPiece(_) => panic!("Can't pack unknown piece!"),
         };
         self.pack(
@@ -95,12 +146,17 @@ impl PieceRepr {
         );
     }
 
+    // The lowest bit of the color index goes right below the kind. The rest of them, which are
+    // only set on the boards for more than two sides, go above the kind.
     pub fn pack_color(&mut self, color: &Color) {
-        let color_repr: u64 = match color {
-            Color::White => 0,
-            Color::Black => 1,
-        };
-        self.pack(34, 1, color_repr, self.initialized);
+        let color_repr = color.index() as u64;
+        self.pack(34, 1, color_repr & 1, self.initialized);
+        self.pack(
+            COLOR_HIGH_BITS_OFFSET,
+            COLOR_HIGH_BITS_SIZE,
+            color_repr >> 1,
+            self.initialized,
+        );
     }
 
     pub fn pack_position(&mut self, position: &Point) {
@@ -124,12 +180,6 @@ impl PieceRepr {
         self.packed
     }
 
-    pub fn piece_type_repr(&self) -> u64 {
-        let bits_to_extract = 2u64.pow(PIECE_KIND_SIZE) - 1;
-        let shifted = self.packed >> PIECE_KIND_OFFSET;
-        shifted & bits_to_extract
-    }
-
     fn pack(&mut self, offset: u64, size: u32, value: u64, clear_bits: bool) {
         self.packed = if clear_bits {
             let bits_to_clear = (2u64.pow(size) - 1) << offset;
@@ -157,30 +207,31 @@ pub struct BoardSummary {
     last_promote_turn_number: usize,
     last_pawn_move_turn_number: usize,
     last_captured_piece: Option<Piece>,
+    // Number of checks, given by each side
+    checks_given: ColoredProperty<u8>,
     // Zobrist representation of the current position
     zposition: ZKey,
     packed_pieces: HashMap<PieceId, PieceRepr, FxBuildHasher>,
 }
 
 impl BoardSummary {
-    pub fn new() -> Self {
+    pub fn new(sides_count: usize) -> Self {
         Self {
-            active_pieces_stats: ColoredProperty([
-                ActivePiecesStats::empty(),
-                ActivePiecesStats::empty(),
-            ]),
+            active_pieces_stats: ColoredProperty::filled(sides_count, ActivePiecesStats::empty()),
             turn_number: 1,
             last_capture_turn_number: 0,
             last_promote_turn_number: 0,
             last_pawn_move_turn_number: 0,
             last_captured_piece: None,
+            checks_given: ColoredProperty::filled(sides_count, 0),
             zposition: ZKey(0),
             packed_pieces: HashMap::default(),
         }
     }
 
-    pub fn next_turn(&mut self) {
-        self.zposition ^= SIDE_TO_MOVE_TAG;
+    // The turn goes from the given side to the next one
+    pub fn next_turn(&mut self, color: &Color, next_color: &Color) {
+        self.zposition ^= Self::side_to_move_repr(color) ^ Self::side_to_move_repr(next_color);
         self.turn_number += 1;
     }
 
@@ -197,43 +248,79 @@ impl BoardSummary {
         self.last_pawn_move_turn_number = self.turn_number;
     }
 
-    pub fn add_piece(&mut self, piece: &Piece, has_castle: bool, has_en_passant: bool) {
+    pub fn check_given(&mut self, color: &Color) {
+        self.set_checks_given(color, self.checks_given[color] + 1);
+    }
+
+    pub fn set_checks_given(&mut self, color: &Color, checks: u8) {
+        let checks_was = self.checks_given[color];
+        if checks_was > 0 {
+            self.zposition ^= Self::zobrist_repr(PieceRepr::from_checks(color, checks_was).packed());
+        }
+        self.checks_given[color] = checks;
+        if checks > 0 {
+            self.zposition ^= Self::zobrist_repr(PieceRepr::from_checks(color, checks).packed());
+        }
+    }
+
+    // Continues counting from the given turn, e.g. when the position is imported. The last capture
+    // and the last pawn move are considered to be made before the given number of turns.
+    pub fn set_turn_number(&mut self, turn_number: usize, reversible_turns: usize) {
+        if (self.turn_number + turn_number) % 2 == 1 {
+            self.zposition ^= SIDE_TO_MOVE_TAG;
+        }
+        self.turn_number = turn_number;
+        self.last_capture_turn_number = turn_number.saturating_sub(reversible_turns + 1);
+        self.last_pawn_move_turn_number = self.last_capture_turn_number;
+    }
+
+    fn update_pieces_count(&mut self, piece: &Piece, delta: isize) {
+        let stats = &mut self.active_pieces_stats[piece.color()];
         match piece {
-            Piece::Bishop(_) => self.active_pieces_stats[piece.color()].bishops_count += 1,
-            Piece::King(_) => self.active_pieces_stats[piece.color()].kings_count += 1,
-            Piece::Knight(_) => self.active_pieces_stats[piece.color()].knights_count += 1,
-            Piece::Pawn(_) => self.active_pieces_stats[piece.color()].pawns_count += 1,
-            Piece::Queen(_) => self.active_pieces_stats[piece.color()].queens_count += 1,
-            Piece::Rook(_) => self.active_pieces_stats[piece.color()].rooks_count += 1,
-            Piece::UnknownPiece(_) => panic!("Can't add unknown piece to active pieces list!"),
+            Piece::Bishop(_) => stats.bishops_count += delta,
+            Piece::King(_) => stats.kings_count += delta,
+            Piece::Knight(_) => stats.knights_count += delta,
+            Piece::Pawn(_) => stats.pawns_count += delta,
+            Piece::Queen(_) => stats.queens_count += delta,
+            Piece::Rook(_) => stats.rooks_count += delta,
+            Piece::Custom(p) => {
+                *stats
+                    .custom_pieces_count
+                    .entry(p.definition().name().to_string())
+                    .or_insert(0) += delta
+            }
+            Piece::UnknownPiece(_) => panic!("Can't count unknown piece in active pieces list!"),
         }
+    }
+
+    pub fn add_piece(&mut self, piece: &Piece, has_castle: bool, has_en_passant: bool) {
+        self.update_pieces_count(piece, 1);
         let packed_piece = PieceRepr::from_piece(piece, has_castle, has_en_passant);
         self.zposition ^= Self::zobrist_repr(packed_piece.packed());
         self.packed_pieces.insert(*piece.id(), packed_piece);
     }
 
-    pub fn remove_piece(&mut self, piece_id: &PieceId) {
-        let packed_repr = self.packed_pieces.remove(piece_id).expect(
+    pub fn remove_piece(&mut self, piece: &Piece) {
+        let packed_repr = self.packed_pieces.remove(piece.id()).expect(
             format!(
                 "Logical error: failed to remove {} from packed pieces representation!",
-                piece_id
+                piece.id()
             )
             .as_str(),
         );
 
-        match packed_repr.piece_type_repr() {
-            BISHOP_REPR => self.active_pieces_stats[&piece_id.color()].bishops_count -= 1,
-            KING_REPR => self.active_pieces_stats[&piece_id.color()].kings_count -= 1,
-            KNIGHT_REPR => self.active_pieces_stats[&piece_id.color()].knights_count -= 1,
-            PAWN_REPR => self.active_pieces_stats[&piece_id.color()].pawns_count -= 1,
-            QUEEN_REPR => self.active_pieces_stats[&piece_id.color()].queens_count -= 1,
-            ROOK_REPR => self.active_pieces_stats[&piece_id.color()].rooks_count -= 1,
-            _ => panic!("Can't add unknown piece to active pieces list!"),
-        }
+        self.update_pieces_count(piece, -1);
 
         self.zposition ^= Self::zobrist_repr(packed_repr.packed());
     }
 
+    // Adds the piece of the pocket to the position or removes it from there. The number is the
+    // count of the pieces of the same kind in the pocket, including the given one.
+    pub fn toggle_pocket_piece(&mut self, piece: &Piece, number: usize) {
+        let packed_piece = PieceRepr::from_pocket_piece(piece, number);
+        self.zposition ^= Self::zobrist_repr(packed_piece.packed());
+    }
+
     pub fn stats(&'_ self) -> BoardStats<'_> {
         BoardStats {
             active_pieces_stats: &self.active_pieces_stats,
@@ -243,6 +330,7 @@ impl BoardSummary {
             last_pawn_move_turn_number: &self.last_pawn_move_turn_number,
             zposition: &self.zposition,
             last_captured_piece: self.last_captured_piece.as_ref(),
+            checks_given: &self.checks_given,
         }
     }
 
@@ -282,6 +370,15 @@ impl BoardSummary {
         })
     }
 
+    // Zobrist representation of the side to move. The first side has none, thus the tag of the
+    // classic game only tells whether Black is to move.
+    fn side_to_move_repr(color: &Color) -> u128 {
+        match color.index() {
+            0 => 0,
+            index => SIDE_TO_MOVE_TAG.rotate_left(index as u32 - 1),
+        }
+    }
+
     fn zobrist_repr(packed: u64) -> u128 {
         let high_bits = SplitMix64::seed_from_u64(SEED1 ^ packed)
             .try_next_u64()
@@ -4,5 +4,5 @@ use crate::point::Point;
 pub enum Buff {
     Castle,
     EnPassant(Point, Point),
-    AdditionalPoint, // A pawn buff to allow going one additional point further
+    AdditionalPoint, // A pawn buff to allow going further on its first move
 }
@@ -1,4 +1,4 @@
-use im_rc::{HashMap};
+use crate::collections::{HashMap};
 use rustc_hash::FxBuildHasher;
 use crate::buff::Buff;
 use crate::piece_id::PieceId;
@@ -1,4 +1,4 @@
-use crate::vector::line_vector::LineVector;
+use crate::orientation::Orientation;
 use crate::vector::Vector;
 use crate::point::Point;
 
@@ -9,10 +9,11 @@ pub enum CastleSide {
 }
 
 impl CastleSide {
-    pub fn direction(&self) -> Vector {
+    // Direction towards the side as the given side of the game sees it
+    pub fn direction(&self, orientation: &Orientation) -> Vector {
         match self {
-            Self::Queen => Vector::Line(LineVector::Left),
-            Self::King => Vector::Line(LineVector::Right),
+            Self::Queen => Vector::Line(orientation.king_side().inverse()),
+            Self::King => Vector::Line(*orientation.king_side()),
         }
     }
 }
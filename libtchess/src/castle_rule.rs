@@ -0,0 +1,125 @@
+use crate::castle_points::CastleSide;
+use crate::castle_x_points::CastleXPoints;
+use crate::orientation::Orientation;
+use crate::point::Point;
+use crate::vector::line_vector::LineVector;
+
+// Where the king and the rook land after the castle
+#[derive(Debug, Copy, Clone)]
+pub enum CastleDestination {
+    // The king and the rook land on the given x points no matter where they have started from,
+    // like in the classic chess and Chess960
+    XPoints(CastleXPoints),
+    // The king goes the given number of points towards the rook. The rook lands the given number
+    // of points away from the initial position of the king, towards its own side.
+    Distance { king: i16, rook: i16 },
+}
+
+// One of the ways the king can castle to the given side. There may be several rules for the same
+// side, e.g. when the king is allowed to go either two or three points on a wide board.
+#[derive(Debug, Copy, Clone)]
+pub struct CastleRule {
+    side: CastleSide,
+    destination: CastleDestination,
+    // Whether the king can castle with the rook, which is not in the corner of its row. The
+    // nearest rook on the side is used in any case.
+    non_corner_rook: bool,
+}
+
+impl CastleRule {
+    pub fn new(side: CastleSide, destination: CastleDestination, non_corner_rook: bool) -> Self {
+        Self {
+            side,
+            destination,
+            non_corner_rook,
+        }
+    }
+
+    // Rule of the classic chess and Chess960
+    pub fn from_x_points(side: CastleSide, castle_x_points: CastleXPoints) -> Self {
+        Self::new(side, CastleDestination::XPoints(castle_x_points), true)
+    }
+
+    pub fn side(&self) -> &CastleSide {
+        &self.side
+    }
+
+    pub fn destination(&self) -> &CastleDestination {
+        &self.destination
+    }
+
+    pub fn non_corner_rook(&self) -> bool {
+        self.non_corner_rook
+    }
+
+    // Points where the king, standing on the given point, and the rook land after the castle. The
+    // king of the side, which plays from the left or the right edge, castles along the column.
+    pub fn castle_points(&self, king_position: &Point, orientation: &Orientation) -> (Point, Point) {
+        let (&x, &y) = king_position.to_tuple();
+        match self.destination {
+            CastleDestination::XPoints(castle_x_points) => {
+                let (king_x, rook_x) = (*castle_x_points.king_x(), *castle_x_points.rook_x());
+                match orientation.king_side() {
+                    LineVector::Left | LineVector::Right => {
+                        (Point::new(king_x, y), Point::new(rook_x, y))
+                    }
+                    LineVector::Top | LineVector::Bottom => {
+                        (Point::new(x, king_x), Point::new(x, rook_x))
+                    }
+                }
+            }
+            CastleDestination::Distance { king, rook } => {
+                let (dx, dy) = self.side.direction(orientation).delta();
+                (
+                    Point::new(x + king * dx, y + king * dy),
+                    Point::new(x + rook * dx, y + rook * dy),
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::castle_x_points::{KingCastleXPoint, RookCastleXPoint};
+
+    #[test]
+    fn test_castle_points_of_x_points_rule() {
+        let rule = CastleRule::from_x_points(
+            CastleSide::King,
+            CastleXPoints(KingCastleXPoint(7), RookCastleXPoint(6)),
+        );
+        assert_eq!(
+            rule.castle_points(&Point::new(2, 8), &Orientation::TOP),
+            (Point::new(7, 8), Point::new(6, 8))
+        );
+    }
+
+    #[test]
+    fn test_castle_points_of_distance_rule() {
+        let destination = CastleDestination::Distance { king: 3, rook: 2 };
+        let king_side = CastleRule::new(CastleSide::King, destination, false);
+        let queen_side = CastleRule::new(CastleSide::Queen, destination, false);
+        assert_eq!(
+            king_side.castle_points(&Point::new(6, 1), &Orientation::BOTTOM),
+            (Point::new(9, 1), Point::new(8, 1))
+        );
+        assert_eq!(
+            queen_side.castle_points(&Point::new(6, 1), &Orientation::BOTTOM),
+            (Point::new(3, 1), Point::new(4, 1))
+        );
+    }
+
+    #[test]
+    fn test_castle_points_along_the_column() {
+        // The side plays from the left edge of the board, its king side is at the bottom
+        let orientation = Orientation::new(LineVector::Right, LineVector::Bottom);
+        let destination = CastleDestination::Distance { king: 2, rook: 1 };
+        let king_side = CastleRule::new(CastleSide::King, destination, false);
+        assert_eq!(
+            king_side.castle_points(&Point::new(1, 7), &orientation),
+            (Point::new(1, 5), Point::new(1, 6))
+        );
+    }
+}
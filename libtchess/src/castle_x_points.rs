@@ -1,10 +1,10 @@
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct KingCastleXPoint(pub i16);
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct RookCastleXPoint(pub i16);
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct CastleXPoints(pub KingCastleXPoint, pub RookCastleXPoint);
 
 impl CastleXPoints {
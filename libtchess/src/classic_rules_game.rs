@@ -0,0 +1,89 @@
+use crate::board::Board;
+use crate::board_config::BoardConfig;
+use crate::board_positions::BoardPositions;
+use crate::game_board::GameBoard;
+use crate::game_result::GameResult;
+use crate::heat_map::HeatMap;
+use crate::move_result::MoveResult;
+use crate::piece_id::PieceId;
+use crate::piece_move::PieceMove;
+use crate::point::Point;
+use crate::squares_map::SquaresMap;
+
+// The game, which is over by checkmate, stalemate, the fifty-move rule or threefold repetition.
+// Variants, which only bring their own board and pieces, are played by it.
+#[derive(Clone)]
+pub struct ClassicRulesGame<HT: HeatMap, SQ: SquaresMap> {
+    board_positions: BoardPositions,
+    board: Board<HT, SQ>,
+    game_result: Option<GameResult>,
+}
+
+impl<HT: HeatMap, SQ: SquaresMap> ClassicRulesGame<HT, SQ> {
+    // Places the pieces on the board, built from the given config, with the given function
+    pub fn with_setup<F: FnOnce(&mut Board<HT, SQ>)>(
+        config: BoardConfig<HT, SQ>,
+        place_pieces: F,
+    ) -> Self {
+        let mut board = Board::empty(config);
+        place_pieces(&mut board);
+
+        Self {
+            board,
+            board_positions: BoardPositions::empty(),
+            game_result: None,
+        }
+    }
+
+    pub fn move_piece_at(&mut self, position: &Point, piece_move: &PieceMove) -> MoveResult {
+        let &piece_id = self
+            .board
+            .piece_id_at(position)
+            .unwrap_or_else(|| panic!("Could not find piece at {} position", position));
+        self.move_piece(&piece_id, piece_move)
+    }
+
+    pub fn move_piece(&mut self, piece_id: &PieceId, piece_move: &PieceMove) -> MoveResult {
+        if let Some(game_result) = self.game_result {
+            return MoveResult::GameEnded(game_result);
+        }
+        if self.board.move_piece(piece_id, piece_move).is_none() {
+            return MoveResult::IllegalMove;
+        }
+
+        let is_check = self.board.is_checked(self.board.current_turn());
+        self.board_positions
+            .persist_position(&self.board.stats().zposition.0, is_check);
+        self.calculate_game_result();
+
+        match self.game_result {
+            Some(game_result) => MoveResult::GameEnded(game_result),
+            None => MoveResult::PieceMoved,
+        }
+    }
+
+    pub fn board(&self) -> &Board<HT, SQ> {
+        &self.board
+    }
+
+    pub fn game_result(&self) -> Option<&GameResult> {
+        self.game_result.as_ref()
+    }
+
+    fn calculate_game_result(&mut self) {
+        let current_turn = *self.board.current_turn();
+        self.game_result = if self.board.has_no_moves(&current_turn) {
+            if self.board.is_checked(&current_turn) {
+                Some(GameResult::Checkmate(current_turn))
+            } else {
+                Some(GameResult::Stalemate(current_turn))
+            }
+        } else if self.board.stats().is_fifty_move_rule_over() {
+            Some(GameResult::FiftyMoveRuleDraw)
+        } else if self.board_positions.is_threefold_repetition() {
+            Some(GameResult::DrawByRepetition)
+        } else {
+            None
+        };
+    }
+}
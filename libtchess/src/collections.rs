@@ -0,0 +1,28 @@
+// Persistent collections the board is built from. By default they are Rc-based and can't leave
+// the thread they were created in. The "sync" feature switches them to their Arc-based
+// counterparts, so boards can be shared between threads.
+#[cfg(feature = "sync")]
+pub use im::{HashMap, HashSet, OrdMap, OrdSet, Vector};
+#[cfg(not(feature = "sync"))]
+pub use im_rc::{HashMap, HashSet, OrdMap, OrdSet, Vector};
+
+#[cfg(not(feature = "sync"))]
+pub(crate) use std::cell::OnceCell;
+#[cfg(not(feature = "sync"))]
+pub(crate) use std::rc::Rc as Shared;
+#[cfg(feature = "sync")]
+pub(crate) use std::sync::Arc as Shared;
+#[cfg(feature = "sync")]
+pub(crate) use std::sync::OnceLock as OnceCell;
+
+// Bound for the types which are stored on the board, like heat maps and squares maps. Requires
+// them to be thread-safe when "sync" feature is enabled.
+#[cfg(not(feature = "sync"))]
+pub trait MaybeSync {}
+#[cfg(not(feature = "sync"))]
+impl<T> MaybeSync for T {}
+
+#[cfg(feature = "sync")]
+pub trait MaybeSync: Send + Sync {}
+#[cfg(feature = "sync")]
+impl<T: Send + Sync> MaybeSync for T {}
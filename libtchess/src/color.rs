@@ -1,26 +1,64 @@
-#[derive(Debug, Copy, Clone, Hash)]
-pub enum Color {
-    Black,
-    White,
+use crate::orientation::Orientation;
+
+// Side of the game. Every side plays for a team, sides of the same team are allies. The classic
+// game is played by White and Black of different teams, boards for more sides number the rest of
+// them after these two.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Color {
+    index: u8,
+    team: u8,
+    orientation: Orientation,
 }
 
+#[allow(non_upper_case_globals)]
 impl Color {
-    pub fn inverse(&self) -> Self {
-        match self {
-            Self::White => Self::Black,
-            Self::Black => Self::White,
+    pub const White: Self = Self::new(0, 0, Orientation::BOTTOM);
+    pub const Black: Self = Self::new(1, 1, Orientation::TOP);
+
+    pub const fn new(index: u8, team: u8, orientation: Orientation) -> Self {
+        Self {
+            index,
+            team,
+            orientation,
         }
     }
-}
 
-impl PartialEq<Color> for Color {
-    fn eq(&self, other: &Color) -> bool {
-        match (self, other) {
-            (Color::White, Color::White) => true,
-            (Color::Black, Color::Black) => true,
-            _ => false,
+    pub fn index(&self) -> usize {
+        self.index as usize
+    }
+
+    pub fn team(&self) -> u8 {
+        self.team
+    }
+
+    pub fn orientation(&self) -> &Orientation {
+        &self.orientation
+    }
+
+    pub fn is_ally(&self, other: &Color) -> bool {
+        self.team == other.team
+    }
+
+    pub fn is_enemy(&self, other: &Color) -> bool {
+        !self.is_ally(other)
+    }
+
+    // The classic color the pieces of the side are drawn with. Sides after the classic two take
+    // turns to look like White and Black.
+    pub fn appearance(&self) -> Self {
+        if self.index.is_multiple_of(2) {
+            Self::White
+        } else {
+            Self::Black
         }
     }
-}
 
-impl Eq for Color {}
+    // The other side of the two-sided game
+    pub fn inverse(&self) -> Self {
+        match *self {
+            Self::White => Self::Black,
+            Self::Black => Self::White,
+            _ => panic!("{:?} side has no single opponent!", self),
+        }
+    }
+}
@@ -1,25 +1,33 @@
 use std::ops::{Index, IndexMut};
 use crate::color::Color;
 
+// Value per side of the game, indexed by the color of the side
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
-pub struct ColoredProperty<T: Clone>(pub [T; 2]);
+pub struct ColoredProperty<T: Clone>(pub Vec<T>);
+
+impl<T: Clone> ColoredProperty<T> {
+    // The same value for each of the given number of sides
+    pub fn filled(sides_count: usize, value: T) -> Self {
+        Self(vec![value; sides_count])
+    }
+}
+
+impl<T: Clone, const N: usize> From<[T; N]> for ColoredProperty<T> {
+    fn from(values: [T; N]) -> Self {
+        Self(values.to_vec())
+    }
+}
 
 impl<T: Clone> Index<&Color> for ColoredProperty<T> {
     type Output = T;
 
     fn index(&self, index: &Color) -> &Self::Output {
-        match index {
-            Color::White => &self.0[0],
-            Color::Black => &self.0[1],
-        }
+        &self.0[index.index()]
     }
 }
 
 impl<T: Clone> IndexMut<&Color> for ColoredProperty<T> {
     fn index_mut(&mut self, index: &Color) -> &mut T {
-        match index {
-            Color::White => &mut self.0[0],
-            Color::Black => &mut self.0[1],
-        }
+        &mut self.0[index.index()]
     }
 }
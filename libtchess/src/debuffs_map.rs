@@ -1,4 +1,4 @@
-use im_rc::{HashMap};
+use crate::collections::{HashMap};
 use rustc_hash::FxBuildHasher;
 use crate::debuff::Debuff;
 use crate::piece_id::PieceId;
@@ -0,0 +1,485 @@
+use crate::board::Board;
+use crate::board_config::BoardConfig;
+use crate::buff::Buff;
+use crate::color::Color;
+use crate::dimension::Dimension;
+use crate::heat_map::HeatMap;
+use crate::piece::Piece;
+use crate::point::Point;
+use crate::squares_map::SquaresMap;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FenError {
+    MissingField(&'static str),
+    UnknownPiece(char),
+    // The piece, which has no FEN letter, e.g. a custom one
+    UnsupportedPiece(String),
+    // The rank, which does not match the width of the board, or the wrong number of ranks
+    InvalidRank(String),
+    InvalidTurn(String),
+    InvalidCastle(char),
+    InvalidEnPassant(String),
+    InvalidNumber(String),
+    InvalidChecks(String),
+}
+
+impl Display for FenError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingField(field) => write!(f, "FEN is missing the {} field", field),
+            Self::UnknownPiece(letter) => write!(f, "Unknown FEN piece: {}", letter),
+            Self::UnsupportedPiece(name) => write!(f, "{} piece has no FEN letter", name),
+            Self::InvalidRank(rank) => write!(f, "Invalid FEN rank: {}", rank),
+            Self::InvalidTurn(turn) => write!(f, "Invalid FEN side to move: {}", turn),
+            Self::InvalidCastle(castle) => write!(f, "Invalid FEN castle: {}", castle),
+            Self::InvalidEnPassant(square) => write!(f, "Invalid FEN en passant: {}", square),
+            Self::InvalidNumber(number) => write!(f, "Invalid FEN number: {}", number),
+            Self::InvalidChecks(checks) => write!(f, "Invalid FEN checks: {}", checks),
+        }
+    }
+}
+
+// Parses FEN(e.g. "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1") into the board of
+// the given config. Ranks are listed from the top row, numbers of empty squares may take several
+// digits on the wide boards. Pawns on the second row from their side can make a long move. The
+// last optional field holds the number of checks, given by each side(e.g. "+1+0"), like in the
+// three-check FEN of lichess.
+pub fn parse<HT: HeatMap, SQ: SquaresMap>(
+    config: BoardConfig<HT, SQ>,
+    fen: &str,
+) -> Result<Board<HT, SQ>, FenError> {
+    let mut fields = fen.split_whitespace();
+    let placement = fields
+        .next()
+        .ok_or(FenError::MissingField("piece placement"))?;
+    let turn = match fields
+        .next()
+        .ok_or(FenError::MissingField("side to move"))?
+    {
+        "w" => Color::White,
+        "b" => Color::Black,
+        turn => return Err(FenError::InvalidTurn(turn.to_string())),
+    };
+    let castle = fields.next().unwrap_or("-");
+    let en_passant = fields.next().unwrap_or("-");
+    let halfmove_clock = parse_number(fields.next().unwrap_or("0"))?;
+    let fullmove_number = parse_number(fields.next().unwrap_or("1"))?.max(1);
+    let checks = fields.next().map(parse_checks).transpose()?;
+
+    let dimension = *config.dimension();
+    let pieces = parse_placement(placement, &dimension)?;
+    let castle_points = parse_castle(castle, &pieces)?;
+    let mut board = Board::empty(config);
+    for (name, color, point) in pieces {
+        let mut buffs = vec![];
+        if castle_points.contains(&point) {
+            buffs.push(Buff::Castle);
+        }
+        if name == "Pawn" && *point.y().value() == pawns_row(&color, &dimension) {
+            buffs.push(Buff::AdditionalPoint);
+        }
+        board.add_piece(name, color, buffs, vec![], point);
+    }
+    board.pass_turn(&turn);
+    if en_passant != "-" {
+        set_en_passant(&mut board, en_passant)?;
+    }
+    let turn_number = 2 * (fullmove_number - 1) + (turn == Color::Black) as usize + 1;
+    board.set_turn_number(turn_number, halfmove_clock);
+    if let Some((white_checks, black_checks)) = checks {
+        board.set_checks_given(&Color::White, white_checks);
+        board.set_checks_given(&Color::Black, black_checks);
+    }
+    Ok(board)
+}
+
+// Exports the board into FEN. The number of checks, given by each side, is added when the checks
+// are limited.
+pub fn export<HT: HeatMap, SQ: SquaresMap>(board: &Board<HT, SQ>) -> Result<String, FenError> {
+    let dimension = board.dimension();
+    let mut ranks = vec![];
+    for y in dimension.get_rows_range().rev() {
+        let mut rank = String::new();
+        let mut empty_squares = 0;
+        for x in dimension.get_columns_range() {
+            let Some(piece) = board.piece_at(&Point::new(x, y)) else {
+                empty_squares += 1;
+                continue;
+            };
+            if empty_squares > 0 {
+                rank.push_str(&empty_squares.to_string());
+                empty_squares = 0;
+            }
+            rank.push(piece_letter(piece)?);
+        }
+        if empty_squares > 0 {
+            rank.push_str(&empty_squares.to_string());
+        }
+        ranks.push(rank);
+    }
+
+    let current_turn = board.current_turn();
+    let stats = board.stats();
+    // Captures and pawn moves are recorded with the number of the turn they have been made at
+    let reversible_turns = stats.turn_number
+        - 1
+        - stats
+            .last_capture_turn_number
+            .max(stats.last_pawn_move_turn_number);
+    let mut fen = format!(
+        "{} {} {} {} {} {}",
+        ranks.join("/"),
+        if current_turn == &Color::White {
+            'w'
+        } else {
+            'b'
+        },
+        export_castle(board),
+        export_en_passant(board),
+        reversible_turns,
+        (stats.turn_number - 1) / 2 + 1
+    );
+    if board.config().check_limit().is_some() {
+        fen.push_str(&format!(
+            " +{}+{}",
+            stats.checks_given[&Color::White],
+            stats.checks_given[&Color::Black]
+        ));
+    }
+    Ok(fen)
+}
+
+fn piece_name(letter: char) -> Option<&'static str> {
+    match letter.to_ascii_lowercase() {
+        'b' => Some("Bishop"),
+        'k' => Some("King"),
+        'n' => Some("Knight"),
+        'p' => Some("Pawn"),
+        'q' => Some("Queen"),
+        'r' => Some("Rook"),
+        _ => None,
+    }
+}
+
+fn piece_letter(piece: &Piece) -> Result<char, FenError> {
+    let letter = match piece {
+        Piece::Bishop(_) => 'b',
+        Piece::King(_) => 'k',
+        Piece::Knight(_) => 'n',
+        Piece::Pawn(_) => 'p',
+        Piece::Queen(_) => 'q',
+        Piece::Rook(_) => 'r',
+        _ => return Err(FenError::UnsupportedPiece(piece.name().to_string())),
+    };
+    if piece.color() == &Color::White {
+        Ok(letter.to_ascii_uppercase())
+    } else {
+        Ok(letter)
+    }
+}
+
+// Row, where the pawns of the given color can make a long move from
+fn pawns_row(color: &Color, dimension: &Dimension) -> i16 {
+    if *color == Color::White {
+        dimension.min_point().y().value() + 1
+    } else {
+        dimension.max_point().y().value() - 1
+    }
+}
+
+fn parse_placement(
+    placement: &str,
+    dimension: &Dimension,
+) -> Result<Vec<(&'static str, Color, Point)>, FenError> {
+    let ranks = placement.split('/').collect::<Vec<_>>();
+    if ranks.len() != dimension.get_rows_range().len() {
+        return Err(FenError::InvalidRank(placement.to_string()));
+    }
+    let mut pieces = vec![];
+    for (rank, y) in ranks.iter().zip(dimension.get_rows_range().rev()) {
+        let mut x = *dimension.min_point().x().value();
+        let mut empty_squares = 0;
+        for letter in rank.chars() {
+            if let Some(digit) = letter.to_digit(10) {
+                empty_squares = empty_squares * 10 + digit as i16;
+                continue;
+            }
+            x += empty_squares;
+            empty_squares = 0;
+            let name = piece_name(letter).ok_or(FenError::UnknownPiece(letter))?;
+            let color = if letter.is_ascii_uppercase() {
+                Color::White
+            } else {
+                Color::Black
+            };
+            let point = Point::new(x, y);
+            if !dimension.is_in_boundaries(&point) {
+                return Err(FenError::InvalidRank(rank.to_string()));
+            }
+            pieces.push((name, color, point));
+            x += 1;
+        }
+        if x + empty_squares != dimension.max_point().x() + 1 {
+            return Err(FenError::InvalidRank(rank.to_string()));
+        }
+    }
+    Ok(pieces)
+}
+
+// Points of the kings and the rooks, which can castle. The outermost rook on the row of the king
+// castles to the given side.
+fn parse_castle(
+    castle: &str,
+    pieces: &[(&'static str, Color, Point)],
+) -> Result<Vec<Point>, FenError> {
+    let mut points = vec![];
+    if castle == "-" {
+        return Ok(points);
+    }
+    for letter in castle.chars() {
+        let color = if letter.is_ascii_uppercase() {
+            Color::White
+        } else {
+            Color::Black
+        };
+        let king_point = pieces
+            .iter()
+            .find(|(name, piece_color, _)| *name == "King" && piece_color == &color)
+            .map(|(_, _, point)| *point)
+            .ok_or(FenError::InvalidCastle(letter))?;
+        let rooks = pieces.iter().filter(|(name, piece_color, point)| {
+            *name == "Rook" && piece_color == &color && point.y() == king_point.y()
+        });
+        let rook_point = match letter.to_ascii_lowercase() {
+            'k' => rooks
+                .filter(|(_, _, point)| point.x() > king_point.x())
+                .max_by_key(|(_, _, point)| *point.x().value()),
+            'q' => rooks
+                .filter(|(_, _, point)| point.x() < king_point.x())
+                .min_by_key(|(_, _, point)| *point.x().value()),
+            _ => None,
+        }
+        .map(|(_, _, point)| *point)
+        .ok_or(FenError::InvalidCastle(letter))?;
+        points.push(king_point);
+        points.push(rook_point);
+    }
+    Ok(points)
+}
+
+fn export_castle<HT: HeatMap, SQ: SquaresMap>(board: &Board<HT, SQ>) -> String {
+    let mut castle = String::new();
+    for color in [Color::White, Color::Black] {
+        let pieces = board.active_pieces(&color).values().collect::<Vec<_>>();
+        let Some(king) = pieces
+            .iter()
+            .find(|piece| matches!(piece, Piece::King(_)))
+            .filter(|king| board.buffs(&color).has_castle(king.id()))
+        else {
+            continue;
+        };
+        let king_position = king.current_position();
+        let rooks = pieces
+            .iter()
+            .filter(|piece| {
+                matches!(piece, Piece::Rook(_))
+                    && board.buffs(&color).has_castle(piece.id())
+                    && piece.current_position().y() == king_position.y()
+            })
+            .collect::<Vec<_>>();
+        let mut letters = vec![];
+        if rooks
+            .iter()
+            .any(|rook| rook.current_position().x() > king_position.x())
+        {
+            letters.push('k');
+        }
+        if rooks
+            .iter()
+            .any(|rook| rook.current_position().x() < king_position.x())
+        {
+            letters.push('q');
+        }
+        for letter in letters {
+            castle.push(if color == Color::White {
+                letter.to_ascii_uppercase()
+            } else {
+                letter
+            });
+        }
+    }
+    if castle.is_empty() {
+        castle.push('-');
+    }
+    castle
+}
+
+// The point is exported only when there is a pawn to capture en passant
+fn export_en_passant<HT: HeatMap, SQ: SquaresMap>(board: &Board<HT, SQ>) -> String {
+    let color = board.current_turn();
+    board
+        .pawns_with_en_passant(color)
+        .iter()
+        .filter_map(|pawn_id| match board.buffs(color).en_passant(pawn_id) {
+            Some(Buff::EnPassant(point, _)) => Some(*point),
+            _ => None,
+        })
+        .min()
+        .map(|point| export_square(&point, board.dimension()))
+        .unwrap_or("-".to_string())
+}
+
+// The pawn, which has skipped the given square, is the nearest one behind it
+fn set_en_passant<HT: HeatMap, SQ: SquaresMap>(
+    board: &mut Board<HT, SQ>,
+    square: &str,
+) -> Result<(), FenError> {
+    let invalid_en_passant = || FenError::InvalidEnPassant(square.to_string());
+    let en_passant = parse_square(square, board.dimension()).ok_or_else(invalid_en_passant)?;
+    let color = board.current_turn().inverse();
+    let (_, step) = color.orientation().forward().delta();
+    let (&x, &y) = en_passant.to_tuple();
+    let mut point = Point::new(x, y + step);
+    while board.dimension().is_in_boundaries(&point) {
+        if let Some(piece) = board.piece_at(&point) {
+            if !matches!(piece, Piece::Pawn(_)) || piece.color() != &color {
+                break;
+            }
+            let pawn_id = *piece.id();
+            board.set_en_passant(&pawn_id, &Point::new(x, y - step));
+            return Ok(());
+        }
+        point = Point::new(x, point.y() + step);
+    }
+    Err(invalid_en_passant())
+}
+
+// Square in the algebraic notation, e.g. "e3". File "a" and rank 1 stand for the bottom left
+// point of the board, wherever the board starts.
+fn parse_square(square: &str, dimension: &Dimension) -> Option<Point> {
+    let mut chars = square.chars();
+    let file = chars.next().filter(|file| file.is_ascii_lowercase())?;
+    let rank = chars.as_str().parse::<i16>().ok()?;
+    let (min_x, min_y) = dimension.min_point().to_tuple();
+    let point = Point::new(min_x + (file as u8 - b'a') as i16, min_y + (rank - 1));
+    dimension.is_in_boundaries(&point).then_some(point)
+}
+
+fn export_square(point: &Point, dimension: &Dimension) -> String {
+    let min_point = dimension.min_point();
+    let file = (b'a' + (point.x() - min_point.x()) as u8) as char;
+    format!("{}{}", file, point.y() - min_point.y() + 1)
+}
+
+fn parse_number(number: &str) -> Result<usize, FenError> {
+    number
+        .parse()
+        .map_err(|_| FenError::InvalidNumber(number.to_string()))
+}
+
+fn parse_checks(checks: &str) -> Result<(u8, u8), FenError> {
+    let invalid_checks = || FenError::InvalidChecks(checks.to_string());
+    let (white_checks, black_checks) = checks
+        .strip_prefix('+')
+        .and_then(|checks| checks.split_once('+'))
+        .ok_or_else(invalid_checks)?;
+    Ok((
+        white_checks.parse().map_err(|_| invalid_checks())?,
+        black_checks.parse().map_err(|_| invalid_checks())?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dimension() -> Dimension {
+        Dimension::new(Point::new(1, 1), Point::new(8, 8))
+    }
+
+    #[test]
+    fn it_parses_placement() {
+        assert_eq!(
+            parse_placement("4k3/8/8/8/8/8/8/R3K3", &dimension()),
+            Ok(vec![
+                ("King", Color::Black, Point::new(5, 8)),
+                ("Rook", Color::White, Point::new(1, 1)),
+                ("King", Color::White, Point::new(5, 1)),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_parses_placement_of_wide_board() {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(10, 2));
+        assert_eq!(
+            parse_placement("10/9K", &dimension),
+            Ok(vec![("King", Color::White, Point::new(10, 1))])
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_placement() {
+        assert_eq!(
+            parse_placement("8/8", &dimension()),
+            Err(FenError::InvalidRank("8/8".to_string()))
+        );
+        assert_eq!(
+            parse_placement("4k4/8/8/8/8/8/8/8", &dimension()),
+            Err(FenError::InvalidRank("4k4".to_string()))
+        );
+        assert_eq!(
+            parse_placement("7/8/8/8/8/8/8/8", &dimension()),
+            Err(FenError::InvalidRank("7".to_string()))
+        );
+        assert_eq!(
+            parse_placement("4x3/8/8/8/8/8/8/8", &dimension()),
+            Err(FenError::UnknownPiece('x'))
+        );
+    }
+
+    #[test]
+    fn it_parses_castle() {
+        let pieces = parse_placement("r3k2r/8/8/8/8/8/8/R3K3", &dimension()).unwrap();
+        assert_eq!(
+            parse_castle("Qk", &pieces),
+            Ok(vec![
+                Point::new(5, 1),
+                Point::new(1, 1),
+                Point::new(5, 8),
+                Point::new(8, 8),
+            ])
+        );
+        assert_eq!(
+            parse_castle("K", &pieces),
+            Err(FenError::InvalidCastle('K'))
+        );
+    }
+
+    #[test]
+    fn it_parses_and_exports_squares_of_offset_board() {
+        let dimension = Dimension::new(Point::new(0, 0), Point::new(11, 11));
+        assert_eq!(parse_square("a1", &dimension), Some(Point::new(0, 0)));
+        assert_eq!(parse_square("f3", &dimension), Some(Point::new(5, 2)));
+        assert_eq!(export_square(&Point::new(5, 2), &dimension), "f3");
+        assert_eq!(parse_square("m1", &dimension), None);
+    }
+
+    #[test]
+    fn it_parses_squares_and_checks() {
+        let wide_dimension = Dimension::new(Point::new(1, 1), Point::new(10, 10));
+        assert_eq!(parse_square("e3", &dimension()), Some(Point::new(5, 3)));
+        assert_eq!(
+            parse_square("j10", &wide_dimension),
+            Some(Point::new(10, 10))
+        );
+        assert_eq!(parse_square("j10", &dimension()), None);
+        assert_eq!(parse_square("3", &dimension()), None);
+        assert_eq!(parse_checks("+2+1"), Ok((2, 1)));
+        assert_eq!(
+            parse_checks("3+3"),
+            Err(FenError::InvalidChecks("3+3".to_string()))
+        );
+    }
+}
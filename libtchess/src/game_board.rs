@@ -0,0 +1,145 @@
+use crate::board::Board;
+use crate::board_config::BoardConfig;
+use crate::board_stats::BoardStats;
+use crate::buff::Buff;
+use crate::collections::HashMap;
+use crate::color::Color;
+use crate::debuff::Debuff;
+use crate::dimension::Dimension;
+use crate::heat_map::HeatMap;
+use crate::move_score::MoveScore;
+use crate::piece::Piece;
+use crate::piece_id::PieceId;
+use crate::piece_move::PieceMove;
+use crate::point::Point;
+use crate::squares_map::SquaresMap;
+use crate::utils::pretty_print::PrettyPrint;
+use rustc_hash::FxBuildHasher;
+
+// Public interface of a board, a game can be played on. It allows to swap the generic Board with
+// a specialized backend, like BitBoard, per game.
+pub trait GameBoard<HT: HeatMap, SQ: SquaresMap>: PrettyPrint {
+    fn empty(config: BoardConfig<HT, SQ>) -> Self;
+
+    fn config(&self) -> &BoardConfig<HT, SQ>;
+
+    fn dimension(&self) -> &Dimension;
+
+    fn add_piece(
+        &mut self,
+        name: &str,
+        color: Color,
+        buffs: Vec<Buff>,
+        debuffs: Vec<Debuff>,
+        position: Point,
+    ) -> PieceId;
+
+    fn piece_id_at(&self, point: &Point) -> Option<&PieceId>;
+
+    fn active_pieces(&self, color: &Color) -> &HashMap<PieceId, Piece, FxBuildHasher>;
+
+    fn king(&self, color: &Color) -> Option<&Piece>;
+
+    fn is_checked(&self, color: &Color) -> bool;
+
+    // Whether the side has lost all its royal pieces, e.g. when its king exploded in atomic chess
+    fn is_out_of_royals(&self, color: &Color) -> bool;
+
+    fn is_check_limit_reached(&self, color: &Color) -> bool;
+
+    fn is_king_on_the_hill(&self, color: &Color) -> bool;
+
+    fn current_turn(&self) -> &Color;
+
+    fn has_no_moves(&self, color: &Color) -> bool;
+
+    // All moves of the given color, which can be made in the current position
+    fn legal_moves(&self, color: &Color) -> Vec<(PieceId, PieceMove)>;
+
+    fn move_piece(&mut self, piece_id: &PieceId, piece_move: &PieceMove) -> Option<MoveScore>;
+
+    fn stats(&self) -> BoardStats<'_>;
+}
+
+impl<HT: HeatMap, SQ: SquaresMap> GameBoard<HT, SQ> for Board<HT, SQ> {
+    fn empty(config: BoardConfig<HT, SQ>) -> Self {
+        Board::empty(config)
+    }
+
+    fn config(&self) -> &BoardConfig<HT, SQ> {
+        Board::config(self)
+    }
+
+    fn dimension(&self) -> &Dimension {
+        Board::dimension(self)
+    }
+
+    fn add_piece(
+        &mut self,
+        name: &str,
+        color: Color,
+        buffs: Vec<Buff>,
+        debuffs: Vec<Debuff>,
+        position: Point,
+    ) -> PieceId {
+        Board::add_piece(self, name, color, buffs, debuffs, position)
+    }
+
+    fn piece_id_at(&self, point: &Point) -> Option<&PieceId> {
+        Board::piece_id_at(self, point)
+    }
+
+    fn active_pieces(&self, color: &Color) -> &HashMap<PieceId, Piece, FxBuildHasher> {
+        Board::active_pieces(self, color)
+    }
+
+    fn king(&self, color: &Color) -> Option<&Piece> {
+        Board::king(self, color)
+    }
+
+    fn is_checked(&self, color: &Color) -> bool {
+        self.royals(color)
+            .iter()
+            .any(|royal_id| self.debuffs(color).has_check(royal_id))
+    }
+
+    fn is_out_of_royals(&self, color: &Color) -> bool {
+        Board::is_out_of_royals(self, color)
+    }
+
+    fn is_check_limit_reached(&self, color: &Color) -> bool {
+        Board::is_check_limit_reached(self, color)
+    }
+
+    fn is_king_on_the_hill(&self, color: &Color) -> bool {
+        Board::is_king_on_the_hill(self, color)
+    }
+
+    fn current_turn(&self) -> &Color {
+        Board::current_turn(self)
+    }
+
+    fn has_no_moves(&self, color: &Color) -> bool {
+        Board::has_no_moves(self, color)
+    }
+
+    fn legal_moves(&self, color: &Color) -> Vec<(PieceId, PieceMove)> {
+        let mut moves = vec![];
+        for (_, piece_to_moves) in self.score_to_moves(color) {
+            for (piece_id, piece_moves) in piece_to_moves {
+                for piece_move in piece_moves {
+                    moves.push((*piece_id, *piece_move));
+                }
+            }
+        }
+        moves
+    }
+
+    fn move_piece(&mut self, piece_id: &PieceId, piece_move: &PieceMove) -> Option<MoveScore> {
+        Board::move_piece(self, piece_id, piece_move)
+    }
+
+    fn stats(&self) -> BoardStats<'_> {
+        Board::stats(self)
+    }
+}
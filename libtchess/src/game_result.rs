@@ -0,0 +1,11 @@
+use crate::color::Color;
+
+// Results, which are shared by the games, played by the classic rules. Games with their own
+// results pass them to MoveResult instead.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GameResult {
+    Stalemate(Color),
+    Checkmate(Color),
+    FiftyMoveRuleDraw,
+    DrawByRepetition,
+}
@@ -1,8 +1,9 @@
+use crate::collections::MaybeSync;
 use crate::piece::Piece;
 use crate::point::Point;
 
 
 // Piece Square Tables (PST) implementation
-pub trait HeatMap {
+pub trait HeatMap: MaybeSync {
     fn positional_value(&self, piece: &Piece, position: &Point) -> i16;
 }
@@ -1,29 +1,49 @@
+pub mod betza;
+pub mod bitboard;
 pub mod board;
 pub mod board_config;
+pub mod board_positions;
 mod board_map;
+pub mod board_region;
 pub mod board_square;
 pub mod buff;
 pub mod castle_points;
+pub mod castle_rule;
 pub mod castle_x_points;
+pub mod classic_rules_game;
+pub mod collections;
 pub mod color;
 pub mod colored_property;
 pub mod debuff;
 pub mod dimension;
+pub mod fen;
+pub mod game_board;
+pub mod game_result;
 pub mod heat_map;
 mod ids_generator;
+pub mod move_result;
 pub mod move_score;
+pub mod movement;
 mod moves_map;
+pub mod orientation;
 pub mod piece;
+pub mod piece_definition;
 pub mod piece_id;
 pub mod piece_move;
+pub mod piece_registry;
 pub mod player;
+pub mod pocket;
 pub mod point;
 pub mod promote_piece;
+pub mod promotion_zone;
+pub mod royal_rule;
+pub mod search;
 pub mod square;
 pub mod squares_map;
 pub mod strategy_point;
 pub mod strategy_points;
 pub mod utils;
+pub mod variant;
 pub mod vector;
 pub mod vector_points;
 mod x_ray_pieces;
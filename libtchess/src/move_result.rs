@@ -0,0 +1,8 @@
+use crate::game_result::GameResult;
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum MoveResult<R = GameResult> {
+    PieceMoved,
+    IllegalMove,
+    GameEnded(R),
+}
@@ -0,0 +1,104 @@
+use crate::orientation::Orientation;
+use crate::point::Point;
+use crate::vector::Vector;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum MovementMode {
+    MoveAndCapture,
+    MoveOnly,
+    CaptureOnly,
+}
+
+// Single way a piece moves along the given direction: either by one step of the vector(leap) or
+// by any number of steps until the first obstacle(ride).
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Movement {
+    vector: Vector,
+    rides: bool,
+    mode: MovementMode,
+    // Lame leaps can be blocked by a piece, standing on the way of the leap
+    lame: bool,
+    // Hopping ride jumps over exactly one piece(screen) before it can land, like the cannon
+    // captures in xiangqi
+    hops: bool,
+}
+
+impl Movement {
+    pub fn new(vector: Vector, rides: bool, mode: MovementMode, lame: bool) -> Self {
+        Self {
+            vector,
+            rides,
+            mode,
+            lame,
+            hops: false,
+        }
+    }
+
+    pub fn hopping(self) -> Self {
+        Self { hops: true, ..self }
+    }
+
+    pub fn leap(vector: Vector) -> Self {
+        Self::new(vector, false, MovementMode::MoveAndCapture, false)
+    }
+
+    pub fn ride(vector: Vector) -> Self {
+        Self::new(vector, true, MovementMode::MoveAndCapture, false)
+    }
+
+    pub fn vector(&self) -> &Vector {
+        &self.vector
+    }
+
+    pub fn rides(&self) -> bool {
+        self.rides
+    }
+
+    pub fn mode(&self) -> &MovementMode {
+        &self.mode
+    }
+
+    pub fn is_lame(&self) -> bool {
+        self.lame
+    }
+
+    pub fn hops(&self) -> bool {
+        self.hops
+    }
+
+    pub fn can_move(&self) -> bool {
+        self.mode != MovementMode::CaptureOnly
+    }
+
+    pub fn can_capture(&self) -> bool {
+        self.mode != MovementMode::MoveOnly
+    }
+
+    // Square the lame leap passes through from the given point. Only leaps, longer than one
+    // square, have it.
+    pub fn leg(&self, from: &Point) -> Option<Point> {
+        if !self.lame {
+            return None;
+        }
+        let (dx, dy) = self.vector.delta();
+        let (&x, &y) = from.to_tuple();
+        if dx.abs() == 2 && dy.abs() == 2 {
+            Some(Point::new(x + dx.signum(), y + dy.signum()))
+        } else if dx.abs() == 2 {
+            Some(Point::new(x + dx.signum(), y))
+        } else if dy.abs() == 2 {
+            Some(Point::new(x, y + dy.signum()))
+        } else {
+            None
+        }
+    }
+
+    // The same movement, made by the side of the given orientation
+    pub fn oriented(&self, orientation: &Orientation) -> Self {
+        let (dx, dy) = self.vector.delta();
+        let (dx, dy) = orientation.orient(dx, dy);
+        let vector =
+            Vector::from_delta(dx, dy).expect("Logical error: oriented vector must exist!");
+        Self { vector, ..*self }
+    }
+}
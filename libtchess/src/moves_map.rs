@@ -1,22 +1,27 @@
 use crate::move_score::MoveScore;
 use crate::piece_id::PieceId;
 use crate::piece_move::PieceMove;
-use im_rc::{HashMap, OrdMap, Vector};
+use crate::collections::{HashMap, HashSet, OrdMap, Vector};
 use rustc_hash::FxBuildHasher;
 
 #[derive(Clone)]
 pub struct MovesMap {
     score_to_moves: OrdMap<MoveScore, OrdMap<PieceId, Vector<PieceMove>>>,
     piece_to_moves: HashMap<PieceId, HashMap<PieceMove, MoveScore, FxBuildHasher>, FxBuildHasher>,
+    // Pieces which moves are outdated and must be generated before they can be read. Only used
+    // when lazy moves generation is enabled.
+    dirty_pieces: HashSet<PieceId, FxBuildHasher>,
 }
 
 impl MovesMap {
     pub fn empty() -> Self {
         let score_to_moves = OrdMap::default();
         let piece_to_moves = HashMap::default();
+        let dirty_pieces = HashSet::default();
         Self {
             score_to_moves,
             piece_to_moves,
+            dirty_pieces,
         }
     }
 
@@ -43,8 +48,15 @@ impl MovesMap {
     }
 
     pub fn add(&mut self, piece_id: &PieceId, piece_move: PieceMove, score: MoveScore) {
-        self.p2m_moves_mut(piece_id)
-            .insert(piece_move, score);
+        // The same move can be added twice, e.g. when the king captures the piece which checks it,
+        // but it must be listed once
+        if let Some(score_was) = self.p2m_moves_mut(piece_id).insert(piece_move, score) {
+            if score_was == score {
+                return;
+            }
+            let moves = self.s2m_moves_mut(&score_was, piece_id);
+            moves.retain(|m| m != &piece_move);
+        }
         self.s2m_moves_mut(&score, piece_id).push_front(piece_move);
     }
 
@@ -60,6 +72,7 @@ impl MovesMap {
         &mut self,
         piece_id: &PieceId,
     ) -> Option<HashMap<PieceMove, MoveScore, FxBuildHasher>> {
+        self.dirty_pieces.remove(piece_id);
         let moves = self.piece_to_moves.remove(piece_id);
 
         if let Some(moves) = moves {
@@ -85,4 +98,71 @@ impl MovesMap {
     pub fn is_empty(&self) -> bool {
         self.piece_to_moves.is_empty()
     }
+
+    // Drops current moves of the given piece and remembers that they have to be generated again
+    pub fn mark_dirty(&mut self, piece_id: &PieceId) {
+        self.remove_piece(piece_id);
+        self.dirty_pieces.insert(*piece_id);
+    }
+
+    pub fn is_dirty(&self, piece_id: &PieceId) -> bool {
+        self.dirty_pieces.contains(piece_id)
+    }
+
+    pub fn has_dirty_pieces(&self) -> bool {
+        !self.dirty_pieces.is_empty()
+    }
+
+    pub fn dirty_pieces(&self) -> &HashSet<PieceId, FxBuildHasher> {
+        &self.dirty_pieces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Color;
+    use crate::point::Point;
+
+    fn listed_moves(moves_map: &MovesMap) -> Vec<(MoveScore, PieceMove)> {
+        moves_map
+            .score_to_moves()
+            .iter()
+            .flat_map(|(move_score, piece_to_moves)| {
+                piece_to_moves
+                    .values()
+                    .flat_map(|moves| moves.iter().map(|piece_move| (*move_score, *piece_move)))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_adding_the_same_move_twice() {
+        let mut moves_map = MovesMap::empty();
+        let piece_id = PieceId::new(1, &Color::White);
+        let piece_move = PieceMove::Point(Point::new(2, 2));
+        moves_map.add(&piece_id, piece_move, MoveScore::WeightDelta(10));
+        moves_map.add(&piece_id, piece_move, MoveScore::WeightDelta(10));
+        assert_eq!(
+            listed_moves(&moves_map),
+            vec![(MoveScore::WeightDelta(10), piece_move)]
+        );
+    }
+
+    #[test]
+    fn test_adding_the_same_move_with_another_score() {
+        let mut moves_map = MovesMap::empty();
+        let piece_id = PieceId::new(1, &Color::White);
+        let piece_move = PieceMove::Point(Point::new(2, 2));
+        moves_map.add(&piece_id, piece_move, MoveScore::WeightDelta(10));
+        moves_map.add(&piece_id, piece_move, MoveScore::WeightDelta(20));
+        assert_eq!(
+            listed_moves(&moves_map),
+            vec![(MoveScore::WeightDelta(20), piece_move)]
+        );
+        assert_eq!(
+            moves_map.move_score(&piece_id, &piece_move),
+            Some(&MoveScore::WeightDelta(20))
+        );
+    }
 }
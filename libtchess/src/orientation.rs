@@ -0,0 +1,54 @@
+use crate::vector::line_vector::LineVector;
+
+// Directions of the board as the side sees them. Movements of the pieces are given for the side,
+// playing from the bottom edge like White: its pawns go up the board and its king side is on the
+// right. Other sides turn the movements towards their own forward and king side directions.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Orientation {
+    forward: LineVector,
+    king_side: LineVector,
+}
+
+impl Orientation {
+    // The side plays from the bottom edge of the board, like White
+    pub const BOTTOM: Self = Self::new(LineVector::Top, LineVector::Right);
+    // The side plays from the top edge of the board, like Black. The board is mirrored rather than
+    // rotated for it, thus its king side is on the right as well.
+    pub const TOP: Self = Self::new(LineVector::Bottom, LineVector::Right);
+
+    pub const fn new(forward: LineVector, king_side: LineVector) -> Self {
+        Self { forward, king_side }
+    }
+
+    pub fn forward(&self) -> &LineVector {
+        &self.forward
+    }
+
+    pub fn king_side(&self) -> &LineVector {
+        &self.king_side
+    }
+
+    // Shift, given for the side playing from the bottom edge, as this side makes it
+    pub fn orient(&self, dx: i16, dy: i16) -> (i16, i16) {
+        let (forward_x, forward_y) = self.forward.delta();
+        let (king_side_x, king_side_y) = self.king_side.delta();
+        (
+            dx * king_side_x + dy * forward_x,
+            dx * king_side_y + dy * forward_y,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orient() {
+        assert_eq!(Orientation::BOTTOM.orient(1, 2), (1, 2));
+        assert_eq!(Orientation::TOP.orient(1, 2), (1, -2));
+        // The side plays from the left edge, its king side is at the bottom
+        let left = Orientation::new(LineVector::Right, LineVector::Bottom);
+        assert_eq!(left.orient(1, 2), (2, -1));
+    }
+}
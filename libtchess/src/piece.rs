@@ -4,6 +4,7 @@ pub mod knight;
 pub mod bishop;
 pub mod queen;
 pub mod king;
+pub mod custom_piece;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -13,6 +14,7 @@ use knight::*;
 use bishop::*;
 use queen::*;
 use king::*;
+use custom_piece::*;
 use crate::color::Color;
 use crate::piece_id::PieceId;
 use crate::point::Point;
@@ -43,22 +45,11 @@ pub enum Piece {
     Bishop(Bishop),
     Queen(Queen),
     King(King),
+    Custom(CustomPiece),
     UnknownPiece(PieceId),
 }
 
 impl Piece {
-    pub fn init_piece_by_name(name: &str, color: Color, current_position: Point, id: PieceId) -> Self {
-        match name {
-            "Pawn" => Self::Pawn(Pawn::new(color, current_position, id)),
-            "Rook" => Self::Rook(Rook::new(color, current_position, id)),
-            "Knight" => Self::Knight(Knight::new(color, current_position, id)),
-            "Bishop" => Self::Bishop(Bishop::new(color, current_position, id)),
-            "Queen" => Self::Queen(Queen::new(color, current_position, id)),
-            "King" => Self::King(King::new(color, current_position, id)),
-            _ => panic!("Unknown piece: {name}")
-        }
-    }
-    
     pub fn name(&self) -> &str {
         match self {
             Self::Pawn(_) => "Pawn",
@@ -67,6 +58,7 @@ impl Piece {
             Self::Bishop(_) => "Bishop",
             Self::Queen(_) => "Queen",
             Self::King(_) => "King",
+            Self::Custom(p) => p.definition().name(),
             Self::UnknownPiece(_) => panic!("Unknown piece can't be named properly!"),
         }
     }
@@ -79,6 +71,7 @@ impl Piece {
             Self::Bishop(p) => p.color(),
             Self::Queen(p) => p.color(),
             Self::King(p) => p.color(),
+            Self::Custom(p) => p.color(),
             Self::UnknownPiece(_) => panic!("Unknown piece does not have a color!"),
         }
     }
@@ -91,6 +84,7 @@ impl Piece {
             Self::Bishop(p) => p.current_position(),
             Self::Queen(p) => p.current_position(),
             Self::King(p) => p.current_position(),
+            Self::Custom(p) => p.current_position(),
             Self::UnknownPiece(_) => panic!("Unknown piece does not have a position!"),
         }
     }
@@ -103,6 +97,7 @@ impl Piece {
             Self::Bishop(p) => p.set_current_position(point),
             Self::Queen(p) => p.set_current_position(point),
             Self::King(p) => p.set_current_position(point),
+            Self::Custom(p) => p.set_current_position(point),
             Self::UnknownPiece(_) => panic!("Can't set a position of an unknown piece!"),
         }
     }
@@ -115,13 +110,14 @@ impl Piece {
             Self::Bishop(p) => p.id(),
             Self::Queen(p) => p.id(),
             Self::King(p) => p.id(),
+            Self::Custom(p) => p.id(),
             Self::UnknownPiece(id) => id,
         }
     }
 
 
     pub fn is_ally(&self, color: &Color) -> bool {
-        self.color() == color
+        self.color().is_ally(color)
     }
 
     pub fn is_enemy(&self, color: &Color) -> bool {
@@ -136,9 +132,30 @@ impl Piece {
             Self::Bishop(p) => p.attack_vector(point1, point2),
             Self::Queen(p) => p.attack_vector(point1, point2),
             Self::King(p) => p.attack_vector(point1, point2),
+            Self::Custom(p) => p.attack_vector(point1, point2),
             Self::UnknownPiece(_) => panic!("Can't calculate attack vector for an unknown piece!"),
         }
     }
+
+    // Whether the piece moves along the given direction until the first obstacle
+    pub fn slides_along(&self, vector: &Vector) -> bool {
+        match (self, vector) {
+            (Self::Queen(_), Vector::Diagonal(_) | Vector::Line(_)) => true,
+            (Self::Rook(_), Vector::Line(_)) => true,
+            (Self::Bishop(_), Vector::Diagonal(_)) => true,
+            (Self::Custom(p), _) => p.slides_along(vector),
+            _ => false,
+        }
+    }
+
+    // Sliding pieces are the ones which can x-ray the opposite king and pin pieces in front of it
+    pub fn is_slider(&self) -> bool {
+        match self {
+            Self::Bishop(_) | Self::Rook(_) | Self::Queen(_) => true,
+            Self::Custom(p) => p.is_slider(),
+            _ => false,
+        }
+    }
 }
 
 impl PrettyPrint for Piece {
@@ -150,6 +167,7 @@ impl PrettyPrint for Piece {
             Self::Bishop(p) => p.pp(),
             Self::Queen(p) => p.pp(),
             Self::King(p) => p.pp(),
+            Self::Custom(p) => p.pp(),
             Self::UnknownPiece(_) => panic!("Don't know how to display an unknown piece!"),
         }
     }
@@ -164,6 +182,7 @@ impl Display for Piece {
             Self::Bishop(p) => write!(f, "{}{}", p.pp(), p.current_position()),
             Self::Queen(p) => write!(f, "{}{}", p.pp(), p.current_position()),
             Self::King(p) => write!(f, "{}{}", p.pp(), p.current_position()),
+            Self::Custom(p) => write!(f, "{}{}", p.pp(), p.current_position()),
             Self::UnknownPiece(piece_id) => write!(f, "U#{}", piece_id.id()),
         }
     }
@@ -178,6 +197,7 @@ impl Hash for Piece {
             Self::Bishop(p) => p.id().hash(hasher),
             Self::Queen(p) => p.id().hash(hasher),
             Self::King(p) => p.id().hash(hasher),
+            Self::Custom(p) => p.id().hash(hasher),
             Self::UnknownPiece(id) => id.hash(hasher),
         }
     }
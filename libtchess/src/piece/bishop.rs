@@ -44,13 +44,15 @@ impl Bishop {
         &self,
         board_map: &BoardMap,
         dimension: &Dimension,
+        max_ride_distance: Option<u16>,
         mut consumer: F,
     ) {
         let bishop_color = self.bishop_color(board_map.board_square(&self.current_position()));
-        let opposite_king_id = board_map.king_id(&self.color.inverse());
+        let kings = board_map.kings();
         for direction in self.attack_vectors() {
             let vector_points =
-                VectorPoints::without_initial(self.current_position, *dimension, direction);
+                VectorPoints::without_initial(self.current_position, *dimension, direction)
+                    .limit(max_ride_distance);
             for point in vector_points {
                 let square = board_map.board_square(&point);
 
@@ -64,7 +66,7 @@ impl Bishop {
                 if square.is_ally_square(&self.color) {
                     consumer(StrategyPoint::Defense(point));
                 }
-                if !square.can_look_through(&self.color, opposite_king_id) {
+                if !square.can_look_through(&self.color, kings) {
                     break;
                 }
             }
@@ -76,6 +78,7 @@ impl Bishop {
         board_map: &BoardMap,
         cdebuffs_map: &ColoredProperty<DebuffsMap>,
         dimension: &Dimension,
+        max_ride_distance: Option<u16>,
         mut consumer: F,
     ) {
         let debuff = cdebuffs_map[&self.color].pin(&self.id);
@@ -95,11 +98,12 @@ impl Bishop {
                 .collect::<Vec<_>>()
         };
         let bishop_color = self.bishop_color(board_map.board_square(&self.current_position));
-        let opposite_king_id = board_map.king_id(&self.color.inverse());
+        let kings = board_map.kings();
 
         for direction in available_directions {
             let vector_points =
-                VectorPoints::without_initial(self.current_position, *dimension, direction);
+                VectorPoints::without_initial(self.current_position, *dimension, direction)
+                    .limit(max_ride_distance);
             for point in vector_points {
                 let square = board_map.board_square(&point);
 
@@ -110,7 +114,7 @@ impl Bishop {
                 let piece_move = PieceMove::Point(point);
 
                 if square.is_empty_square()
-                    || square.is_capturable_enemy_square(&self.color, opposite_king_id)
+                    || square.is_capturable_enemy_square(&self.color, kings)
                 {
                     consumer(piece_move);
                 }
@@ -162,9 +166,9 @@ impl PieceInit for Bishop {
 
 impl PrettyPrint for Bishop {
     fn pp(&self) -> String {
-        match self.color {
+        match self.color.appearance() {
             Color::White => if INVERT_COLORS { '♝' } else { '♗' }.to_string(),
-            Color::Black => if INVERT_COLORS { '♗' } else { '♝' }.to_string(),
+            _ => if INVERT_COLORS { '♗' } else { '♝' }.to_string(),
         }
     }
 }
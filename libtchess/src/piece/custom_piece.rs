@@ -0,0 +1,269 @@
+use crate::board_map::BoardMap;
+use crate::collections::Shared;
+use crate::color::Color;
+use crate::colored_property::ColoredProperty;
+use crate::debuff::Debuff;
+use crate::debuffs_map::DebuffsMap;
+use crate::dimension::Dimension;
+use crate::movement::Movement;
+use crate::piece::PieceId;
+use crate::piece_definition::PieceDefinition;
+use crate::piece_move::PieceMove;
+use crate::point::Point;
+use crate::strategy_point::StrategyPoint;
+use crate::utils::pretty_print::PrettyPrint;
+use crate::vector::Vector;
+use crate::vector_points::VectorPoints;
+
+// A piece, which moves are driven by its PieceDefinition
+#[derive(Debug, Clone)]
+pub struct CustomPiece {
+    color: Color,
+    current_position: Point,
+    id: PieceId,
+    // Kind of the piece, assigned by the PieceRegistry. It distinguishes the piece from other
+    // pieces in the Zobrist key.
+    kind: u64,
+    definition: Shared<dyn PieceDefinition>,
+}
+
+impl CustomPiece {
+    pub fn new(
+        color: Color,
+        current_position: Point,
+        id: PieceId,
+        kind: u64,
+        definition: Shared<dyn PieceDefinition>,
+    ) -> Self {
+        Self {
+            color,
+            current_position,
+            id,
+            kind,
+            definition,
+        }
+    }
+
+    pub fn id(&self) -> &PieceId {
+        &self.id
+    }
+
+    pub fn color(&self) -> &Color {
+        &self.color
+    }
+
+    pub fn current_position(&self) -> &Point {
+        &self.current_position
+    }
+
+    pub fn set_current_position(&mut self, point: Point) {
+        self.current_position = point;
+    }
+
+    pub fn kind(&self) -> u64 {
+        self.kind
+    }
+
+    pub fn definition(&self) -> &dyn PieceDefinition {
+        &*self.definition
+    }
+
+    pub fn calculate_strategy_points<F: FnMut(StrategyPoint)>(
+        &self,
+        board_map: &BoardMap,
+        dimension: &Dimension,
+        max_ride_distance: Option<u16>,
+        mut consumer: F,
+    ) {
+        let kings = board_map.kings();
+        for movement in self.definition.movements(&self.color) {
+            if let Some(leg) = movement.leg(&self.current_position) {
+                // A piece, appearing on the way of the lame leap, changes the moves of this piece
+                consumer(StrategyPoint::BlockedMove(leg));
+                if !board_map.board_square(&leg).is_empty_square() {
+                    continue;
+                }
+            }
+            let vector_points = VectorPoints::without_initial(
+                self.current_position,
+                *dimension,
+                *movement.vector(),
+            )
+            .limit(max_ride_distance.filter(|_| movement.rides()));
+            if movement.hops() {
+                self.hop_strategy_points(board_map, &movement, vector_points, &mut consumer);
+                continue;
+            }
+            for point in vector_points {
+                let square = board_map.board_square(&point);
+
+                if square.is_void_square() {
+                    consumer(StrategyPoint::DeadEnd(point));
+                    break;
+                }
+                if movement.can_capture() {
+                    if square.is_empty_square() || square.is_enemy_square(&self.color) {
+                        consumer(StrategyPoint::Attack(point));
+                    }
+                    if square.is_ally_square(&self.color) {
+                        consumer(StrategyPoint::Defense(point));
+                    }
+                    if !movement.rides() || !square.can_look_through(&self.color, kings)
+                    {
+                        break;
+                    }
+                } else {
+                    if !square.is_empty_square() {
+                        consumer(StrategyPoint::BlockedMove(point));
+                        break;
+                    }
+                    consumer(StrategyPoint::Move(point));
+                    if !movement.rides() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    // Any piece, appearing on the way to the screen or replacing the screen, changes the landing
+    // point of the hop. Only the points behind the screen are reached.
+    fn hop_strategy_points<F: FnMut(StrategyPoint)>(
+        &self,
+        board_map: &BoardMap,
+        movement: &Movement,
+        vector_points: VectorPoints,
+        consumer: &mut F,
+    ) {
+        let mut has_screen = false;
+        for point in vector_points {
+            let square = board_map.board_square(&point);
+
+            if square.is_void_square() {
+                consumer(StrategyPoint::DeadEnd(point));
+                break;
+            }
+            if !has_screen {
+                consumer(StrategyPoint::BlockedMove(point));
+                has_screen = !square.is_empty_square();
+                continue;
+            }
+            if movement.can_capture() {
+                if square.is_ally_square(&self.color) {
+                    consumer(StrategyPoint::Defense(point));
+                } else {
+                    consumer(StrategyPoint::Attack(point));
+                }
+            } else if square.is_empty_square() {
+                consumer(StrategyPoint::Move(point));
+            } else {
+                consumer(StrategyPoint::BlockedMove(point));
+            }
+            if !movement.rides() || !square.is_empty_square() {
+                break;
+            }
+        }
+    }
+
+    pub fn calculate_moves<F: FnMut(PieceMove)>(
+        &self,
+        board_map: &BoardMap,
+        cdebuffs_map: &ColoredProperty<DebuffsMap>,
+        dimension: &Dimension,
+        max_ride_distance: Option<u16>,
+        mut consumer: F,
+    ) {
+        let mut movements = self.definition.movements(&self.color);
+        // Pinned piece can only move along the pin direction
+        if let Some(debuff) = cdebuffs_map[&self.color].pin(&self.id) {
+            let pin_vector = match debuff {
+                Debuff::Pin(v) => v,
+                _ => panic!("Logical error! Expected pin debuff, but got {:?}", debuff),
+            };
+            movements.retain(|movement| {
+                pin_vector == movement.vector() || &pin_vector.inverse() == movement.vector()
+            });
+        }
+        let kings = board_map.kings();
+
+        for movement in movements {
+            if let Some(leg) = movement.leg(&self.current_position)
+                && !board_map.board_square(&leg).is_empty_square()
+            {
+                continue;
+            }
+            let vector_points = VectorPoints::without_initial(
+                self.current_position,
+                *dimension,
+                *movement.vector(),
+            )
+            .limit(max_ride_distance.filter(|_| movement.rides()));
+            let mut has_screen = !movement.hops();
+            for point in vector_points {
+                let square = board_map.board_square(&point);
+
+                if square.is_void_square() {
+                    break;
+                }
+                if !has_screen {
+                    has_screen = !square.is_empty_square();
+                    continue;
+                }
+
+                if (square.is_empty_square() && movement.can_move())
+                    || (square.is_capturable_enemy_square(&self.color, kings)
+                        && movement.can_capture())
+                {
+                    consumer(PieceMove::Point(point))
+                }
+                if !movement.rides() || !square.is_empty_square() {
+                    break;
+                }
+            }
+        }
+    }
+
+    pub fn attack_vector(&self, point1: &Point, point2: &Point) -> Option<Vector> {
+        let movements = self.definition.movements(&self.color);
+        let mut capturing = movements
+            .iter()
+            .filter(|movement| movement.can_capture() && !movement.hops());
+        // Leaps go first. An attack by a leap can't be blocked even if a riding movement of the
+        // piece attacks the same point.
+        capturing
+            .clone()
+            .find(|movement| {
+                !movement.rides() && &movement.vector().calc_next_point(point1) == point2
+            })
+            .or_else(|| {
+                capturing
+                    .find(|movement| movement.rides() && movement.vector().reaches(point1, point2))
+            })
+            .map(|movement| *movement.vector())
+    }
+
+    pub fn slides_along(&self, vector: &Vector) -> bool {
+        self.definition
+            .movements(&self.color)
+            .iter()
+            .any(|movement| {
+                movement.vector() == vector
+                    && movement.rides()
+                    && movement.can_capture()
+                    && !movement.hops()
+            })
+    }
+
+    pub fn is_slider(&self) -> bool {
+        self.definition
+            .movements(&self.color)
+            .iter()
+            .any(|movement| movement.rides() && movement.can_capture() && !movement.hops())
+    }
+}
+
+impl PrettyPrint for CustomPiece {
+    fn pp(&self) -> String {
+        self.definition.symbol(&self.color).to_string()
+    }
+}
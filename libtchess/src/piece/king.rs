@@ -79,10 +79,24 @@ impl King {
         cdebuffs_map: &ColoredProperty<DebuffsMap>,
         dimension: &Dimension,
         board_config: &BoardConfig<HT, SQ>,
-        opposite_strategy_points: &StrategyPoints,
+        cstrategy_points: &ColoredProperty<StrategyPoints>,
         mut consumer: F,
     ) {
         let available_directions = Vector::diagonal_and_line_vectors();
+        let is_atomic = board_config.is_atomic();
+
+        // The king is an ordinary piece in antichess, thus it does not care about the attacked
+        // squares
+        if board_config.is_antichess() {
+            for direction in available_directions {
+                let point = direction.calc_next_point(&self.current_position);
+                let square = board_map.board_square(&point);
+                if square.is_empty_square() || square.is_enemy_square(&self.color) {
+                    consumer(PieceMove::Point(point));
+                }
+            }
+            return;
+        }
 
         for direction in available_directions {
             let vector_points =
@@ -93,12 +107,19 @@ impl King {
                 if square.is_void_square() {
                     break;
                 }
-                if square.is_empty_square() && !opposite_strategy_points.is_under_attack(&point) {
+                // In atomic chess kings standing next to each other can't check each other, thus
+                // the king can step next to the enemy king even when the square is attacked
+                if square.is_empty_square()
+                    && (!self.is_attacked(&point, board_config, cstrategy_points)
+                        || is_atomic && self.is_next_to_enemy_king(&point, board_map))
+                {
                     consumer(PieceMove::Point(point));
                     break;
                 }
-                if square.is_enemy_square(&self.color)
-                    && !opposite_strategy_points.is_under_enemy_defense(&point)
+                // The king capturing a piece would explode itself in atomic chess
+                if !is_atomic
+                    && square.is_enemy_square(&self.color)
+                    && !self.is_defended(&point, board_config, cstrategy_points)
                 {
                     consumer(PieceMove::Point(point));
                 }
@@ -113,7 +134,7 @@ impl King {
                 cdebuffs_map,
                 dimension,
                 board_config,
-                opposite_strategy_points,
+                cstrategy_points,
                 consumer,
             );
         }
@@ -126,12 +147,19 @@ impl King {
         cdebuffs_map: &ColoredProperty<DebuffsMap>,
         dimension: &Dimension,
         board_config: &BoardConfig<HT, SQ>,
-        opposite_strategy_points: &StrategyPoints,
+        cstrategy_points: &ColoredProperty<StrategyPoints>,
         mut consumer: F,
     ) {
         let current_position = self.current_position;
 
-        for (king_point, rook_point, side) in self.castle_points(board_config) {
+        for rule in board_config.castle_rules() {
+            let side = rule.side();
+            let (king_point, rook_point) =
+                rule.castle_points(&current_position, self.color.orientation());
+            if !dimension.is_in_boundaries(&king_point) || !dimension.is_in_boundaries(&rook_point)
+            {
+                continue;
+            }
             let mut king_path_is_safe = false;
             let mut rook_path_is_safe = false;
             let mut ally_rook: Option<&Piece> = None;
@@ -155,7 +183,7 @@ impl King {
                     if square.is_void_square() {
                         break;
                     }
-                    if opposite_strategy_points.is_under_attack(&point) {
+                    if self.is_attacked(&point, board_config, cstrategy_points) {
                         break;
                     }
                     if let Some(piece_id) = square.get_piece_id() {
@@ -166,7 +194,8 @@ impl King {
 
                         match piece {
                             Piece::Rook(_) => {
-                                if direction == side.direction() && ally_rook.is_none() {
+                                if direction == side.direction(self.color.orientation())
+                                    && ally_rook.is_none() {
                                     // King meets a rook when looking up its path to its castle
                                     // point. In this case we can say that rook's path is safe,
                                     // too
@@ -194,8 +223,11 @@ impl King {
             // side castle and to the right for king side castle. There should not be any
             // pieces between the king and the rook.
             if ally_rook.is_none() {
-                let points =
-                    VectorPoints::without_initial(current_position, *dimension, side.direction());
+                let points = VectorPoints::without_initial(
+                    current_position,
+                    *dimension,
+                    side.direction(self.color.orientation()),
+                );
 
                 for point in points {
                     if let Some(piece) = board_map.piece_at(&point) {
@@ -221,6 +253,11 @@ impl King {
                 if !(cbuffs_map[rook.color()].has_castle(rook.id()) && !cdebuffs_map[rook.color()].has_pin(rook.id())) {
                     continue;
                 }
+                if !rule.non_corner_rook()
+                    && !Self::is_in_corner(rook, side, board_map, dimension)
+                {
+                    continue;
+                }
 
                 // Rook was placed outside of king's path to king's castle point. Thus, we
                 // have to make sure the rook's path to its castle point is safe as well.
@@ -245,8 +282,10 @@ impl King {
                                 if piece.is_enemy(self.color()) {
                                     break;
                                 }
+                                // The rook may go through the king or land on its initial
+                                // point
                                 match piece {
-                                    Piece::King(_) => continue,
+                                    Piece::King(_) => (),
                                     _ => break,
                                 }
                             }
@@ -269,33 +308,54 @@ impl King {
         }
     }
 
-    fn castle_points<HT: HeatMap, SQ: SquaresMap>(&self, config: &BoardConfig<HT, SQ>) -> [(Point, Point, CastleSide); 2] {
-        let king_side_points = config.king_side_castle_x_points();
-        let queen_side_points = config.queen_side_castle_x_points();
-        [
-            (
-                Point::new(
-                    *king_side_points.king_x(),
-                    *self.current_position().y().value(),
-                ),
-                Point::new(
-                    *king_side_points.rook_x(),
-                    *self.current_position().y().value(),
-                ),
-                CastleSide::King,
-            ),
-            (
-                Point::new(
-                    *queen_side_points.king_x(),
-                    *self.current_position().y().value(),
-                ),
-                Point::new(
-                    *queen_side_points.rook_x(),
-                    *self.current_position().y().value(),
-                ),
-                CastleSide::Queen,
-            ),
-        ]
+    // Whether any of the enemy sides attacks the given point
+    fn is_attacked<HT: HeatMap, SQ: SquaresMap>(
+        &self,
+        point: &Point,
+        board_config: &BoardConfig<HT, SQ>,
+        cstrategy_points: &ColoredProperty<StrategyPoints>,
+    ) -> bool {
+        board_config
+            .enemies_of(&self.color)
+            .any(|enemy| cstrategy_points[enemy].is_under_attack(point))
+    }
+
+    // Whether the enemy piece on the given point is defended by any of the enemy sides
+    fn is_defended<HT: HeatMap, SQ: SquaresMap>(
+        &self,
+        point: &Point,
+        board_config: &BoardConfig<HT, SQ>,
+        cstrategy_points: &ColoredProperty<StrategyPoints>,
+    ) -> bool {
+        board_config
+            .enemies_of(&self.color)
+            .any(|enemy| cstrategy_points[enemy].is_under_enemy_defense(point))
+    }
+
+    fn is_next_to_enemy_king(&self, point: &Point, board_map: &BoardMap) -> bool {
+        self.attack_vectors().iter().any(|direction| {
+            board_map
+                .piece_at(&direction.calc_next_point(point))
+                .is_some_and(|piece| matches!(piece, Piece::King(_)) && piece.is_enemy(self.color()))
+        })
+    }
+
+    // The rook is in the corner when there are no more squares behind it on the castle side
+    fn is_in_corner(
+        rook: &Piece,
+        side: &CastleSide,
+        board_map: &BoardMap,
+        dimension: &Dimension,
+    ) -> bool {
+        let mut points = VectorPoints::without_initial(
+            *rook.current_position(),
+            *dimension,
+            side.direction(rook.color().orientation()),
+        );
+        match points.next() {
+            Some(point) => board_map.board_square(&point).is_void_square(),
+            None => true,
+        }
     }
 
     pub fn attack_vectors(&self) -> Vec<Vector> {
@@ -329,11 +389,9 @@ impl PieceInit for King {
 
 impl PrettyPrint for King {
     fn pp(&self) -> String {
-        match self.color {
+        match self.color.appearance() {
             Color::White => if INVERT_COLORS { '♚' } else { '♔' }.to_string(),
-            Color::Black => if INVERT_COLORS { '♔' } else { '♚' }
-                .to_string()
-                .to_string(),
+            _ => if INVERT_COLORS { '♔' } else { '♚' }.to_string(),
         }
     }
 }
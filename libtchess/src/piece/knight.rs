@@ -78,7 +78,7 @@ impl Knight {
             // moves.
             return;
         }
-        let opposite_king_id = board_map.king_id(&self.color.inverse());
+        let kings = board_map.kings();
 
         for direction in Vector::jump_vectors() {
             let vector_points =
@@ -93,7 +93,7 @@ impl Knight {
                 let piece_move = PieceMove::Point(point);
 
                 if square.is_empty_square()
-                    || square.is_capturable_enemy_square(&self.color, opposite_king_id)
+                    || square.is_capturable_enemy_square(&self.color, kings)
                 {
                     consumer(piece_move)
                 }
@@ -131,9 +131,9 @@ impl PieceInit for Knight {
 
 impl PrettyPrint for Knight {
     fn pp(&self) -> String {
-        match self.color {
+        match self.color.appearance() {
             Color::White => if INVERT_COLORS { '♞' } else { '♘' }.to_string(),
-            Color::Black => if INVERT_COLORS { '♘' } else { '♞' }.to_string(),
+            _ => if INVERT_COLORS { '♘' } else { '♞' }.to_string(),
         }
     }
 }
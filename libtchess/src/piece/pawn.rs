@@ -1,4 +1,5 @@
 use crate::board::{INVERT_COLORS};
+use crate::board_config::BoardConfig;
 use crate::board_map::BoardMap;
 use crate::buff::Buff;
 use crate::buffs_map::BuffsMap;
@@ -7,10 +8,12 @@ use crate::colored_property::ColoredProperty;
 use crate::debuff::Debuff;
 use crate::debuffs_map::DebuffsMap;
 use crate::dimension::Dimension;
+use crate::heat_map::HeatMap;
 use crate::piece::{PieceId, PieceInit};
 use crate::piece_move::PieceMove;
 use crate::point::Point;
-use crate::promote_piece::PromotePiece;
+use crate::promotion_zone::PromotionZone;
+use crate::squares_map::SquaresMap;
 use crate::strategy_point::StrategyPoint;
 use crate::utils::pretty_print::PrettyPrint;
 use crate::vector::Vector;
@@ -42,13 +45,14 @@ impl Pawn {
         self.current_position = point;
     }
 
-    pub fn calculate_strategy_points<F: FnMut(StrategyPoint)>(
+    pub fn calculate_strategy_points<F: FnMut(StrategyPoint), HT: HeatMap, SQ: SquaresMap>(
         &self,
         board_map: &BoardMap,
         cbuffs_map: &ColoredProperty<BuffsMap>,
-        dimension: &Dimension,
+        board_config: &BoardConfig<HT, SQ>,
         mut consumer: F,
     ) {
+        let dimension = board_config.dimension();
         // Attack/defense directions
         for direction in self.attack_vectors() {
             let vector_points =
@@ -67,12 +71,8 @@ impl Pawn {
             }
         }
         // Move direction
-        let direction = match self.color {
-            Color::White => Vector::Line(LineVector::Top),
-            Color::Black => Vector::Line(LineVector::Bottom),
-        };
         let vector_points =
-            VectorPoints::without_initial(self.current_position, *dimension, direction);
+            VectorPoints::without_initial(self.current_position, *dimension, self.forward());
         let mut points_calculated: u8 = 0;
         for point in vector_points {
             let square = board_map.board_square(&point);
@@ -88,37 +88,26 @@ impl Pawn {
                 break;
             }
             points_calculated += 1;
-            if cbuffs_map[&self.color].has_additional_point(&self.id) && points_calculated == 1 {
+            if cbuffs_map[&self.color].has_additional_point(&self.id)
+                && points_calculated < board_config.pawn_initial_steps()
+            {
                 continue;
             }
             break;
         }
     }
 
-    pub fn calculate_moves<F: FnMut(PieceMove)>(
+    pub fn calculate_moves<F: FnMut(PieceMove), HT: HeatMap, SQ: SquaresMap>(
         &self,
         board_map: &BoardMap,
         cbuffs_map: &ColoredProperty<BuffsMap>,
         cdebuffs_map: &ColoredProperty<DebuffsMap>,
-        dimension: &Dimension,
+        board_config: &BoardConfig<HT, SQ>,
         mut consumer: F,
     ) {
-        let mut available_directions = match self.color {
-            Color::White => {
-                vec![
-                    Vector::Line(LineVector::Top),
-                    Vector::Diagonal(DiagonalVector::TopLeft),
-                    Vector::Diagonal(DiagonalVector::TopRight),
-                ]
-            }
-            Color::Black => {
-                vec![
-                    Vector::Line(LineVector::Bottom),
-                    Vector::Diagonal(DiagonalVector::BottomLeft),
-                    Vector::Diagonal(DiagonalVector::BottomRight),
-                ]
-            }
-        };
+        let dimension = board_config.dimension();
+        let mut available_directions = vec![self.forward()];
+        available_directions.extend(self.attack_vectors());
 
         if let Some(debuff) = cdebuffs_map[&self.color].pin(&self.id) {
             let pin_vector =
@@ -133,19 +122,11 @@ impl Pawn {
                 .collect::<Vec<_>>();
         }
 
-        let pre_promote_position = match self.color {
-            Color::White => {
-                &(dimension.max_point().y().value() - 1) == self.current_position.y().value()
-            }
-            Color::Black => {
-                &(dimension.min_point().y().value() + 1) == self.current_position.y().value()
-            }
-        };
         for direction in available_directions {
             let vector_points =
                 VectorPoints::without_initial(self.current_position, *dimension, direction);
-            let mut points_calculated = 0;
-            let opposite_king_id = board_map.king_id(&self.color.inverse());
+            let mut points_calculated: u8 = 0;
+            let kings = board_map.kings();
 
             for point in vector_points {
                 let square = board_map.board_square(&point);
@@ -155,44 +136,57 @@ impl Pawn {
                 }
                 match direction {
                     Vector::Diagonal(_) => {
-                        if let Some(buff) = cbuffs_map[&self.color].en_passant(&self.id)
-                        {
+                        if let Some(buff) = cbuffs_map[&self.color].en_passant(&self.id) {
                             let (en_passant, enemy_piece_point) =
                                 match buff {
                                     Buff::EnPassant(p1, p2) => (*p1, *p2),
                                     _ => panic!("Logical error! EnPassant buff is expected, but got: {:?}", buff),
                                 };
                             if en_passant == point {
-                                consumer(PieceMove::EnPassant(en_passant, enemy_piece_point));
-                            }
-                        } else {
-                            if square.is_capturable_enemy_square(&self.color, opposite_king_id) {
-                                if pre_promote_position {
-                                    for variant in PromotePiece::all_variants() {
-                                        consumer(PieceMove::Promote(point, variant))
-                                    }
-                                } else {
-                                    consumer(PieceMove::Point(point))
-                                }
-                            }
-                        }
-                    }
-                    Vector::Line(_) => {
-                        if square.is_empty_square() {
-                            if pre_promote_position {
-                                for variant in PromotePiece::all_variants() {
-                                    consumer(PieceMove::Promote(point, variant))
+                                if !self.en_passant_exposes_king(
+                                    &en_passant,
+                                    &enemy_piece_point,
+                                    board_map,
+                                    dimension,
+                                ) {
+                                    consumer(PieceMove::EnPassant(en_passant, enemy_piece_point));
                                 }
                                 break;
                             }
-                            if points_calculated == 1 {
-                                consumer(PieceMove::LongMove(point))
+                        }
+                        if square.is_capturable_enemy_square(&self.color, kings) {
+                            if board_config.is_promotion_square(&point, &self.color) {
+                                self.promote(PieceMove::Point(point), board_config, &mut consumer);
                             } else {
                                 consumer(PieceMove::Point(point))
                             }
                         }
+                    }
+                    Vector::Line(_) => {
+                        // The pawn can't move through the occupied square
+                        if !square.is_empty_square() {
+                            break;
+                        }
+                        let piece_move = if points_calculated > 0 {
+                            PieceMove::LongMove(point)
+                        } else {
+                            PieceMove::Point(point)
+                        };
+                        // The long move promotes the pawn only when it lands inside the zone of
+                        // several ranks or squares. The last rank is promoted on by a single step.
+                        let promotes = match board_config.promotion_zone(&self.color) {
+                            PromotionZone::LastRank => points_calculated == 0,
+                            _ => true,
+                        };
+                        if promotes && board_config.is_promotion_square(&point, &self.color) {
+                            self.promote(piece_move, board_config, &mut consumer);
+                        } else {
+                            consumer(piece_move)
+                        }
                         points_calculated += 1;
-                        if cbuffs_map[&self.color].has_additional_point(&self.id) && points_calculated < 2 {
+                        if cbuffs_map[&self.color].has_additional_point(&self.id)
+                            && points_calculated < board_config.pawn_initial_steps()
+                        {
                             continue;
                         }
                     }
@@ -203,21 +197,81 @@ impl Pawn {
         }
     }
 
-    pub fn attack_vectors(&self) -> Vec<Vector> {
-        match self.color {
-            Color::White => {
-                vec![
-                    Vector::Diagonal(DiagonalVector::TopLeft),
-                    Vector::Diagonal(DiagonalVector::TopRight),
-                ]
-            }
-            Color::Black => {
-                vec![
-                    Vector::Diagonal(DiagonalVector::BottomLeft),
-                    Vector::Diagonal(DiagonalVector::BottomRight),
-                ]
+    // The pawn may stay unpromoted after the given move, when optional promotions are enabled and
+    // it can go further from there
+    fn promote<F: FnMut(PieceMove), HT: HeatMap, SQ: SquaresMap>(
+        &self,
+        piece_move: PieceMove,
+        board_config: &BoardConfig<HT, SQ>,
+        consumer: &mut F,
+    ) {
+        let point = *piece_move.destination();
+        for variant in board_config.promote_pieces() {
+            consumer(PieceMove::Promote(point, *variant))
+        }
+        if board_config.has_optional_pawn_promotions()
+            && board_config
+                .dimension()
+                .is_in_boundaries(&self.forward().calc_next_point(&point))
+        {
+            consumer(piece_move)
+        }
+    }
+
+    // En passant capture removes two pieces from the same row at once, thus it may open the line
+    // to the king, which is not covered by pins
+    fn en_passant_exposes_king(
+        &self,
+        en_passant: &Point,
+        enemy_piece_point: &Point,
+        board_map: &BoardMap,
+        dimension: &Dimension,
+    ) -> bool {
+        let Some(king) = board_map.king(&self.color) else {
+            return false;
+        };
+        let directions = LineVector::all_variants()
+            .into_iter()
+            .map(Vector::Line)
+            .chain(DiagonalVector::all_variants().into_iter().map(Vector::Diagonal));
+        for direction in directions {
+            let vector_points =
+                VectorPoints::without_initial(*king.current_position(), *dimension, direction);
+            for point in vector_points {
+                if &point == en_passant {
+                    break;
+                }
+                if point == self.current_position || &point == enemy_piece_point {
+                    continue;
+                }
+                if let Some(piece) = board_map.piece_at(&point) {
+                    if piece.slides_along(&direction.inverse()) && piece.is_enemy(&self.color) {
+                        return true;
+                    }
+                    break;
+                }
+                if board_map.board_square(&point).is_void_square() {
+                    break;
+                }
             }
         }
+        false
+    }
+
+    // Direction the pawn moves along, towards the opposite edge of the board for its side
+    pub fn forward(&self) -> Vector {
+        Vector::Line(*self.color.orientation().forward())
+    }
+
+    pub fn attack_vectors(&self) -> Vec<Vector> {
+        let orientation = self.color.orientation();
+        [(-1, 1), (1, 1)]
+            .into_iter()
+            .map(|(dx, dy)| {
+                let (dx, dy) = orientation.orient(dx, dy);
+                Vector::from_delta(dx, dy).expect("Logical error: pawn attack vector must exist!")
+            })
+            .collect()
     }
 
     pub fn attack_vector(&self, point1: &Point, point2: &Point) -> Option<Vector> {
@@ -250,9 +304,9 @@ impl PieceInit for Pawn {
 
 impl PrettyPrint for Pawn {
     fn pp(&self) -> String {
-        match self.color {
+        match self.color.appearance() {
             Color::White => if INVERT_COLORS { '♟' } else { '♙' }.to_string(),
-            Color::Black => if INVERT_COLORS { '♙' } else { '♟' }.to_string(),
+            _ => if INVERT_COLORS { '♙' } else { '♟' }.to_string(),
         }
     }
 }
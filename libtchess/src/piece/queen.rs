@@ -43,12 +43,14 @@ impl Queen {
         &self,
         board_map: &BoardMap,
         dimension: &Dimension,
+        max_ride_distance: Option<u16>,
         mut consumer: F,
     ) {
-        let opposite_king_id = board_map.king_id(&self.color.inverse());
+        let kings = board_map.kings();
         for direction in self.attack_vectors() {
             let vector_points =
-                VectorPoints::without_initial(self.current_position, *dimension, direction);
+                VectorPoints::without_initial(self.current_position, *dimension, direction)
+                    .limit(max_ride_distance);
             for point in vector_points {
                 let square = board_map.board_square(&point);
 
@@ -62,7 +64,7 @@ impl Queen {
                 if square.is_ally_square(&self.color) {
                     consumer(StrategyPoint::Defense(point));
                 }
-                if !square.can_look_through(&self.color, opposite_king_id) {
+                if !square.can_look_through(&self.color, kings) {
                     break;
                 }
             }
@@ -74,6 +76,7 @@ impl Queen {
         board_map: &BoardMap,
         cdebuffs_map: &ColoredProperty<DebuffsMap>,
         dimension: &Dimension,
+        max_ride_distance: Option<u16>,
         mut consumer: F,
     ) {
         let debuff = cdebuffs_map[&self.color].pin(&self.id);
@@ -92,11 +95,12 @@ impl Queen {
                 .map(|&vec| vec)
                 .collect::<Vec<_>>()
         };
-        let opposite_king_id = board_map.king_id(&self.color.inverse());
+        let kings = board_map.kings();
 
         for direction in available_directions {
             let vector_points =
-                VectorPoints::without_initial(self.current_position, *dimension, direction);
+                VectorPoints::without_initial(self.current_position, *dimension, direction)
+                    .limit(max_ride_distance);
             for point in vector_points {
                 let square = board_map.board_square(&point);
 
@@ -107,7 +111,7 @@ impl Queen {
                 let piece_move = PieceMove::Point(point);
 
                 if square.is_empty_square()
-                    || square.is_capturable_enemy_square(&self.color, opposite_king_id)
+                    || square.is_capturable_enemy_square(&self.color, kings)
                 {
                     consumer(piece_move)
                 }
@@ -149,9 +153,9 @@ impl PieceInit for Queen {
 
 impl PrettyPrint for Queen {
     fn pp(&self) -> String {
-        match self.color {
+        match self.color.appearance() {
             Color::White => if INVERT_COLORS { '♛' } else { '♕' }.to_string(),
-            Color::Black => if INVERT_COLORS { '♕' } else { '♛' }.to_string(),
+            _ => if INVERT_COLORS { '♕' } else { '♛' }.to_string(),
         }
     }
 }
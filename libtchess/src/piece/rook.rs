@@ -42,12 +42,14 @@ impl Rook {
         &self,
         board_map: &BoardMap,
         dimension: &Dimension,
+        max_ride_distance: Option<u16>,
         mut consumer: F,
     ) {
-        let opposite_king_id = board_map.king_id(&self.color.inverse());
+        let kings = board_map.kings();
         for direction in self.attack_vectors() {
             let vector_points =
-                VectorPoints::without_initial(self.current_position, *dimension, direction);
+                VectorPoints::without_initial(self.current_position, *dimension, direction)
+                    .limit(max_ride_distance);
             for point in vector_points {
                 let square = board_map.board_square(&point);
 
@@ -61,7 +63,7 @@ impl Rook {
                 if square.is_ally_square(&self.color) {
                     consumer(StrategyPoint::Defense(point));
                 }
-                if !square.can_look_through(&self.color, opposite_king_id) {
+                if !square.can_look_through(&self.color, kings) {
                     break;
                 }
             }
@@ -73,6 +75,7 @@ impl Rook {
         board_map: &BoardMap,
         cdebuffs_map: &ColoredProperty<DebuffsMap>,
         dimension: &Dimension,
+        max_ride_distance: Option<u16>,
         mut consumer: F,
     ) {
         let debuff = cdebuffs_map[&self.color].pin(&self.id);
@@ -91,11 +94,12 @@ impl Rook {
                 .map(|&vec| vec)
                 .collect::<Vec<_>>()
         };
-        let opposite_king_id = board_map.king_id(&self.color.inverse());
+        let kings = board_map.kings();
 
         for direction in available_directions {
             let vector_points =
-                VectorPoints::without_initial(self.current_position, *dimension, direction);
+                VectorPoints::without_initial(self.current_position, *dimension, direction)
+                    .limit(max_ride_distance);
             for point in vector_points {
                 let square = board_map.board_square(&point);
 
@@ -106,7 +110,7 @@ impl Rook {
                 let piece_move = PieceMove::Point(point);
 
                 if square.is_empty_square()
-                    || square.is_capturable_enemy_square(&self.color, opposite_king_id)
+                    || square.is_capturable_enemy_square(&self.color, kings)
                 {
                     consumer(piece_move)
                 }
@@ -146,9 +150,9 @@ impl PieceInit for Rook {
 
 impl PrettyPrint for Rook {
     fn pp(&self) -> String {
-        match self.color {
+        match self.color.appearance() {
             Color::White => if INVERT_COLORS { '♜' } else { '♖' }.to_string(),
-            Color::Black => if INVERT_COLORS { '♖' } else { '♜' }.to_string(),
+            _ => if INVERT_COLORS { '♖' } else { '♜' }.to_string(),
         }
     }
 }
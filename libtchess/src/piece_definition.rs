@@ -0,0 +1,22 @@
+use crate::collections::MaybeSync;
+use crate::color::Color;
+use crate::movement::Movement;
+use std::fmt::Debug;
+
+// Describes a piece, which is not built into the library. Pieces, defined this way, are added to
+// the PieceRegistry of the board config and then can be placed on the board by their names just
+// like the built-in ones.
+pub trait PieceDefinition: MaybeSync + Debug {
+    // Unique name of the piece. It is used to add the piece to the board.
+    fn name(&self) -> &str;
+
+    // Symbol to display the piece of the given color with
+    fn symbol(&self, color: &Color) -> char;
+
+    // Material value of the piece. Heat maps, which know nothing about the piece, may rely on it.
+    fn value(&self) -> i16;
+
+    // Ways the piece of the given color moves and captures. Only riding movements, which can
+    // capture and do not hop, pin enemy pieces.
+    fn movements(&self, color: &Color) -> Vec<Movement>;
+}
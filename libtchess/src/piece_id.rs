@@ -2,31 +2,27 @@ use crate::color::Color;
 use std::fmt::{Display, Formatter};
 
 #[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Copy, Clone)]
-pub struct PieceId(isize);
+pub struct PieceId {
+    id: isize,
+    color: Color,
+}
 
 impl PieceId {
     pub fn new(id: isize, color: &Color) -> Self {
-        match color {
-            Color::White => Self(id),
-            Color::Black => Self(-id),
-        }
+        Self { id, color: *color }
     }
 
     pub fn color(&self) -> Color {
-        if self.0 > 0 {
-            Color::White
-        } else {
-            Color::Black
-        }
+        self.color
     }
 
     pub fn id(&self) -> &isize {
-        &self.0
+        &self.id
     }
 }
 
 impl Display for PieceId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.id)
     }
 }
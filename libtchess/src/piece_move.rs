@@ -10,10 +10,14 @@ pub enum PieceMove {
     // first point is where a pawn should land and second point is enemy pawn position
     EnPassant(Point, Point),
     Castle(CastlePoints),
-    // This move is related to pawns. More specifically - when a pawn is moved by two points instead
-    // of one. We later use it to calculate EnPassant moves for the opposite color.
+    // This move is related to pawns. More specifically - when a pawn is moved by more than one
+    // point. We later use it to calculate EnPassant moves on every skipped point for the opposite
+    // color.
     LongMove(Point),
     Promote(Point, PromotePiece),
+    // A piece from the pocket is placed on the empty point. The piece to drop is identified by its
+    // id in the pocket.
+    Drop(Point),
 }
 
 impl PieceMove {
@@ -22,7 +26,8 @@ impl PieceMove {
             Self::Point(point) |
             Self::EnPassant(point, _) |
             Self::LongMove(point) | 
-            Self::Promote(point, _) => { &point },
+            Self::Promote(point, _) |
+            Self::Drop(point) => { &point },
             Self::Castle(castle_points) => {
                 castle_points.king_point()
             },
@@ -49,6 +54,7 @@ impl Display for PieceMove {
             Self::Promote(point, promote_piece) => {
                 write!(f, "PieceMove::Promote({} on {})", promote_piece.name(), point)
             },
+            Self::Drop(point) => write!(f, "PieceMove::Drop{}", point),
         }
     }
 }
\ No newline at end of file
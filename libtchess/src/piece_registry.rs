@@ -0,0 +1,146 @@
+use crate::board_summary::{
+    BISHOP_REPR, KING_REPR, KNIGHT_REPR, PAWN_REPR, PIECE_KIND_SIZE, QUEEN_REPR, ROOK_REPR,
+};
+use crate::collections::{HashMap, Shared};
+use crate::color::Color;
+use crate::piece::bishop::Bishop;
+use crate::piece::custom_piece::CustomPiece;
+use crate::piece::king::King;
+use crate::piece::knight::Knight;
+use crate::piece::pawn::Pawn;
+use crate::piece::queen::Queen;
+use crate::piece::rook::Rook;
+use crate::piece::{Piece, PieceInit};
+use crate::piece_definition::PieceDefinition;
+use crate::piece_id::PieceId;
+use crate::point::Point;
+use crate::promote_piece::PromotePiece;
+
+// Number of the pieces, built into the library. Kinds of custom pieces are numbered after them.
+const BUILTIN_PIECES_COUNT: u64 = 6;
+// The kind must fit into its part of the packed piece, used by the Zobrist hashing
+const MAX_PIECE_KINDS: u64 = 1 << PIECE_KIND_SIZE;
+
+#[derive(Clone)]
+enum PieceKind {
+    Builtin(u64, fn(Color, Point, PieceId) -> Piece),
+    Custom(u64, Shared<dyn PieceDefinition>),
+}
+
+impl PieceKind {
+    fn kind(&self) -> u64 {
+        match self {
+            Self::Builtin(kind, _) | Self::Custom(kind, _) => *kind,
+        }
+    }
+}
+
+// Pieces which can be placed on the board, by their names
+#[derive(Clone)]
+pub struct PieceRegistry {
+    kinds: HashMap<String, PieceKind>,
+    custom_kinds_count: u64,
+    // Pieces a pawn can promote to. Only registered pieces are included.
+    promote_pieces: Vec<PromotePiece>,
+}
+
+impl PieceRegistry {
+    pub fn empty() -> Self {
+        Self {
+            kinds: HashMap::new(),
+            custom_kinds_count: 0,
+            promote_pieces: vec![],
+        }
+    }
+
+    // Registry of the classic chess pieces
+    pub fn classic() -> Self {
+        let mut registry = Self::empty();
+        registry.add_builtin("Pawn", PAWN_REPR, |color, position, id| {
+            Piece::Pawn(Pawn::new(color, position, id))
+        });
+        registry.add_builtin("Rook", ROOK_REPR, |color, position, id| {
+            Piece::Rook(Rook::new(color, position, id))
+        });
+        registry.add_builtin("Knight", KNIGHT_REPR, |color, position, id| {
+            Piece::Knight(Knight::new(color, position, id))
+        });
+        registry.add_builtin("Bishop", BISHOP_REPR, |color, position, id| {
+            Piece::Bishop(Bishop::new(color, position, id))
+        });
+        registry.add_builtin("Queen", QUEEN_REPR, |color, position, id| {
+            Piece::Queen(Queen::new(color, position, id))
+        });
+        registry.add_builtin("King", KING_REPR, |color, position, id| {
+            Piece::King(King::new(color, position, id))
+        });
+        registry
+    }
+
+    fn add_builtin(&mut self, name: &str, kind: u64, init: fn(Color, Point, PieceId) -> Piece) {
+        self.kinds
+            .insert(name.to_string(), PieceKind::Builtin(kind, init));
+        self.update_promote_pieces();
+    }
+
+    // Adds the piece under the name of its definition. A piece, registered under the same name
+    // before, is replaced and its kind is taken over by the new one.
+    pub fn register<D: PieceDefinition + 'static>(&mut self, definition: D) {
+        let kind = match self.kinds.get(definition.name()) {
+            Some(piece_kind) => piece_kind.kind(),
+            None => {
+                let kind = BUILTIN_PIECES_COUNT + self.custom_kinds_count;
+                if kind >= MAX_PIECE_KINDS {
+                    panic!("Can't register more than {} piece kinds!", MAX_PIECE_KINDS);
+                }
+                self.custom_kinds_count += 1;
+                kind
+            }
+        };
+        self.kinds.insert(
+            definition.name().to_string(),
+            PieceKind::Custom(kind, Shared::new(definition)),
+        );
+        self.update_promote_pieces();
+    }
+
+    pub fn is_registered(&self, name: &str) -> bool {
+        self.kinds.contains_key(name)
+    }
+
+    // Whether any of the registered pieces hops over a screen
+    pub fn has_hoppers(&self) -> bool {
+        self.kinds.values().any(|kind| match kind {
+            PieceKind::Builtin(..) => false,
+            PieceKind::Custom(_, definition) => definition
+                .movements(&Color::White)
+                .iter()
+                .any(|movement| movement.hops()),
+        })
+    }
+
+    pub fn promote_pieces(&self) -> &[PromotePiece] {
+        &self.promote_pieces
+    }
+
+    fn update_promote_pieces(&mut self) {
+        self.promote_pieces = PromotePiece::all_variants()
+            .into_iter()
+            .filter(|promote_piece| self.is_registered(&promote_piece.name()))
+            .collect();
+    }
+
+    pub fn init_piece(&self, name: &str, color: Color, position: Point, id: PieceId) -> Piece {
+        match self.kinds.get(name) {
+            Some(PieceKind::Builtin(_, init)) => init(color, position, id),
+            Some(PieceKind::Custom(kind, definition)) => Piece::Custom(CustomPiece::new(
+                color,
+                position,
+                id,
+                *kind,
+                definition.clone(),
+            )),
+            None => panic!("Unknown piece: {name}"),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::collections::OrdMap;
+use crate::piece_id::PieceId;
+
+// Pieces, captured by the side, which can be dropped back to the board. Each piece in the pocket
+// has its own id, so its drops are stored in the moves map like the moves of any other piece.
+#[derive(Clone)]
+pub struct Pocket {
+    pieces: OrdMap<PieceId, String>,
+}
+
+impl Pocket {
+    pub fn empty() -> Self {
+        Self {
+            pieces: OrdMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, piece_id: PieceId, name: &str) {
+        self.pieces.insert(piece_id, name.to_string());
+    }
+
+    pub fn remove(&mut self, piece_id: &PieceId) -> Option<String> {
+        self.pieces.remove(piece_id)
+    }
+
+    pub fn name_of(&self, piece_id: &PieceId) -> Option<&str> {
+        self.pieces.get(piece_id).map(|name| name.as_str())
+    }
+
+    pub fn contains(&self, piece_id: &PieceId) -> bool {
+        self.pieces.contains_key(piece_id)
+    }
+
+    pub fn count(&self, name: &str) -> usize {
+        self.pieces
+            .values()
+            .filter(|piece_name| *piece_name == name)
+            .count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pieces.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&PieceId, &str)> {
+        self.pieces
+            .iter()
+            .map(|(piece_id, name)| (piece_id, name.as_str()))
+    }
+
+    // Pieces of the same kind have the same drops, thus only the first piece of each kind can be
+    // dropped
+    pub fn droppable(&self) -> Vec<(PieceId, &str)> {
+        let mut droppable: Vec<(PieceId, &str)> = vec![];
+        for (piece_id, name) in self.iter() {
+            if droppable
+                .iter()
+                .all(|(_, droppable_name)| *droppable_name != name)
+            {
+                droppable.push((*piece_id, name));
+            }
+        }
+        droppable
+    }
+}
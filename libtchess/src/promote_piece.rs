@@ -4,6 +4,19 @@ pub enum PromotePiece {
     Bishop,
     Rook,
     Queen,
+    // Compound pieces of the variants like Capablanca chess. A pawn can promote to them only when
+    // they are registered in the PieceRegistry.
+    Archbishop,
+    Chancellor,
+    // The same compound pieces under their names in Grand chess
+    Cardinal,
+    Marshal,
+    // The king is only a promotion target in the variants where it is not royal, like antichess.
+    // Thus, it is not included into all_variants() and must be set explicitly.
+    King,
+    // The piece turns into its promoted form, set by BoardConfig::set_promotion, like in shogi.
+    // It is not included into all_variants() as well.
+    Promoted,
 }
 
 impl PromotePiece {
@@ -13,6 +26,10 @@ impl PromotePiece {
             Self::Bishop,
             Self::Rook,
             Self::Queen,
+            Self::Archbishop,
+            Self::Chancellor,
+            Self::Cardinal,
+            Self::Marshal,
         ]
     }
 
@@ -22,6 +39,12 @@ impl PromotePiece {
             Self::Bishop => String::from("Bishop"),
             Self::Rook => String::from("Rook"),
             Self::Queen => String::from("Queen"),
+            Self::Archbishop => String::from("Archbishop"),
+            Self::Chancellor => String::from("Chancellor"),
+            Self::Cardinal => String::from("Cardinal"),
+            Self::Marshal => String::from("Marshal"),
+            Self::King => String::from("King"),
+            Self::Promoted => String::from("Promoted"),
         }
     }
 }
@@ -0,0 +1,15 @@
+use crate::collections::HashSet;
+use crate::point::Point;
+use std::ops::RangeInclusive;
+
+// Squares where a pawn of the color gets promoted
+#[derive(Debug, Clone)]
+pub enum PromotionZone {
+    // The last rank of the board in the direction pawns of the color move
+    LastRank,
+    // Ranks of the given range, e.g. 8..=10 ranks of the white side in Grand chess
+    Ranks(RangeInclusive<i16>),
+    Squares(HashSet<Point>),
+    // Squares, marked as promotion squares of the color by the SquaresMap of the board
+    SquaresMap,
+}
@@ -0,0 +1,9 @@
+// When the side, having several royal pieces, loses the game
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum RoyalRule {
+    // Every royal piece is protected from check. The side loses when any of them is mated.
+    AnyMated,
+    // Royal pieces can be captured as long as the side has several of them. The last one is
+    // protected from check, so the side loses when it is either captured or mated.
+    AllCaptured,
+}
@@ -0,0 +1,368 @@
+pub mod transposition_table;
+
+use crate::board::Board;
+use crate::color::Color;
+use crate::heat_map::HeatMap;
+use crate::piece_id::PieceId;
+use crate::piece_move::PieceMove;
+use crate::squares_map::SquaresMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use transposition_table::{Bound, TableEntry, TranspositionTable};
+
+const MATE_SCORE: i32 = 1_000_000;
+// Mate scores are within this distance from MATE_SCORE. It is used to distinguish them from
+// regular scores.
+const MAX_MATE_DISTANCE: i32 = 10_000;
+const INFINITY: i32 = 2 * MATE_SCORE;
+const DEFAULT_TABLE_SLOTS_NUMBER: usize = 1 << 16;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SearchResult {
+    piece_id: PieceId,
+    piece_move: PieceMove,
+    // Score of the position after the move from the point of view of the side to move
+    score: i32,
+}
+
+impl SearchResult {
+    pub fn piece_id(&self) -> &PieceId {
+        &self.piece_id
+    }
+
+    pub fn piece_move(&self) -> &PieceMove {
+        &self.piece_move
+    }
+
+    pub fn score(&self) -> i32 {
+        self.score
+    }
+}
+
+// Alpha-beta search of the best move. When "sync" feature is enabled and more than one thread is
+// configured, the search is performed in Lazy SMP fashion: helper threads search copies of the
+// root board with varied depths and moves order and share their findings with the main thread
+// through the transposition table. Otherwise the search is single-threaded and deterministic.
+pub struct Search {
+    max_depth: u8,
+    threads_number: usize,
+    transposition_table: TranspositionTable,
+}
+
+impl Search {
+    pub fn new(max_depth: u8) -> Self {
+        Self::with_table_size(max_depth, DEFAULT_TABLE_SLOTS_NUMBER)
+    }
+
+    pub fn with_table_size(max_depth: u8, table_slots_number: usize) -> Self {
+        Self {
+            max_depth: max_depth.max(1),
+            threads_number: 1,
+            transposition_table: TranspositionTable::new(table_slots_number),
+        }
+    }
+
+    pub fn set_threads_number(&mut self, threads_number: usize) {
+        self.threads_number = threads_number.max(1);
+    }
+
+    pub fn threads_number(&self) -> usize {
+        self.threads_number
+    }
+
+    pub fn max_depth(&self) -> u8 {
+        self.max_depth
+    }
+
+    pub fn transposition_table(&self) -> &TranspositionTable {
+        &self.transposition_table
+    }
+
+    pub fn best_move<HT: HeatMap + Clone, SQ: SquaresMap + Clone>(
+        &self,
+        board: &Board<HT, SQ>,
+    ) -> Option<SearchResult> {
+        // Entries of previous searches are dropped, so the result depends on the given position
+        // only
+        self.transposition_table.clear();
+        let stop = AtomicBool::new(false);
+        if self.threads_number == 1 {
+            return Worker::new(&self.transposition_table, &stop, 0).search(board, self.max_depth);
+        }
+        self.parallel_best_move(board, &stop)
+    }
+
+    #[cfg(feature = "sync")]
+    fn parallel_best_move<HT: HeatMap + Clone, SQ: SquaresMap + Clone>(
+        &self,
+        board: &Board<HT, SQ>,
+        stop: &AtomicBool,
+    ) -> Option<SearchResult> {
+        std::thread::scope(|scope| {
+            for worker_number in 1..self.threads_number {
+                let board = board.clone();
+                // Every second helper searches one ply deeper than the main thread
+                let depth = self.max_depth + (worker_number % 2) as u8;
+                let transposition_table = &self.transposition_table;
+                scope.spawn(move || {
+                    Worker::new(transposition_table, stop, worker_number).search(&board, depth)
+                });
+            }
+            let result =
+                Worker::new(&self.transposition_table, stop, 0).search(board, self.max_depth);
+            stop.store(true, Ordering::Relaxed);
+            result
+        })
+    }
+
+    #[cfg(not(feature = "sync"))]
+    fn parallel_best_move<HT: HeatMap + Clone, SQ: SquaresMap + Clone>(
+        &self,
+        board: &Board<HT, SQ>,
+        stop: &AtomicBool,
+    ) -> Option<SearchResult> {
+        // The board can't be sent to other threads without "sync" feature
+        Worker::new(&self.transposition_table, stop, 0).search(board, self.max_depth)
+    }
+}
+
+struct Worker<'a> {
+    transposition_table: &'a TranspositionTable,
+    stop: &'a AtomicBool,
+    // Helper threads start the search of the root position from different moves, so they explore
+    // different parts of the tree first. It is 0 for the main thread.
+    root_moves_shift: usize,
+}
+
+impl<'a> Worker<'a> {
+    fn new(
+        transposition_table: &'a TranspositionTable,
+        stop: &'a AtomicBool,
+        root_moves_shift: usize,
+    ) -> Self {
+        Self {
+            transposition_table,
+            stop,
+            root_moves_shift,
+        }
+    }
+
+    // Iterative deepening. Returns the best move of the last completed iteration.
+    fn search<HT: HeatMap + Clone, SQ: SquaresMap + Clone>(
+        &self,
+        board: &Board<HT, SQ>,
+        max_depth: u8,
+    ) -> Option<SearchResult> {
+        let mut result = None;
+        for depth in 1..=max_depth {
+            match self.search_root(board, depth) {
+                Some(depth_result) => result = Some(depth_result),
+                None => break,
+            }
+        }
+        result
+    }
+
+    fn search_root<HT: HeatMap + Clone, SQ: SquaresMap + Clone>(
+        &self,
+        board: &Board<HT, SQ>,
+        depth: u8,
+    ) -> Option<SearchResult> {
+        let zkey = *board.stats().zposition;
+        let moves = Self::ordered_moves(board);
+        if moves.is_empty() {
+            return None;
+        }
+        let best_index_was = self
+            .transposition_table
+            .get(&zkey)
+            .map(|e| e.move_index() as usize);
+
+        let mut alpha = -INFINITY;
+        let mut best_index = 0;
+        for index in Self::moves_order(moves.len(), best_index_was, self.root_moves_shift) {
+            let (piece_id, piece_move) = moves[index];
+            let mut next_board = board.clone();
+            next_board.move_piece(&piece_id, &piece_move);
+            let score = -self.negamax(&next_board, depth - 1, -INFINITY, -alpha, 1)?;
+            if score > alpha {
+                alpha = score;
+                best_index = index;
+            }
+        }
+
+        self.transposition_table.store(
+            &zkey,
+            TableEntry::new(alpha, depth, Bound::Exact, best_index as u16),
+        );
+        let (piece_id, piece_move) = moves[best_index];
+        Some(SearchResult {
+            piece_id,
+            piece_move,
+            score: alpha,
+        })
+    }
+
+    // Returns None when the search was stopped
+    fn negamax<HT: HeatMap + Clone, SQ: SquaresMap + Clone>(
+        &self,
+        board: &Board<HT, SQ>,
+        depth: u8,
+        mut alpha: i32,
+        beta: i32,
+        ply: i32,
+    ) -> Option<i32> {
+        if self.stop.load(Ordering::Relaxed) {
+            return None;
+        }
+        let color = *board.current_turn();
+        if board.is_out_of_royals(&color)
+            || board.config().enemies_of(&color).any(|enemy_color| {
+                board.is_check_limit_reached(enemy_color) || board.is_king_on_the_hill(enemy_color)
+            })
+        {
+            return Some(ply - MATE_SCORE);
+        }
+        if board.has_no_moves(&color) {
+            // The side, which can't move in antichess, wins
+            if board.config().is_antichess() {
+                return Some(MATE_SCORE - ply);
+            }
+            // The side, which has lost all its pieces, like the horde, loses
+            if board.active_pieces(&color).is_empty() {
+                return Some(ply - MATE_SCORE);
+            }
+            let is_checked = board
+                .royals(&color)
+                .iter()
+                .any(|royal_id| board.debuffs(&color).has_check(royal_id));
+            return Some(if is_checked { ply - MATE_SCORE } else { 0 });
+        }
+        if depth == 0 {
+            return Some(Self::evaluate(board, &color));
+        }
+
+        let zkey = *board.stats().zposition;
+        let mut best_index_was = None;
+        if let Some(entry) = self.transposition_table.get(&zkey) {
+            let score = Self::score_from_table(entry.score(), ply);
+            if entry.depth() >= depth {
+                match entry.bound() {
+                    Bound::Exact => return Some(score),
+                    Bound::Lower if score >= beta => return Some(score),
+                    Bound::Upper if score <= alpha => return Some(score),
+                    _ => (),
+                }
+            }
+            best_index_was = Some(entry.move_index() as usize);
+        }
+
+        let moves = Self::ordered_moves(board);
+        let alpha_was = alpha;
+        let mut best_score = -INFINITY;
+        let mut best_index = 0;
+        for index in Self::moves_order(moves.len(), best_index_was, 0) {
+            let (piece_id, piece_move) = moves[index];
+            let mut next_board = board.clone();
+            next_board.move_piece(&piece_id, &piece_move);
+            let score = -self.negamax(&next_board, depth - 1, -beta, -alpha, ply + 1)?;
+            if score > best_score {
+                best_score = score;
+                best_index = index;
+            }
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        let bound = if best_score <= alpha_was {
+            Bound::Upper
+        } else if best_score >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        self.transposition_table.store(
+            &zkey,
+            TableEntry::new(
+                Self::score_to_table(best_score, ply),
+                depth,
+                bound,
+                best_index as u16,
+            ),
+        );
+        Some(best_score)
+    }
+
+    // Moves of the side to move, the moves with the best score go first
+    fn ordered_moves<HT: HeatMap + Clone, SQ: SquaresMap + Clone>(
+        board: &Board<HT, SQ>,
+    ) -> Vec<(PieceId, PieceMove)> {
+        let mut moves = vec![];
+        for (_, piece_to_moves) in board.score_to_moves(board.current_turn()).iter().rev() {
+            for (piece_id, piece_moves) in piece_to_moves {
+                for piece_move in piece_moves {
+                    moves.push((*piece_id, *piece_move));
+                }
+            }
+        }
+        moves
+    }
+
+    // Indexes of the moves in the order they should be searched. The best move, found by the
+    // previous search of the position, goes first.
+    fn moves_order(
+        moves_number: usize,
+        best_index_was: Option<usize>,
+        shift: usize,
+    ) -> impl Iterator<Item = usize> {
+        let best_index_was = best_index_was.filter(|index| *index < moves_number);
+        best_index_was.into_iter().chain(
+            (0..moves_number)
+                .map(move |index| (index + shift) % moves_number)
+                .filter(move |index| Some(*index) != best_index_was),
+        )
+    }
+
+    fn evaluate<HT: HeatMap + Clone, SQ: SquaresMap + Clone>(
+        board: &Board<HT, SQ>,
+        color: &Color,
+    ) -> i32 {
+        let heat_map = board.config().heat_map();
+        let positional_weight = |color: &Color| {
+            board
+                .active_pieces(color)
+                .values()
+                .map(|piece| heat_map.positional_value(piece, piece.current_position()) as i32)
+                .sum::<i32>()
+        };
+        positional_weight(color)
+            - board
+                .config()
+                .enemies_of(color)
+                .map(positional_weight)
+                .sum::<i32>()
+    }
+
+    // Mate scores are stored relatively to the position they were found in, so they remain
+    // correct when the same position is met at a different ply.
+    fn score_to_table(score: i32, ply: i32) -> i32 {
+        if score > MATE_SCORE - MAX_MATE_DISTANCE {
+            score + ply
+        } else if score < MAX_MATE_DISTANCE - MATE_SCORE {
+            score - ply
+        } else {
+            score
+        }
+    }
+
+    fn score_from_table(score: i32, ply: i32) -> i32 {
+        if score > MATE_SCORE - MAX_MATE_DISTANCE {
+            score - ply
+        } else if score < MAX_MATE_DISTANCE - MATE_SCORE {
+            score + ply
+        } else {
+            score
+        }
+    }
+}
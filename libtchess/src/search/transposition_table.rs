@@ -0,0 +1,189 @@
+use crate::board_summary::ZKey;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const SCORE_SIZE: u32 = 32;
+const DEPTH_OFFSET: u32 = 32;
+const DEPTH_SIZE: u32 = 8;
+const BOUND_OFFSET: u32 = 40;
+const BOUND_SIZE: u32 = 2;
+const MOVE_INDEX_OFFSET: u32 = 42;
+const MOVE_INDEX_SIZE: u32 = 16;
+const OCCUPIED_OFFSET: u32 = 58;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Bound {
+    Exact,
+    // The real score is greater than or equal to the stored one
+    Lower,
+    // The real score is less than or equal to the stored one
+    Upper,
+}
+
+impl Bound {
+    fn to_u64(self) -> u64 {
+        match self {
+            Self::Exact => 0,
+            Self::Lower => 1,
+            Self::Upper => 2,
+        }
+    }
+
+    fn from_u64(value: u64) -> Self {
+        match value {
+            0 => Self::Exact,
+            1 => Self::Lower,
+            2 => Self::Upper,
+            _ => panic!("Logical error: unknown bound representation {value}"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TableEntry {
+    score: i32,
+    depth: u8,
+    bound: Bound,
+    // Index of the best move in the list of moves of the position, ordered by the search
+    move_index: u16,
+}
+
+impl TableEntry {
+    pub fn new(score: i32, depth: u8, bound: Bound, move_index: u16) -> Self {
+        Self {
+            score,
+            depth,
+            bound,
+            move_index,
+        }
+    }
+
+    pub fn score(&self) -> i32 {
+        self.score
+    }
+
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    pub fn bound(&self) -> Bound {
+        self.bound
+    }
+
+    pub fn move_index(&self) -> u16 {
+        self.move_index
+    }
+
+    // Layout(high to low bits) and size(bits):
+    // [occupied: 1; move index: 16; bound: 2; depth: 8; score: 32]
+    fn pack(&self) -> u64 {
+        (self.score as u32 as u64)
+            | ((self.depth as u64) << DEPTH_OFFSET)
+            | (self.bound.to_u64() << BOUND_OFFSET)
+            | ((self.move_index as u64) << MOVE_INDEX_OFFSET)
+            | (1 << OCCUPIED_OFFSET)
+    }
+
+    fn unpack(packed: u64) -> Option<Self> {
+        if packed >> OCCUPIED_OFFSET == 0 {
+            return None;
+        }
+        let bits = |offset: u32, size: u32| (packed >> offset) & ((1 << size) - 1);
+        Some(Self {
+            score: bits(0, SCORE_SIZE) as u32 as i32,
+            depth: bits(DEPTH_OFFSET, DEPTH_SIZE) as u8,
+            bound: Bound::from_u64(bits(BOUND_OFFSET, BOUND_SIZE)),
+            move_index: bits(MOVE_INDEX_OFFSET, MOVE_INDEX_SIZE) as u16,
+        })
+    }
+}
+
+struct Slot {
+    // Key is stored XOR-ed with the data. This way a torn write, made by two threads storing into
+    // the same slot at the same time, is detected during the lookup as a key mismatch.
+    key_xor_data: AtomicU64,
+    data: AtomicU64,
+}
+
+// Lock-free transposition table which is shared between the search threads. Slots are chosen by
+// the low bits of the Zobrist key, and the high bits are used to verify the slot belongs to the
+// position.
+pub struct TranspositionTable {
+    slots: Vec<Slot>,
+}
+
+impl TranspositionTable {
+    pub fn new(slots_number: usize) -> Self {
+        let slots = (0..slots_number.next_power_of_two())
+            .map(|_| Slot {
+                key_xor_data: AtomicU64::new(0),
+                data: AtomicU64::new(0),
+            })
+            .collect();
+        Self { slots }
+    }
+
+    pub fn get(&self, zkey: &ZKey) -> Option<TableEntry> {
+        let slot = self.slot(zkey);
+        let data = slot.data.load(Ordering::Relaxed);
+        let key_xor_data = slot.key_xor_data.load(Ordering::Relaxed);
+        if key_xor_data ^ data != Self::verification_key(zkey) {
+            return None;
+        }
+        TableEntry::unpack(data)
+    }
+
+    pub fn store(&self, zkey: &ZKey, entry: TableEntry) {
+        let slot = self.slot(zkey);
+        let data = entry.pack();
+        slot.data.store(data, Ordering::Relaxed);
+        slot.key_xor_data
+            .store(Self::verification_key(zkey) ^ data, Ordering::Relaxed);
+    }
+
+    pub fn clear(&self) {
+        for slot in self.slots.iter() {
+            slot.data.store(0, Ordering::Relaxed);
+            slot.key_xor_data.store(0, Ordering::Relaxed);
+        }
+    }
+
+    fn slot(&self, zkey: &ZKey) -> &Slot {
+        &self.slots[(zkey.0 as usize) & (self.slots.len() - 1)]
+    }
+
+    fn verification_key(zkey: &ZKey) -> u64 {
+        (zkey.0 >> 64) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stores_and_finds_entry() {
+        let table = TranspositionTable::new(16);
+        let zkey = ZKey(0xa7d2c50b1827dd5c_63d6f65c2c508220);
+        let entry = TableEntry::new(-350, 4, Bound::Lower, 12);
+        table.store(&zkey, entry);
+        assert_eq!(table.get(&zkey), Some(entry));
+    }
+
+    #[test]
+    fn does_not_find_entry_of_another_position_in_the_same_slot() {
+        let table = TranspositionTable::new(16);
+        let zkey = ZKey(0xa7d2c50b1827dd5c_63d6f65c2c508220);
+        let other_zkey = ZKey(0xb599dc227a3a1a24_63d6f65c2c508220);
+        table.store(&zkey, TableEntry::new(10, 1, Bound::Exact, 0));
+        assert_eq!(table.get(&other_zkey), None);
+    }
+
+    #[test]
+    fn does_not_find_entry_after_clear() {
+        let table = TranspositionTable::new(16);
+        let zkey = ZKey(0xa7d2c50b1827dd5c_63d6f65c2c508220);
+        table.store(&zkey, TableEntry::new(10, 1, Bound::Upper, 0));
+        table.clear();
+        assert_eq!(table.get(&zkey), None);
+    }
+}
@@ -38,10 +38,7 @@ impl Square {
 impl PrettyPrint for Square {
     fn pp(&self) -> String {
         let mut output = String::new();
-        let base_sym = match self.color {
-            Color::White => if INVERT_COLORS { '▓' } else { '░' },
-            Color::Black => if INVERT_COLORS { '░' } else { '▓' },
-        };
+        let base_sym = if (self.color == Color::White) != INVERT_COLORS { '░' } else { '▓' };
         match &self.piece_id {
             Some(piece_id) => {
                 output.push(base_sym);
@@ -1,6 +1,21 @@
+use crate::board_region::BoardRegion;
 use crate::board_square::BoardSquare;
+use crate::collections::MaybeSync;
+use crate::color::Color;
 use crate::point::Point;
 
-pub trait SquaresMap {
+pub trait SquaresMap: MaybeSync {
     fn square(&self, point: &Point) -> Option<BoardSquare>;
+
+    // Whether a pawn of the given color gets promoted on the given square. Only used when the
+    // promotion zone of the color is PromotionZone::SquaresMap.
+    fn is_promotion_square(&self, _point: &Point, _color: &Color) -> bool {
+        false
+    }
+
+    // Whether the given point belongs to the region of the given color. Only used for pieces,
+    // confined to the region by the board config.
+    fn is_in_region(&self, _point: &Point, _region: &BoardRegion, _color: &Color) -> bool {
+        true
+    }
 }
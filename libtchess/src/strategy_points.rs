@@ -1,7 +1,7 @@
 use crate::piece_id::PieceId;
 use crate::point::Point;
 use crate::strategy_point::StrategyPoint;
-use im_rc::{HashMap, HashSet};
+use crate::collections::{HashMap, HashSet};
 use rustc_hash::FxBuildHasher;
 
 #[derive(Debug, Clone)]
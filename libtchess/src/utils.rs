@@ -1 +1,2 @@
 pub mod pretty_print;
+pub mod perft;
@@ -0,0 +1,28 @@
+use crate::game_board::GameBoard;
+use crate::heat_map::HeatMap;
+use crate::squares_map::SquaresMap;
+
+// Counts leaf nodes of the moves tree of the given depth, starting from the given position. It is
+// used to verify moves generation against known numbers and to cross-check different boards.
+pub fn perft<HT, SQ, B>(board: &B, depth: u8) -> u64
+where
+    HT: HeatMap,
+    SQ: SquaresMap,
+    B: GameBoard<HT, SQ> + Clone,
+{
+    if depth == 0 {
+        return 1;
+    }
+    let moves = board.legal_moves(board.current_turn());
+    if depth == 1 {
+        return moves.len() as u64;
+    }
+    moves
+        .iter()
+        .map(|(piece_id, piece_move)| {
+            let mut next_board = board.clone();
+            next_board.move_piece(piece_id, piece_move);
+            perft(&next_board, depth - 1)
+        })
+        .sum()
+}
@@ -0,0 +1,16 @@
+// Rules, which change what the side must or must not do on top of the classic ones. The games
+// decide on the results, which are specific to the variant, by it as well.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Variant {
+    Classic,
+    // Each capture explodes the capturing piece and all non-pawn pieces around the destination
+    // square, like in atomic chess
+    Atomic,
+    // The game is played by the losing chess rules: captures are compulsory and the king is an
+    // ordinary piece, which can be captured
+    Antichess,
+    // The side wins by giving the given number of checks, like in three-check
+    CheckLimit(u8),
+    // Giving check is not allowed, like in Racing Kings
+    RacingKings,
+}
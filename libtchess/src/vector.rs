@@ -1,16 +1,19 @@
 use crate::vector::diagonal_vector::DiagonalVector;
 use crate::vector::jump_vector::JumpVector;
+use crate::vector::leap_vector::LeapVector;
 use crate::vector::line_vector::LineVector;
 use crate::point::Point;
 
 pub mod diagonal_vector;
 pub mod line_vector;
 pub mod jump_vector;
+pub mod leap_vector;
 
 #[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
 pub enum Vector {
     Diagonal(DiagonalVector),
     Jump(JumpVector),
+    Leap(LeapVector),
     Line(LineVector),
 }
 
@@ -22,20 +25,69 @@ impl Vector {
         if let Some(line) = LineVector::calc_direction(point1, point2) {
             return Some(Vector::Line(line));
         }
-        if let Some(jump) = JumpVector::calc_direction(point1, point2) {
-            return Some(Vector::Jump(jump));
+        // Any other direction is a repetition of the shortest leap along it, e.g. (2, 4) shift is
+        // two knight jumps.
+        let (&x1, &y1) = point1.to_tuple();
+        let (&x2, &y2) = point2.to_tuple();
+        let (dx, dy) = (x2 - x1, y2 - y1);
+        let gcd = Self::gcd(dx.abs(), dy.abs());
+        if gcd == 0 {
+            return None;
         }
-        None
+        Self::from_delta(dx / gcd, dy / gcd)
+    }
+
+    // Vector, making the given shift by one step
+    pub fn from_delta(dx: i16, dy: i16) -> Option<Self> {
+        let (from, to) = (Point::new(0, 0), Point::new(dx, dy));
+        match (dx.abs(), dy.abs()) {
+            (0, 0) => None,
+            (0, 1) | (1, 0) => LineVector::calc_direction(&from, &to).map(Self::Line),
+            (1, 1) => DiagonalVector::calc_direction(&from, &to).map(Self::Diagonal),
+            (1, 2) | (2, 1) => JumpVector::calc_direction(&from, &to).map(Self::Jump),
+            _ => Some(Self::Leap(LeapVector::new(dx, dy))),
+        }
+    }
+
+    // Whether the point2 can be reached from the point1 by one or more steps of the vector
+    pub fn reaches(&self, point1: &Point, point2: &Point) -> bool {
+        let (dx, dy) = self.delta();
+        let (&x1, &y1) = point1.to_tuple();
+        let (&x2, &y2) = point2.to_tuple();
+        let (shift_x, shift_y) = (x2 - x1, y2 - y1);
+        if dx == 0 {
+            return shift_x == 0 && shift_y % dy == 0 && shift_y / dy > 0;
+        }
+        shift_x % dx == 0 && shift_x / dx > 0 && shift_y == shift_x / dx * dy
+    }
+
+    fn gcd(a: i16, b: i16) -> i16 {
+        if b == 0 { a } else { Self::gcd(b, a % b) }
     }
 
     pub fn calc_next_point(&self, current_point: &Point) -> Point {
         match self {
             Self::Diagonal(d) => d.calc_next_point(current_point),
             Self::Jump(d) => d.calc_next_point(current_point),
+            Self::Leap(d) => d.calc_next_point(current_point),
             Self::Line(d) => d.calc_next_point(current_point),
         }
     }
 
+    // Shift of the coordinates, made by one step of the vector
+    pub fn delta(&self) -> (i16, i16) {
+        let next_point = self.calc_next_point(&Point::new(0, 0));
+        (*next_point.x().value(), *next_point.y().value())
+    }
+
+    // Number of steps along the vector from p1 to p2. The vector is expected to reach p2 from p1.
+    pub fn steps_between(&self, p1: &Point, p2: &Point) -> i16 {
+        let (dx, dy) = self.delta();
+        let distance_x = (p2.x().value() - p1.x().value()).abs();
+        let distance_y = (p2.y().value() - p1.y().value()).abs();
+        distance_x.max(distance_y) / dx.abs().max(dy.abs())
+    }
+
     pub fn diagonal_vectors() -> Vec<Self> {
         DiagonalVector::all_variants().into_iter().map(|d| Self::Diagonal(d)).collect::<Vec<_>>()
     }
@@ -50,6 +102,14 @@ impl Vector {
         JumpVector::all_variants().into_iter().map(|d| Self::Jump(d)).collect::<Vec<_>>()
     }
 
+    // All directions of the (m, n) leaper, e.g. (1, 3) for the camel
+    pub fn leap_vectors(m: i16, n: i16) -> Vec<Self> {
+        LeapVector::all_variants(m, n)
+            .into_iter()
+            .filter_map(|leap| Self::from_delta(leap.dx(), leap.dy()))
+            .collect::<Vec<_>>()
+    }
+
     pub fn line_vectors() -> Vec<Self> {
         LineVector::all_variants().into_iter().map(|d| Self::Line(d)).collect::<Vec<_>>()
     }
@@ -58,7 +118,67 @@ impl Vector {
         match self {
             Self::Diagonal(v) => Self::Diagonal(v.inverse()),
             Self::Jump(v) => Self::Jump(v.inverse()),
+            Self::Leap(v) => Self::Leap(v.inverse()),
             Self::Line(v) => Self::Line(v.inverse()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calc_direction_of_repeated_jump() {
+        assert_eq!(
+            Vector::calc_direction(&Point::new(1, 1), &Point::new(3, 5)),
+            Some(Vector::Jump(JumpVector::TopRightLeft))
+        );
+    }
+
+    #[test]
+    fn test_calc_direction_of_leap() {
+        assert_eq!(
+            Vector::calc_direction(&Point::new(1, 1), &Point::new(5, 7)),
+            Some(Vector::Leap(LeapVector::new(2, 3)))
+        );
+        assert_eq!(Vector::calc_direction(&Point::new(1, 1), &Point::new(1, 1)), None);
+    }
+
+    #[test]
+    fn test_from_delta() {
+        assert_eq!(Vector::from_delta(0, -1), Some(Vector::Line(LineVector::Bottom)));
+        assert_eq!(Vector::from_delta(1, 1), Some(Vector::Diagonal(DiagonalVector::TopRight)));
+        assert_eq!(Vector::from_delta(-2, 1), Some(Vector::Jump(JumpVector::TopLeftLeft)));
+        assert_eq!(Vector::from_delta(2, 2), Some(Vector::Leap(LeapVector::new(2, 2))));
+        assert_eq!(Vector::from_delta(0, 0), None);
+    }
+
+    #[test]
+    fn test_reaches() {
+        let camel = Vector::Leap(LeapVector::new(1, 3));
+        assert!(camel.reaches(&Point::new(1, 1), &Point::new(2, 4)));
+        assert!(camel.reaches(&Point::new(1, 1), &Point::new(3, 7)));
+        assert!(!camel.reaches(&Point::new(1, 1), &Point::new(1, 1)));
+        assert!(!camel.reaches(&Point::new(2, 4), &Point::new(1, 1)));
+        assert!(!camel.reaches(&Point::new(1, 1), &Point::new(2, 5)));
+        let top = Vector::Line(LineVector::Top);
+        assert!(top.reaches(&Point::new(1, 1), &Point::new(1, 8)));
+        assert!(!top.reaches(&Point::new(1, 1), &Point::new(2, 8)));
+    }
+
+    #[test]
+    fn test_steps_between() {
+        let camel = Vector::Leap(LeapVector::new(1, 3));
+        assert_eq!(camel.steps_between(&Point::new(1, 1), &Point::new(3, 7)), 2);
+        let diagonal = Vector::Diagonal(DiagonalVector::BottomLeft);
+        assert_eq!(diagonal.steps_between(&Point::new(5, 5), &Point::new(2, 2)), 3);
+    }
+
+    #[test]
+    fn test_leap_vectors() {
+        assert_eq!(Vector::leap_vectors(0, 1).len(), 4);
+        assert!(Vector::leap_vectors(1, 2).iter().all(|v| matches!(v, Vector::Jump(_))));
+        assert!(Vector::leap_vectors(2, 3).iter().all(|v| matches!(v, Vector::Leap(_))));
+    }
+}
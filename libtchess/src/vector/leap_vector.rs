@@ -0,0 +1,94 @@
+use crate::point::Point;
+
+// Leap by an arbitrary offset, e.g. (1, 3) of the camel or (2, 3) of the zebra. Offsets of a
+// single line or diagonal step, as well as knight offsets, have their own vectors. Use
+// Vector::from_delta to get the right one.
+#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+pub struct LeapVector {
+    dx: i16,
+    dy: i16,
+}
+
+impl LeapVector {
+    pub fn new(dx: i16, dy: i16) -> Self {
+        Self { dx, dy }
+    }
+
+    // All directions of the (m, n) leaper. There are eight of them, or four when m == n or one of
+    // the offsets is zero.
+    pub fn all_variants(m: i16, n: i16) -> Vec<Self> {
+        let mut variants = vec![];
+        for (dx, dy) in [(m, n), (n, m)] {
+            for (sx, sy) in [(-1, 1), (1, 1), (-1, -1), (1, -1)] {
+                let variant = Self::new(dx * sx, dy * sy);
+                if !variants.contains(&variant) {
+                    variants.push(variant);
+                }
+            }
+        }
+        variants
+    }
+
+    pub fn dx(&self) -> i16 {
+        self.dx
+    }
+
+    pub fn dy(&self) -> i16 {
+        self.dy
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self::new(-self.dx, -self.dy)
+    }
+
+    pub fn calc_next_point(&self, current_point: &Point) -> Point {
+        let (&x, &y) = current_point.to_tuple();
+        Point::new(x + self.dx, y + self.dy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_variants_of_oblique_leap() {
+        let variants = LeapVector::all_variants(1, 3);
+        assert_eq!(variants.len(), 8);
+        assert!(variants.contains(&LeapVector::new(-3, -1)));
+        assert!(variants.contains(&LeapVector::new(1, -3)));
+    }
+
+    #[test]
+    fn test_all_variants_of_orthogonal_leap() {
+        let variants = LeapVector::all_variants(0, 2);
+        assert_eq!(
+            variants,
+            vec![
+                LeapVector::new(0, 2),
+                LeapVector::new(0, -2),
+                LeapVector::new(-2, 0),
+                LeapVector::new(2, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_all_variants_of_diagonal_leap() {
+        assert_eq!(LeapVector::all_variants(2, 2).len(), 4);
+    }
+
+    #[test]
+    fn test_inverse() {
+        assert_eq!(LeapVector::new(2, -3).inverse(), LeapVector::new(-2, 3));
+    }
+
+    #[test]
+    fn test_calc_next_point() {
+        let direction = LeapVector::new(1, 4);
+        assert_eq!(
+            direction.calc_next_point(&Point::new(1, 1)),
+            Point::new(2, 5)
+        );
+    }
+}
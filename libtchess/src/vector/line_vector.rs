@@ -1,6 +1,6 @@
 use crate::point::Point;
 
-#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash, Ord, PartialOrd)]
 pub enum LineVector {
     Top,
     Bottom,
@@ -63,6 +63,15 @@ impl LineVector {
         }
         Point::new(x, y)
     }
+
+    pub fn delta(&self) -> (i16, i16) {
+        match self {
+            Self::Top => (0, 1),
+            Self::Bottom => (0, -1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+        }
+    }
 }
 
 #[cfg(test)]
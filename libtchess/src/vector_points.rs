@@ -7,20 +7,29 @@ pub struct VectorPoints {
     current_point: Point,
     dimension: Dimension,
     vector: Vector,
+    // Number of points left to iterate over, when the iteration is limited
+    points_left: Option<u16>,
 }
 
 impl VectorPoints {
     pub fn with_initial(starting_point: Point, dimension: Dimension, vector: Vector) -> Self {
-        Self { current_point: starting_point, dimension, vector }
+        Self { current_point: starting_point, dimension, vector, points_left: None }
     }
 
     pub fn without_initial(starting_point: Point, dimension: Dimension, vector: Vector) -> Self {
         let mut vector_points = Self {
-            current_point: starting_point, dimension, vector
+            current_point: starting_point, dimension, vector, points_left: None
         };
         vector_points.next();
         vector_points
     }
+
+    // Limits the number of points, which are left to iterate over. No limit is applied when None
+    // is given.
+    pub fn limit(mut self, max_points: Option<u16>) -> Self {
+        self.points_left = max_points;
+        self
+    }
 }
 
 impl Iterator for VectorPoints {
@@ -30,6 +39,12 @@ impl Iterator for VectorPoints {
         if !self.dimension.is_in_boundaries(&self.current_point) {
             return None
         }
+        if let Some(points_left) = self.points_left {
+            if points_left == 0 {
+                return None
+            }
+            self.points_left = Some(points_left - 1);
+        }
 
         let current_point = self.current_point;
         self.current_point = self.vector.calc_next_point(&self.current_point);
@@ -84,6 +99,19 @@ mod tests {
         assert_eq!(vector_points.next(), None);
     }
 
+    #[test]
+    fn test_limited_iteration() {
+        let point = Point::new(0, 0);
+        let dimension = Dimension::new(Point::new(0, 0), Point::new(5, 5));
+        let vector = Vector::Line(LineVector::Right);
+        let vector_points = VectorPoints::without_initial(
+            point,
+            dimension,
+            vector
+        ).limit(Some(2));
+        assert_eq!(vector_points.collect::<Vec<_>>(), vec![Point::new(1, 0), Point::new(2, 0)]);
+    }
+
     #[test]
     fn test_out_of_bounce_iteration_excluding_initial_point() {
         let point = Point::new(1, 2);
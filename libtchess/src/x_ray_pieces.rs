@@ -1,4 +1,4 @@
-use im_rc::HashMap;
+use crate::collections::HashMap;
 use rustc_hash::{FxBuildHasher};
 use crate::piece_id::PieceId;
 use crate::piece::{Piece};
@@ -63,7 +63,13 @@ impl XRayPieces {
                             },
                         }
                     },
-                    _ => panic!("Unsupported vector: {:?}", vector)
+                    _ => {
+                        // The piece, which is further along the vector, is closer to the king
+                        let (dx, dy) = vector.delta();
+                        let (new_x, new_y) = new_piece.current_position().to_tuple();
+                        let (current_x, current_y) = current_piece.current_position().to_tuple();
+                        (new_x - current_x) * dx + (new_y - current_y) * dy > 0
+                    },
                 };
             if should_replace {
                 self.add_or_replace_pin_vector(vector, new_piece);
@@ -94,6 +100,11 @@ impl XRayPieces {
         }
     }
 
+    // Forgets the piece pinned by the given piece. Returns the id of the piece which was pinned
+    pub fn remove_pin(&mut self, pinned_by_id: &PieceId) -> Option<PieceId> {
+        self.x_ray_data.get_mut(pinned_by_id).and_then(|data| data.pin.take())
+    }
+
     pub fn pinned_pieces(&self) -> Vec<&PieceId> {
         self.x_ray_data.values().filter_map(|data| data.pin.as_ref()).collect::<Vec<_>>()
     }
@@ -87,6 +87,22 @@ mod white_pawn {
         );
     }
 
+    #[test]
+    fn when_there_is_a_piece_right_in_front_of_the_pawn_with_additional_move_point() {
+        let mut board = board_default_4x4();
+        let pawn = add_piece(&mut board,
+            "Pawn",
+            Color::White,
+            vec![Buff::AdditionalPoint],
+            vec![],
+            Point::new(2, 2),
+        );
+        add_piece(&mut board,"Pawn", Color::Black, vec![], vec![], Point::new(2, 3));
+
+        println!("{}", board.pp());
+        compare_and_assert(&board.moves_of(pawn.id()).to_vec(), &vec![]);
+    }
+
     #[test]
     fn when_there_is_an_enemy_piece_on_attack_point() {
         let mut board = board_default_4x4();
@@ -400,6 +416,23 @@ mod black_pawn {
         );
     }
 
+    #[test]
+    fn when_there_is_a_piece_right_in_front_of_the_pawn_with_additional_move_point() {
+        let mut board = board_default_4x4();
+        board.set_pov(Color::Black);
+        let pawn = add_piece(&mut board,
+            "Pawn",
+            Color::Black,
+            vec![Buff::AdditionalPoint],
+            vec![],
+            Point::new(2, 3),
+        );
+        add_piece(&mut board,"Pawn", Color::White, vec![], vec![], Point::new(2, 2));
+
+        println!("{}", board.pp());
+        compare_and_assert(&board.moves_of(pawn.id()).to_vec(), &vec![]);
+    }
+
     #[test]
     fn when_there_is_an_enemy_piece_on_attack_point() {
         let mut board = board_default_4x4();
@@ -0,0 +1,110 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::promote_piece::PromotePiece;
+use libtchess::utils::pretty_print::PrettyPrint;
+use libtchess::variant::Variant;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+fn config_8x8() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_variant(Variant::Antichess);
+    config
+}
+
+mod when_the_capture_is_possible {
+    use super::*;
+
+    #[test]
+    fn only_captures_can_be_made() {
+        let mut board = Board::empty(config_8x8());
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 1));
+        let knight = add_piece(&mut board, "Knight", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "Pawn", Color::Black, vec![], vec![], Point::new(1, 6));
+
+        println!("{}", board.pp());
+        assert_eq!(
+            board.legal_moves(rook.color()),
+            vec![(*rook.id(), PieceMove::Point(Point::new(1, 6)))]
+        );
+        assert_eq!(board.moves_of(knight.id()).to_vec(), Vec::<&PieceMove>::new());
+        assert!(board.move_piece(knight.id(), &PieceMove::Point(Point::new(6, 3))).is_none());
+    }
+
+    #[test]
+    fn any_move_can_be_made_otherwise() {
+        let mut board = Board::empty(config_8x8());
+        let knight = add_piece(&mut board, "Knight", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "Pawn", Color::Black, vec![], vec![], Point::new(8, 7));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(knight.id()).to_vec(),
+            &vec![&PieceMove::Point(Point::new(2, 3)), &PieceMove::Point(Point::new(3, 2))],
+        );
+    }
+}
+
+mod when_the_king_is_on_the_board {
+    use super::*;
+
+    #[test]
+    fn it_is_not_checked() {
+        let mut board = Board::empty(config_8x8());
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(2, 8));
+        add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(8, 2));
+
+        println!("{}", board.pp());
+        assert!(board.royals(king.color()).is_empty());
+        assert!(!board.is_checked(king.color()));
+        // The king can step on the attacked square
+        assert!(
+            board
+                .moves_of(king.id())
+                .to_vec()
+                .contains(&&PieceMove::Point(Point::new(2, 1)))
+        );
+    }
+
+    #[test]
+    fn it_can_be_captured() {
+        let mut board = Board::empty(config_8x8());
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 1));
+        let king = add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 8));
+
+        move_piece_at(&mut board, Point::new(1, 1), PieceMove::Point(king.current_position));
+        println!("{}", board.pp());
+        assert_eq!(board.piece_at(king.current_position()).unwrap().name(), "Rook");
+        assert!(board.has_no_moves(&Color::Black));
+    }
+
+    #[test]
+    fn the_pawn_can_promote_to_it() {
+        let mut config = config_8x8();
+        config.set_promote_pieces(vec![PromotePiece::Queen, PromotePiece::King]);
+        let mut board = Board::empty(config);
+        add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(1, 7));
+        add_piece(&mut board, "Pawn", Color::Black, vec![], vec![], Point::new(8, 7));
+
+        move_piece_at(
+            &mut board,
+            Point::new(1, 7),
+            PieceMove::Promote(Point::new(1, 8), PromotePiece::King),
+        );
+        println!("{}", board.pp());
+        assert_eq!(board.piece_at(&Point::new(1, 8)).unwrap().name(), "King");
+        assert!(board.royals(&Color::White).is_empty());
+    }
+}
@@ -0,0 +1,128 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use libtchess::variant::Variant;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+fn config_8x8() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_variant(Variant::Atomic);
+    config
+}
+
+mod when_the_piece_is_captured {
+    use super::*;
+
+    #[test]
+    fn pieces_around_explode_together_with_the_capturing_piece() {
+        let mut board = Board::empty(config_8x8());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(4, 1));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(4, 6));
+        add_piece(&mut board, "Bishop", Color::Black, vec![], vec![], Point::new(5, 7));
+        add_piece(&mut board, "Knight", Color::White, vec![], vec![], Point::new(3, 5));
+        add_piece(&mut board, "Pawn", Color::Black, vec![], vec![], Point::new(3, 7));
+
+        move_piece(&mut board, *rook.id(), PieceMove::Point(Point::new(4, 6)));
+        println!("{}", board.pp());
+        assert!(board.find_piece_by_id(rook.id()).is_none());
+        assert!(board.piece_at(rook.current_position()).is_none());
+        for point in [Point::new(4, 6), Point::new(5, 7), Point::new(3, 5)] {
+            assert!(board.piece_at(&point).is_none());
+        }
+        // Pawns survive the explosion
+        assert_eq!(board.piece_at(&Point::new(3, 7)).unwrap().name(), "Pawn");
+        assert_eq!(board.active_pieces(rook.color()).len(), 1);
+    }
+
+    #[test]
+    fn the_capture_next_to_own_king_is_illegal() {
+        let mut board = Board::empty(config_8x8());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(4, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 2));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(3, 2));
+
+        println!("{}", board.pp());
+        assert!(
+            !board
+                .moves_of(rook.id())
+                .to_vec()
+                .contains(&&PieceMove::Point(Point::new(3, 2)))
+        );
+        assert!(
+            board
+                .moves_of(rook.id())
+                .to_vec()
+                .contains(&&PieceMove::Point(Point::new(2, 2)))
+        );
+    }
+}
+
+mod when_the_king_explodes {
+    use super::*;
+
+    #[test]
+    fn the_capture_is_legal_even_when_own_king_is_in_check() {
+        let mut board = Board::empty(config_8x8());
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(5, 8));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(5, 7));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(1, 8));
+
+        println!("{}", board.pp());
+        assert!(board.is_checked(king.color()));
+        move_piece_at(&mut board, Point::new(5, 1), PieceMove::Point(Point::new(5, 7)));
+        println!("{}", board.pp());
+        assert!(board.is_out_of_royals(&Color::Black));
+        assert!(!board.is_out_of_royals(&Color::White));
+        assert_eq!(board.piece_at(&king.current_position).unwrap().name(), "King");
+    }
+}
+
+mod when_the_king_moves {
+    use super::*;
+
+    #[test]
+    fn it_can_not_capture() {
+        let mut board = Board::empty(config_8x8());
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(2, 2));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(king.id()).to_vec(),
+            &vec![&PieceMove::Point(Point::new(1, 2)), &PieceMove::Point(Point::new(2, 1))],
+        );
+    }
+
+    #[test]
+    fn it_can_step_next_to_the_enemy_king_under_attack() {
+        let mut board = Board::empty(config_8x8());
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(4, 4));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(4, 6));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(1, 5));
+
+        println!("{}", board.pp());
+        move_piece(&mut board, *king.id(), PieceMove::Point(Point::new(4, 5)));
+        println!("{}", board.pp());
+        // Adjacent kings can't check each other
+        assert!(!board.is_checked(king.color()));
+        assert!(!board.is_checked(&Color::Black));
+    }
+}
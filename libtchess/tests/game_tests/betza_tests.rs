@@ -0,0 +1,163 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::betza::{BetzaError, BetzaPiece};
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::dimension::Dimension;
+use libtchess::piece_move::PieceMove;
+use libtchess::piece_registry::PieceRegistry;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+fn betza_piece(name: &str, notation: &str) -> BetzaPiece {
+    BetzaPiece::new(name, ColoredProperty::from(['X', 'x']), 300, notation).unwrap()
+}
+
+fn config_5x5(pieces: Vec<BetzaPiece>) -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    let mut piece_registry = PieceRegistry::classic();
+    for piece in pieces {
+        piece_registry.register(piece);
+    }
+    config.set_piece_registry(piece_registry);
+    config
+}
+
+mod when_the_piece_moves_forward_only {
+    use super::*;
+
+    #[test]
+    fn it_moves_towards_the_opposite_side() {
+        let mut board = Board::empty(config_5x5(vec![betza_piece("Soldier", "fmWfcF")]));
+        let soldier =
+            add_piece(&mut board, "Soldier", Color::Black, vec![], vec![], Point::new(3, 3));
+        let enemy_color = soldier.color().inverse();
+        add_piece(&mut board, "Knight", enemy_color, vec![], vec![], Point::new(2, 2));
+        add_piece(&mut board, "Knight", enemy_color, vec![], vec![], Point::new(4, 4));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(soldier.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(3, 2)),
+                &PieceMove::Point(Point::new(2, 2)),
+            ],
+        );
+    }
+}
+
+mod when_the_piece_moves_and_captures_differently {
+    use super::*;
+
+    #[test]
+    fn it_captures_along_capture_directions_only() {
+        let mut board = Board::empty(config_5x5(vec![betza_piece("Hybrid", "mRcB")]));
+        let hybrid =
+            add_piece(&mut board, "Hybrid", Color::White, vec![], vec![], Point::new(3, 3));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(3, 5));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(5, 5));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(hybrid.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(3, 4)),
+                &PieceMove::Point(Point::new(3, 2)),
+                &PieceMove::Point(Point::new(3, 1)),
+                &PieceMove::Point(Point::new(2, 3)),
+                &PieceMove::Point(Point::new(1, 3)),
+                &PieceMove::Point(Point::new(4, 3)),
+                &PieceMove::Point(Point::new(5, 3)),
+                &PieceMove::Point(Point::new(5, 5)),
+            ],
+        );
+    }
+}
+
+mod when_the_lame_leap_is_blocked {
+    use super::*;
+
+    #[test]
+    fn it_can_not_leap_over_the_piece() {
+        let mut board = Board::empty(config_5x5(vec![betza_piece("Horse", "nN")]));
+        let horse = add_piece(&mut board, "Horse", Color::White, vec![], vec![], Point::new(3, 3));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(3, 4));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(horse.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(1, 4)),
+                &PieceMove::Point(Point::new(5, 4)),
+                &PieceMove::Point(Point::new(1, 2)),
+                &PieceMove::Point(Point::new(5, 2)),
+                &PieceMove::Point(Point::new(2, 1)),
+                &PieceMove::Point(Point::new(4, 1)),
+            ],
+        );
+    }
+
+    #[test]
+    fn it_leaps_again_when_the_piece_moves_away() {
+        let mut board = Board::empty(config_5x5(vec![betza_piece("Horse", "nN")]));
+        let horse = add_piece(&mut board, "Horse", Color::White, vec![], vec![], Point::new(3, 3));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(3, 4));
+
+        move_piece(&mut board, *rook.id(), PieceMove::Point(Point::new(3, 5)));
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(horse.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(2, 5)),
+                &PieceMove::Point(Point::new(4, 5)),
+                &PieceMove::Point(Point::new(1, 4)),
+                &PieceMove::Point(Point::new(5, 4)),
+                &PieceMove::Point(Point::new(1, 2)),
+                &PieceMove::Point(Point::new(5, 2)),
+                &PieceMove::Point(Point::new(2, 1)),
+                &PieceMove::Point(Point::new(4, 1)),
+            ],
+        );
+    }
+}
+
+mod when_the_leaper_is_doubled {
+    use super::*;
+
+    #[test]
+    fn it_rides_until_the_first_obstacle() {
+        let mut board = Board::empty(config_5x5(vec![betza_piece("Nightrider", "NN")]));
+        let nightrider =
+            add_piece(&mut board, "Nightrider", Color::White, vec![], vec![], Point::new(1, 1));
+        let knight =
+            add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(2, 3));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(nightrider.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(*knight.current_position()),
+                &PieceMove::Point(Point::new(3, 2)),
+                &PieceMove::Point(Point::new(5, 3)),
+            ],
+        );
+    }
+}
+
+mod when_the_notation_is_invalid {
+    use super::*;
+
+    #[test]
+    fn it_returns_an_error() {
+        let piece = BetzaPiece::new("Unknown", ColoredProperty::from(['X', 'x']), 250, "X");
+        assert_eq!(piece.unwrap_err(), BetzaError::UnknownAtom('X'));
+    }
+}
@@ -0,0 +1,69 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::board_region::BoardRegion;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+// The white rook can't leave the palace of 2..=4 files and 1..=2 ranks
+fn config_5x5() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+    let mut squares_map = TestSquaresMap::from_dimension(&dimension);
+    for x in 2..=4 {
+        for y in 1..=2 {
+            squares_map.add_region_square(Point::new(x, y), BoardRegion::Palace, Color::White);
+        }
+    }
+    let mut config = board_config(dimension, squares_map);
+    config.set_piece_region("Rook", BoardRegion::Palace);
+    config
+}
+
+mod when_the_piece_is_confined_to_the_region {
+    use super::*;
+
+    #[test]
+    fn it_does_not_leave_the_region() {
+        let mut board = Board::empty(config_5x5());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(5, 5));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(2, 1));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(rook.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(2, 2)),
+                &PieceMove::Point(Point::new(3, 1)),
+                &PieceMove::Point(Point::new(4, 1)),
+            ],
+        );
+    }
+
+    #[test]
+    fn it_does_not_attack_outside_of_the_region() {
+        let mut board = Board::empty(config_5x5());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        let king = add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 4));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(2, 2));
+
+        println!("{}", board.pp());
+        let king_moves = board.moves_of(king.id());
+        let king_moves = king_moves.to_vec();
+        // The rook would leave the region to reach the third rank and the first file
+        assert!(king_moves.contains(&&PieceMove::Point(Point::new(2, 3))));
+        assert!(king_moves.contains(&&PieceMove::Point(Point::new(1, 3))));
+        assert!(board.piece_at(rook.current_position()).is_some());
+        assert_eq!(rook.color(), &Color::White);
+        assert_eq!(rook.current_position, Point::new(2, 2));
+    }
+}
@@ -1,7 +1,7 @@
 #[path = "../support/mod.rs"]
 mod support;
 
-use im_rc::HashSet;
+use libtchess::collections::HashSet;
 use libtchess::board::Board;
 use libtchess::color::Color;
 use libtchess::piece_id::PieceId;
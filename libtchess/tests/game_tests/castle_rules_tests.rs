@@ -0,0 +1,158 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::buff::Buff;
+use libtchess::castle_points::{CastlePoints, CastleSide};
+use libtchess::castle_rule::{CastleDestination, CastleRule};
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::piece_id::PieceId;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::*;
+
+// The king can castle either two or three points to the king side
+fn config_8x2(non_corner_rook: bool) -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 2));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_castle_rules(vec![
+        CastleRule::new(
+            CastleSide::King,
+            CastleDestination::Distance { king: 2, rook: 1 },
+            non_corner_rook,
+        ),
+        CastleRule::new(
+            CastleSide::King,
+            CastleDestination::Distance { king: 3, rook: 2 },
+            non_corner_rook,
+        ),
+        CastleRule::new(
+            CastleSide::Queen,
+            CastleDestination::Distance { king: 2, rook: 1 },
+            non_corner_rook,
+        ),
+    ]);
+    config
+}
+
+// Returns the board along with the king side rook
+fn setup_board(
+    config: BoardConfig<TestHeatMap, TestSquaresMap>,
+    king_side_rook_x: i16,
+) -> (Board<TestHeatMap, TestSquaresMap>, PieceRepr) {
+    let mut board = Board::empty(config);
+    add_piece(&mut board, "King", Color::White, vec![Buff::Castle], vec![], Point::new(4, 1));
+    add_piece(&mut board, "Rook", Color::White, vec![Buff::Castle], vec![], Point::new(1, 1));
+    let rook = add_piece(
+        &mut board,
+        "Rook",
+        Color::White,
+        vec![Buff::Castle],
+        vec![],
+        Point::new(king_side_rook_x, 1),
+    );
+    (board, rook)
+}
+
+fn castle_moves(board: &Board<TestHeatMap, TestSquaresMap>, king_id: &PieceId) -> Vec<PieceMove> {
+    let mut moves = board
+        .moves_of(king_id)
+        .unwrap()
+        .keys()
+        .filter(|piece_move| matches!(piece_move, PieceMove::Castle(_)))
+        .copied()
+        .collect::<Vec<_>>();
+    moves.sort();
+    moves
+}
+
+fn castle(king_x: i16, rook_x: i16, initial_rook_point: Point) -> PieceMove {
+    PieceMove::Castle(CastlePoints::new(
+        Point::new(king_x, 1),
+        Point::new(rook_x, 1),
+        Point::new(4, 1),
+        initial_rook_point,
+    ))
+}
+
+fn queen_side_castle() -> PieceMove {
+    castle(2, 3, Point::new(1, 1))
+}
+
+mod when_there_are_several_rules_for_the_same_side {
+    use super::*;
+
+    #[test]
+    fn it_allows_to_castle_by_each_of_them() {
+        let (board, rook) = setup_board(config_8x2(false), 8);
+        let king_id = *board.piece_id_at(&Point::new(4, 1)).unwrap();
+
+        println!("{}", board.pp());
+        let mut expected = vec![
+            queen_side_castle(),
+            castle(6, 5, rook.current_position),
+            castle(7, 6, rook.current_position),
+        ];
+        expected.sort();
+        assert_eq!(castle_moves(&board, &king_id), expected);
+    }
+
+    #[test]
+    fn it_checks_the_path_of_each_rule_separately() {
+        let (mut board, rook) = setup_board(config_8x2(false), 8);
+        // The enemy rook attacks the destination of the three points castle
+        add_piece(&mut board, "Rook", rook.color().inverse(), vec![], vec![], Point::new(7, 2));
+        let king_id = *board.piece_id_at(&Point::new(4, 1)).unwrap();
+
+        println!("{}", board.pp());
+        let mut expected = vec![queen_side_castle(), castle(6, 5, rook.current_position)];
+        expected.sort();
+        assert_eq!(castle_moves(&board, &king_id), expected);
+    }
+
+    #[test]
+    fn it_moves_the_king_and_the_rook_to_the_points_of_the_rule() {
+        let (mut board, rook) = setup_board(config_8x2(false), 8);
+        let king_id = *board.piece_id_at(&Point::new(4, 1)).unwrap();
+
+        move_piece(&mut board, king_id, castle(7, 6, *rook.current_position()));
+
+        println!("{}", board.pp());
+        assert_eq!(board.piece_id_at(&Point::new(7, 1)), Some(&king_id));
+        assert_eq!(board.piece_id_at(&Point::new(6, 1)), Some(rook.id()));
+        assert!(board.piece_at(rook.current_position()).is_none());
+    }
+}
+
+mod when_the_rook_is_not_in_the_corner {
+    use super::*;
+
+    #[test]
+    fn it_does_not_castle_with_it_by_default() {
+        let (board, _) = setup_board(config_8x2(false), 7);
+        let king_id = *board.piece_id_at(&Point::new(4, 1)).unwrap();
+
+        println!("{}", board.pp());
+        assert_eq!(castle_moves(&board, &king_id), vec![queen_side_castle()]);
+    }
+
+    #[test]
+    fn it_castles_with_it_when_the_rule_allows() {
+        let (board, rook) = setup_board(config_8x2(true), 7);
+        let king_id = *board.piece_id_at(&Point::new(4, 1)).unwrap();
+
+        println!("{}", board.pp());
+        let mut expected = vec![
+            queen_side_castle(),
+            castle(6, 5, rook.current_position),
+            castle(7, 6, rook.current_position),
+        ];
+        expected.sort();
+        assert_eq!(castle_moves(&board, &king_id), expected);
+    }
+}
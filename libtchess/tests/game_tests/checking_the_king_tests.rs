@@ -752,3 +752,28 @@ mod multiple_pieces_check {
             .to(|_board| vec![]);
     }
 }
+
+mod move_which_does_not_evade_the_check {
+    use super::*;
+
+    #[test]
+    fn it_is_rejected() {
+        let mut board = classic_8x8_prefilled();
+        // 1. e4 f5 2. Qh5+
+        move_piece_at(&mut board, Point::new(5, 2), PieceMove::LongMove(Point::new(5, 4)));
+        move_piece_at(&mut board, Point::new(6, 7), PieceMove::LongMove(Point::new(6, 5)));
+        move_piece_at(&mut board, Point::new(4, 1), PieceMove::Point(Point::new(8, 5)));
+        println!("{}", board.pp());
+
+        let pawn_id = *board.piece_id_at(&Point::new(1, 7)).unwrap();
+        assert!(
+            board
+                .move_piece(&pawn_id, &PieceMove::Point(Point::new(1, 6)))
+                .is_none()
+        );
+        // 2... g6 blocks the check
+        move_piece_at(&mut board, Point::new(7, 7), PieceMove::Point(Point::new(7, 6)));
+        println!("{}", board.pp());
+        assert_eq!(board.current_turn(), &Color::White);
+    }
+}
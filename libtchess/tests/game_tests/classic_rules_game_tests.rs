@@ -0,0 +1,121 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board_config::BoardConfig;
+use libtchess::classic_rules_game::ClassicRulesGame;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_result::GameResult;
+use libtchess::move_result::MoveResult;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::*;
+
+fn config_8x8() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    board_config(dimension, TestSquaresMap::from_dimension(&dimension))
+}
+
+fn kings_and_rook() -> ClassicRulesGame<TestHeatMap, TestSquaresMap> {
+    ClassicRulesGame::with_setup(config_8x8(), |board| {
+        board.add_piece("King", Color::White, vec![], vec![], Point::new(1, 1));
+        board.add_piece("Rook", Color::White, vec![], vec![], Point::new(2, 2));
+        board.add_piece("King", Color::Black, vec![], vec![], Point::new(8, 8));
+    })
+}
+
+mod when_the_side_has_no_moves {
+    use super::*;
+
+    #[test]
+    fn it_is_checkmate_when_the_king_is_checked() {
+        let mut game = ClassicRulesGame::with_setup(config_8x8(), |board| {
+            board.add_piece("King", Color::White, vec![], vec![], Point::new(6, 6));
+            board.add_piece("Rook", Color::White, vec![], vec![], Point::new(1, 1));
+            board.add_piece("King", Color::Black, vec![], vec![], Point::new(8, 8));
+            board.add_piece("Pawn", Color::White, vec![], vec![], Point::new(8, 6));
+            board.add_piece("Pawn", Color::Black, vec![], vec![], Point::new(8, 7));
+        });
+        assert_eq!(
+            game.move_piece_at(&Point::new(1, 1), &PieceMove::Point(Point::new(1, 8))),
+            MoveResult::GameEnded(GameResult::Checkmate(Color::Black))
+        );
+        assert_eq!(
+            game.game_result(),
+            Some(&GameResult::Checkmate(Color::Black))
+        );
+    }
+
+    #[test]
+    fn it_is_stalemate_when_the_king_is_not_checked() {
+        let mut game = ClassicRulesGame::with_setup(config_8x8(), |board| {
+            board.add_piece("King", Color::White, vec![], vec![], Point::new(6, 7));
+            board.add_piece("Queen", Color::White, vec![], vec![], Point::new(7, 1));
+            board.add_piece("King", Color::Black, vec![], vec![], Point::new(8, 8));
+        });
+        assert_eq!(
+            game.move_piece_at(&Point::new(7, 1), &PieceMove::Point(Point::new(7, 6))),
+            MoveResult::GameEnded(GameResult::Stalemate(Color::Black))
+        );
+    }
+}
+
+mod when_the_game_goes_on_without_progress {
+    use super::*;
+
+    #[test]
+    fn it_is_drawn_by_the_fifty_move_rule() {
+        let mut game = ClassicRulesGame::with_setup(config_8x8(), |board| {
+            board.add_piece("King", Color::White, vec![], vec![], Point::new(1, 1));
+            board.add_piece("Rook", Color::White, vec![], vec![], Point::new(2, 2));
+            board.add_piece("King", Color::Black, vec![], vec![], Point::new(8, 8));
+            board.set_turn_number(100, 99);
+        });
+        assert_eq!(
+            game.move_piece_at(&Point::new(2, 2), &PieceMove::Point(Point::new(2, 3))),
+            MoveResult::GameEnded(GameResult::FiftyMoveRuleDraw)
+        );
+    }
+
+    #[test]
+    fn it_is_drawn_by_threefold_repetition() {
+        let mut game = kings_and_rook();
+        let moves = [
+            (Point::new(2, 2), Point::new(2, 3)),
+            (Point::new(8, 8), Point::new(7, 8)),
+            (Point::new(2, 3), Point::new(2, 2)),
+            (Point::new(7, 8), Point::new(8, 8)),
+        ];
+        for (point, destination) in moves.iter().chain(moves.iter()) {
+            assert_eq!(
+                game.move_piece_at(point, &PieceMove::Point(*destination)),
+                MoveResult::PieceMoved
+            );
+        }
+        assert_eq!(
+            game.move_piece_at(&Point::new(2, 2), &PieceMove::Point(Point::new(2, 3))),
+            MoveResult::GameEnded(GameResult::DrawByRepetition)
+        );
+        // No moves are made once the game is over
+        assert_eq!(
+            game.move_piece_at(&Point::new(8, 8), &PieceMove::Point(Point::new(7, 8))),
+            MoveResult::GameEnded(GameResult::DrawByRepetition)
+        );
+    }
+}
+
+mod when_the_move_is_illegal {
+    use super::*;
+
+    #[test]
+    fn it_keeps_the_game_going() {
+        let mut game = kings_and_rook();
+        assert_eq!(
+            game.move_piece_at(&Point::new(2, 2), &PieceMove::Point(Point::new(3, 3))),
+            MoveResult::IllegalMove
+        );
+        assert_eq!(game.game_result(), None);
+    }
+}
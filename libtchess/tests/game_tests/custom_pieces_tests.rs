@@ -0,0 +1,224 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::betza::BetzaPiece;
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::dimension::Dimension;
+use libtchess::movement::Movement;
+use libtchess::piece_definition::PieceDefinition;
+use libtchess::piece_move::PieceMove;
+use libtchess::piece_registry::PieceRegistry;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use libtchess::vector::Vector;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+// Moves like a bishop and a knight
+#[derive(Debug)]
+struct Archbishop;
+
+impl PieceDefinition for Archbishop {
+    fn name(&self) -> &str {
+        "Archbishop"
+    }
+
+    fn symbol(&self, color: &Color) -> char {
+        match color.appearance() {
+            Color::White => 'A',
+            _ => 'a',
+        }
+    }
+
+    fn value(&self) -> i16 {
+        875
+    }
+
+    fn movements(&self, _color: &Color) -> Vec<Movement> {
+        let mut movements =
+            Vector::diagonal_vectors().into_iter().map(Movement::ride).collect::<Vec<_>>();
+        movements.extend(Vector::jump_vectors().into_iter().map(Movement::leap));
+        movements
+    }
+}
+
+fn config_5x5() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    let mut piece_registry = PieceRegistry::classic();
+    piece_registry.register(Archbishop);
+    config.set_piece_registry(piece_registry);
+    config
+}
+
+mod when_there_are_no_pieces_around {
+    use super::*;
+
+    #[test]
+    fn it_moves_along_all_its_vectors() {
+        let mut board = Board::empty(config_5x5());
+        let archbishop = add_piece(
+            &mut board,
+            "Archbishop",
+            Color::White,
+            vec![],
+            vec![],
+            Point::new(3, 3),
+        );
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(archbishop.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(1, 1)),
+                &PieceMove::Point(Point::new(2, 2)),
+                &PieceMove::Point(Point::new(4, 4)),
+                &PieceMove::Point(Point::new(5, 5)),
+                &PieceMove::Point(Point::new(1, 5)),
+                &PieceMove::Point(Point::new(2, 4)),
+                &PieceMove::Point(Point::new(4, 2)),
+                &PieceMove::Point(Point::new(5, 1)),
+                &PieceMove::Point(Point::new(2, 1)),
+                &PieceMove::Point(Point::new(1, 2)),
+                &PieceMove::Point(Point::new(1, 4)),
+                &PieceMove::Point(Point::new(2, 5)),
+                &PieceMove::Point(Point::new(4, 5)),
+                &PieceMove::Point(Point::new(5, 4)),
+                &PieceMove::Point(Point::new(5, 2)),
+                &PieceMove::Point(Point::new(4, 1)),
+            ],
+        );
+    }
+}
+
+mod when_the_piece_is_on_the_way_to_the_king {
+    use super::*;
+
+    #[test]
+    fn it_pins_the_piece() {
+        let mut board = Board::empty(config_5x5());
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(5, 5));
+        let knight = add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(3, 3));
+        add_piece(&mut board, "Archbishop", Color::White, vec![], vec![], Point::new(1, 1));
+
+        println!("{}", board.pp());
+        assert!(board.debuffs(knight.color()).has_pin(knight.id()));
+        compare_and_assert(&board.moves_of(knight.id()).to_vec(), &vec![]);
+    }
+}
+
+mod when_the_piece_checks_the_king {
+    use super::*;
+
+    #[test]
+    fn it_allows_to_block_a_sliding_check() {
+        let mut board = Board::empty(config_5x5());
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(5, 2));
+        add_piece(&mut board, "Archbishop", Color::Black, vec![], vec![], Point::new(3, 3));
+
+        println!("{}", board.pp());
+        assert!(board.debuffs(&Color::White).has_check(king.id()));
+        compare_and_assert(
+            &board.moves_of(rook.id()).to_vec(),
+            &vec![&PieceMove::Point(Point::new(2, 2))],
+        );
+    }
+
+    #[test]
+    fn it_does_not_allow_to_block_a_jump_check() {
+        let mut board = Board::empty(config_5x5());
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(5, 3));
+        add_piece(&mut board, "Archbishop", Color::Black, vec![], vec![], Point::new(2, 3));
+
+        println!("{}", board.pp());
+        assert!(board.debuffs(&Color::White).has_check(king.id()));
+        compare_and_assert(
+            &board.moves_of(rook.id()).to_vec(),
+            &vec![&PieceMove::Point(Point::new(2, 3))],
+        );
+    }
+}
+
+mod when_the_piece_is_captured {
+    use super::*;
+
+    #[test]
+    fn it_is_removed_from_the_stats() {
+        let mut board = Board::empty(config_5x5());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(5, 5));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(3, 1));
+        let archbishop =
+            add_piece(&mut board, "Archbishop", Color::Black, vec![], vec![], Point::new(3, 4));
+        let stats = board.stats();
+        assert_eq!(
+            stats.active_pieces_stats[&Color::Black].custom_pieces_count.get("Archbishop"),
+            Some(&1)
+        );
+        let zposition_was = *stats.zposition;
+
+        move_piece(&mut board, *rook.id(), PieceMove::Point(*archbishop.current_position()));
+        println!("{}", board.pp());
+        let stats = board.stats();
+        assert_eq!(
+            stats.active_pieces_stats[&Color::Black].custom_pieces_count.get("Archbishop"),
+            Some(&0)
+        );
+        assert_ne!(*stats.zposition, zposition_was);
+    }
+}
+
+mod when_the_piece_is_not_registered {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "Unknown piece: Archbishop")]
+    fn it_can_not_be_added() {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+        let mut board =
+            Board::empty(board_config(dimension, TestSquaresMap::from_dimension(&dimension)));
+        board.add_piece("Archbishop", Color::White, vec![], vec![], Point::new(3, 3));
+    }
+}
+
+mod when_the_piece_is_registered_again {
+    use super::*;
+
+    #[test]
+    fn it_keeps_its_kind() {
+        let mut config = config_5x5();
+        let mut piece_registry = PieceRegistry::classic();
+        for _ in 0..300 {
+            piece_registry.register(Archbishop);
+        }
+        config.set_piece_registry(piece_registry);
+        let mut board = Board::empty(config);
+        add_piece(&mut board, "Archbishop", Color::White, vec![], vec![], Point::new(3, 3));
+        let mut board_was = Board::empty(config_5x5());
+        add_piece(&mut board_was, "Archbishop", Color::White, vec![], vec![], Point::new(3, 3));
+
+        assert_eq!(*board.stats().zposition, *board_was.stats().zposition);
+    }
+}
+
+mod when_there_are_too_many_pieces {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "Can't register more than 256 piece kinds!")]
+    fn they_can_not_be_registered() {
+        let mut piece_registry = PieceRegistry::classic();
+        for number in 0..=250 {
+            let name = format!("Piece{number}");
+            piece_registry
+                .register(BetzaPiece::new(&name, ColoredProperty::from(['P', 'p']), 100, "W").unwrap());
+        }
+    }
+}
@@ -0,0 +1,172 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::betza::BetzaPiece;
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::dimension::Dimension;
+use libtchess::piece_move::PieceMove;
+use libtchess::piece_registry::PieceRegistry;
+use libtchess::point::Point;
+use libtchess::promote_piece::PromotePiece;
+use libtchess::promotion_zone::PromotionZone;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+// The soldier steps forward and promotes to the general, the scout steps diagonally or forward
+// and promotes to the general as well
+fn config_5x5(drops: bool) -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    let mut piece_registry = PieceRegistry::classic();
+    piece_registry.register(BetzaPiece::new("Soldier", ColoredProperty::from(['S', 's']), 100, "fW").unwrap());
+    piece_registry.register(BetzaPiece::new("Scout", ColoredProperty::from(['C', 'c']), 300, "FfW").unwrap());
+    piece_registry.register(BetzaPiece::new("General", ColoredProperty::from(['G', 'g']), 500, "WF").unwrap());
+    config.set_piece_registry(piece_registry);
+    config.set_promotion_zone(&Color::White, PromotionZone::Ranks(4..=5));
+    config.set_promotion_zone(&Color::Black, PromotionZone::Ranks(1..=2));
+    config.set_promotion("Soldier", "General");
+    config.set_promotion("Scout", "General");
+    config.set_drops(drops);
+    config
+}
+
+mod when_the_piece_enters_the_zone {
+    use super::*;
+
+    #[test]
+    fn it_can_promote_or_stay_unpromoted() {
+        let mut board = Board::empty(config_5x5(false));
+        let soldier = add_piece(&mut board, "Soldier", Color::White, vec![], vec![], Point::new(2, 3));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(soldier.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(2, 4)),
+                &PieceMove::Promote(Point::new(2, 4), PromotePiece::Promoted),
+            ],
+        );
+    }
+
+    #[test]
+    fn it_turns_into_the_promoted_piece() {
+        let mut board = Board::empty(config_5x5(false));
+        let soldier = add_piece(&mut board, "Soldier", Color::White, vec![], vec![], Point::new(4, 3));
+
+        move_piece(
+            &mut board,
+            *soldier.id(),
+            PieceMove::Promote(Point::new(4, 4), PromotePiece::Promoted),
+        );
+        println!("{}", board.pp());
+        let general = board.piece_at(&Point::new(4, 4)).unwrap();
+        assert_eq!(general.name(), "General");
+        assert_eq!(general.color(), soldier.color());
+        assert!(board.piece_at(soldier.current_position()).is_none());
+    }
+}
+
+mod when_the_piece_moves_inside_or_out_of_the_zone {
+    use super::*;
+
+    #[test]
+    fn it_can_promote_when_leaving_the_zone() {
+        let mut board = Board::empty(config_5x5(false));
+        let scout = add_piece(&mut board, "Scout", Color::White, vec![], vec![], Point::new(3, 4));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(scout.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(3, 5)),
+                &PieceMove::Promote(Point::new(3, 5), PromotePiece::Promoted),
+                &PieceMove::Point(Point::new(2, 5)),
+                &PieceMove::Promote(Point::new(2, 5), PromotePiece::Promoted),
+                &PieceMove::Point(Point::new(4, 5)),
+                &PieceMove::Promote(Point::new(4, 5), PromotePiece::Promoted),
+                &PieceMove::Point(Point::new(2, 3)),
+                &PieceMove::Promote(Point::new(2, 3), PromotePiece::Promoted),
+                &PieceMove::Point(Point::new(4, 3)),
+                &PieceMove::Promote(Point::new(4, 3), PromotePiece::Promoted),
+            ],
+        );
+    }
+
+    #[test]
+    fn it_does_not_promote_outside_of_the_zone() {
+        let mut board = Board::empty(config_5x5(false));
+        let scout = add_piece(&mut board, "Scout", Color::White, vec![], vec![], Point::new(3, 2));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(scout.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(3, 3)),
+                &PieceMove::Point(Point::new(2, 3)),
+                &PieceMove::Point(Point::new(4, 3)),
+                &PieceMove::Point(Point::new(2, 1)),
+                &PieceMove::Point(Point::new(4, 1)),
+            ],
+        );
+    }
+}
+
+mod when_the_piece_can_not_move_further {
+    use super::*;
+
+    #[test]
+    fn it_must_promote() {
+        let mut board = Board::empty(config_5x5(false));
+        let soldier = add_piece(&mut board, "Soldier", Color::White, vec![], vec![], Point::new(2, 4));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(soldier.id()).to_vec(),
+            &vec![&PieceMove::Promote(Point::new(2, 5), PromotePiece::Promoted)],
+        );
+    }
+
+    #[test]
+    fn it_must_promote_when_the_promotion_is_compulsory() {
+        let mut config = config_5x5(false);
+        config.set_compulsory_promotions(&["Soldier"]);
+        let mut board = Board::empty(config);
+        let soldier = add_piece(&mut board, "Soldier", Color::White, vec![], vec![], Point::new(2, 3));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(soldier.id()).to_vec(),
+            &vec![&PieceMove::Promote(Point::new(2, 4), PromotePiece::Promoted)],
+        );
+    }
+}
+
+mod when_the_promoted_piece_is_captured {
+    use super::*;
+
+    #[test]
+    fn it_goes_into_the_pocket_unpromoted() {
+        let mut board = Board::empty(config_5x5(true));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(5, 5));
+        let soldier = add_piece(&mut board, "Soldier", Color::White, vec![], vec![], Point::new(1, 3));
+        let rook = add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(3, 4));
+
+        move_piece(
+            &mut board,
+            *soldier.id(),
+            PieceMove::Promote(Point::new(1, 4), PromotePiece::Promoted),
+        );
+        move_piece(&mut board, *rook.id(), PieceMove::Point(Point::new(1, 4)));
+        println!("{}", board.pp());
+        assert_eq!(board.pocket(&Color::Black).count("Soldier"), 1);
+        assert_eq!(board.pocket(&Color::Black).count("General"), 0);
+        assert!(board.piece_at(&rook.current_position).is_none());
+    }
+}
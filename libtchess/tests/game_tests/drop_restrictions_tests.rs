@@ -0,0 +1,146 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::betza::BetzaPiece;
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_id::PieceId;
+use libtchess::piece_move::PieceMove;
+use libtchess::piece_registry::PieceRegistry;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::*;
+
+// The soldier steps forward, it can not be dropped onto a file with another own soldier or to
+// give a mate
+fn config_5x5() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    let mut piece_registry = PieceRegistry::classic();
+    piece_registry.register(BetzaPiece::new("Soldier", ColoredProperty::from(['S', 's']), 100, "fW").unwrap());
+    config.set_piece_registry(piece_registry);
+    config.set_drops(true);
+    config.set_drop_restricted_pieces(&["Soldier"]);
+    config
+}
+
+fn drop_points(board: &Board<TestHeatMap, TestSquaresMap>, piece_id: &PieceId) -> Vec<Point> {
+    board
+        .moves_of(piece_id)
+        .map(|moves| moves.keys().map(|piece_move| *piece_move.destination()).collect())
+        .unwrap_or_default()
+}
+
+mod when_the_soldier_is_dropped {
+    use super::*;
+
+    #[test]
+    fn it_can_not_be_dropped_onto_the_file_with_own_soldier() {
+        let mut board = Board::empty(config_5x5());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(5, 5));
+        let soldier = add_piece(&mut board, "Soldier", Color::White, vec![], vec![], Point::new(2, 2));
+        add_piece(&mut board, "Soldier", Color::Black, vec![], vec![], Point::new(3, 4));
+        let soldier_id = board.add_to_pocket(soldier.color(), "Soldier");
+
+        println!("{}", board.pp());
+        let points = drop_points(&board, &soldier_id);
+        assert!(!points.is_empty());
+        assert!(points.iter().all(|point| point.x() != soldier.current_position().x()));
+        // The enemy soldier does not restrict the drop
+        assert!(points.contains(&Point::new(3, 3)));
+        assert!(board.moves_of(soldier.id()).is_some());
+    }
+
+    #[test]
+    fn it_can_not_be_dropped_where_it_has_no_moves() {
+        let mut board = Board::empty(config_5x5());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(5, 5));
+        let soldier_id = board.add_to_pocket(&Color::White, "Soldier");
+
+        println!("{}", board.pp());
+        let points = drop_points(&board, &soldier_id);
+        assert!(!points.is_empty());
+        assert!(points.iter().all(|point| *point.y().value() != 5));
+    }
+}
+
+mod when_the_soldier_drop_checks_the_king {
+    use super::*;
+
+    // The black king is boxed in by own knights, the only escape is to capture the soldier
+    fn setup_board(white_king: Point) -> Board<TestHeatMap, TestSquaresMap> {
+        let mut board = Board::empty(config_5x5());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], white_king);
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 5));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(2, 5));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(2, 4));
+        board
+    }
+
+    #[test]
+    fn it_can_not_give_a_mate() {
+        let mut board = setup_board(Point::new(2, 3));
+        let soldier_id = board.add_to_pocket(&Color::White, "Soldier");
+
+        println!("{}", board.pp());
+        let points = drop_points(&board, &soldier_id);
+        assert!(!points.is_empty());
+        assert!(!points.contains(&Point::new(1, 4)));
+        assert!(
+            !board
+                .legal_moves(&Color::White)
+                .contains(&(soldier_id, PieceMove::Drop(Point::new(1, 4))))
+        );
+    }
+
+    #[test]
+    fn it_can_give_a_check() {
+        let mut board = setup_board(Point::new(5, 1));
+        let soldier_id = board.add_to_pocket(&Color::White, "Soldier");
+
+        move_piece(&mut board, soldier_id, PieceMove::Drop(Point::new(1, 4)));
+        println!("{}", board.pp());
+        assert!(board.is_checked(&Color::Black));
+        assert!(!board.has_no_moves(&Color::Black));
+    }
+
+    #[test]
+    fn other_pieces_can_give_a_mate() {
+        let mut board = setup_board(Point::new(2, 3));
+        let rook_id = board.add_to_pocket(&Color::White, "Rook");
+
+        move_piece(&mut board, rook_id, PieceMove::Drop(Point::new(1, 4)));
+        println!("{}", board.pp());
+        assert!(board.is_checked(&Color::Black));
+        assert!(board.has_no_moves(&Color::Black));
+    }
+}
+
+mod when_the_soldier_drop_blocks_the_attack_on_the_escape_square {
+    use super::*;
+
+    #[test]
+    fn it_is_not_a_mate() {
+        let mut board = Board::empty(config_5x5());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 3));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 4));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(2, 5));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(1, 5));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(3, 5));
+        let soldier_id = board.add_to_pocket(&Color::White, "Soldier");
+
+        println!("{}", board.pp());
+        assert!(drop_points(&board, &soldier_id).contains(&Point::new(2, 4)));
+        move_piece(&mut board, soldier_id, PieceMove::Drop(Point::new(2, 4)));
+        assert!(board.is_checked(&Color::Black));
+        assert!(!board.has_no_moves(&Color::Black));
+    }
+}
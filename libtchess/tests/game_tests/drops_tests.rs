@@ -0,0 +1,279 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_id::PieceId;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::promote_piece::PromotePiece;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+fn config_8x8(drops: bool) -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_drops(drops);
+    config
+}
+
+fn pocket_piece(board: &Board<TestHeatMap, TestSquaresMap>, color: &Color, name: &str) -> PieceId {
+    let (piece_id, _) = board
+        .pocket(color)
+        .droppable()
+        .into_iter()
+        .find(|(_, piece_name)| *piece_name == name)
+        .unwrap();
+    piece_id
+}
+
+fn drop_points(board: &Board<TestHeatMap, TestSquaresMap>, piece_id: &PieceId) -> Vec<Point> {
+    board
+        .moves_of(piece_id)
+        .map(|moves| moves.keys().map(|piece_move| *piece_move.destination()).collect())
+        .unwrap_or_default()
+}
+
+mod when_the_piece_is_captured {
+    use super::*;
+
+    #[test]
+    fn it_goes_into_the_pocket_of_the_capturing_side() {
+        let mut board = Board::empty(config_8x8(true));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(4, 1));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(4, 6));
+
+        move_piece(&mut board, *rook.id(), PieceMove::Point(Point::new(4, 6)));
+        println!("{}", board.pp());
+        assert_eq!(board.pocket(rook.color()).count("Knight"), 1);
+        assert!(board.piece_at(rook.current_position()).is_none());
+        assert!(board.pocket(&Color::Black).is_empty());
+    }
+
+    #[test]
+    fn it_is_not_kept_when_drops_are_disabled() {
+        let mut board = Board::empty(config_8x8(false));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(4, 1));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(4, 6));
+
+        move_piece_at(&mut board, Point::new(4, 1), PieceMove::Point(Point::new(4, 6)));
+        println!("{}", board.pp());
+        assert!(board.pocket(&Color::White).is_empty());
+    }
+
+    #[test]
+    fn promoted_piece_turns_back_into_pawn() {
+        let mut board = Board::empty(config_8x8(true));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 6));
+        add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(2, 7));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(8, 8));
+
+        move_piece_at(
+            &mut board,
+            Point::new(2, 7),
+            PieceMove::Promote(Point::new(2, 8), PromotePiece::Queen),
+        );
+        move_piece_at(&mut board, Point::new(8, 8), PieceMove::Point(Point::new(2, 8)));
+        println!("{}", board.pp());
+        assert_eq!(board.pocket(&Color::Black).count("Pawn"), 1);
+        assert_eq!(board.pocket(&Color::Black).count("Queen"), 0);
+    }
+}
+
+mod when_the_piece_is_in_the_pocket {
+    use super::*;
+
+    fn setup_board() -> Board<TestHeatMap, TestSquaresMap> {
+        let mut board = Board::empty(config_8x8(true));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(4, 1));
+        add_piece(&mut board, "Pawn", Color::Black, vec![], vec![], Point::new(4, 6));
+        move_piece_at(&mut board, Point::new(4, 1), PieceMove::Point(Point::new(4, 6)));
+        move_piece_at(&mut board, Point::new(8, 8), PieceMove::Point(Point::new(8, 7)));
+        board
+    }
+
+    #[test]
+    fn it_can_be_dropped_on_any_empty_square() {
+        let board = setup_board();
+        let pawn_id = pocket_piece(&board, &Color::White, "Pawn");
+
+        println!("{}", board.pp());
+        let points = drop_points(&board, &pawn_id);
+        // Every square except the squares of the pieces, the first and the last ranks
+        assert_eq!(points.len(), 48 - 2);
+        assert!(points.contains(&Point::new(4, 5)));
+        assert!(!points.contains(&Point::new(4, 6)));
+        assert!(
+            board
+                .legal_moves(&Color::White)
+                .contains(&(pawn_id, PieceMove::Drop(Point::new(4, 5))))
+        );
+    }
+
+    #[test]
+    fn pawn_can_not_be_dropped_on_promotion_ranks() {
+        let board = setup_board();
+        let pawn_id = pocket_piece(&board, &Color::White, "Pawn");
+
+        println!("{}", board.pp());
+        let points = drop_points(&board, &pawn_id);
+        assert!(points.iter().all(|point| ![1, 8].contains(point.y().value())));
+    }
+
+    #[test]
+    fn dropped_piece_leaves_the_pocket() {
+        let mut board = setup_board();
+        let pawn_id = pocket_piece(&board, &Color::White, "Pawn");
+
+        move_piece(&mut board, pawn_id, PieceMove::Drop(Point::new(7, 6)));
+        println!("{}", board.pp());
+        assert!(board.pocket(&Color::White).is_empty());
+        let pawn = board.piece_at(&Point::new(7, 6)).unwrap();
+        assert_eq!(pawn.name(), "Pawn");
+        assert_eq!(pawn.color(), &Color::White);
+        assert_eq!(board.current_turn(), &Color::Black);
+        // The dropped pawn checks the king
+        assert!(board.is_checked(&Color::Black));
+        assert_eq!(board.moves_of(&pawn_id), None);
+    }
+}
+
+mod when_the_king_is_in_check {
+    use super::*;
+
+    #[test]
+    fn the_check_can_be_blocked_by_a_drop() {
+        let mut board = Board::empty(config_8x8(true));
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 4));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(2, 2));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(2, 6));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(8, 8));
+
+        move_piece_at(&mut board, Point::new(2, 2), PieceMove::Point(Point::new(2, 6)));
+        move_piece_at(&mut board, Point::new(8, 8), PieceMove::Point(Point::new(5, 8)));
+        println!("{}", board.pp());
+        assert!(board.is_checked(king.color()));
+        let knight_id = pocket_piece(&board, &Color::White, "Knight");
+        compare_and_assert(
+            &board.moves_of(&knight_id).to_vec(),
+            &vec![
+                &PieceMove::Drop(Point::new(5, 2)),
+                &PieceMove::Drop(Point::new(5, 3)),
+                &PieceMove::Drop(Point::new(5, 4)),
+                &PieceMove::Drop(Point::new(5, 5)),
+                &PieceMove::Drop(Point::new(5, 6)),
+                &PieceMove::Drop(Point::new(5, 7)),
+            ],
+        );
+    }
+
+    #[test]
+    fn the_knight_check_can_not_be_blocked() {
+        let mut board = Board::empty(config_8x8(true));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(2, 2));
+        add_piece(&mut board, "Bishop", Color::Black, vec![], vec![], Point::new(2, 6));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(5, 5));
+
+        move_piece_at(&mut board, Point::new(2, 2), PieceMove::Point(Point::new(2, 6)));
+        move_piece_at(&mut board, Point::new(5, 5), PieceMove::Point(Point::new(4, 3)));
+        println!("{}", board.pp());
+        assert!(board.is_checked(&Color::White));
+        let bishop_id = pocket_piece(&board, &Color::White, "Bishop");
+        assert_eq!(board.moves_of(&bishop_id), None);
+        assert!(
+            board
+                .legal_moves(&Color::White)
+                .iter()
+                .all(|(_, piece_move)| !matches!(piece_move, PieceMove::Drop(_)))
+        );
+    }
+}
+
+mod when_captures_are_passed {
+    use super::*;
+
+    fn config() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+        let mut config = config_8x8(true);
+        config.set_pass_captures(true);
+        config
+    }
+
+    #[test]
+    fn captured_piece_does_not_go_into_the_own_pocket() {
+        let mut board = Board::empty(config());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(4, 1));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(4, 6));
+
+        move_piece_at(&mut board, Point::new(4, 1), PieceMove::Point(Point::new(4, 6)));
+        println!("{}", board.pp());
+        assert!(board.pocket(&Color::White).is_empty());
+        assert_eq!(board.take_passed_pieces(&Color::White), vec!["Knight".to_string()]);
+        assert!(board.take_passed_pieces(&Color::White).is_empty());
+    }
+
+    #[test]
+    fn passed_piece_can_block_the_check() {
+        let mut board = Board::empty(config());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 4));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(5, 4));
+
+        println!("{}", board.pp());
+        assert!(board.is_checked(&Color::White));
+        let bishop_id = board.add_to_pocket(&Color::White, "Bishop");
+        compare_and_assert(
+            &board.moves_of(&bishop_id).to_vec(),
+            &vec![
+                &PieceMove::Drop(Point::new(5, 2)),
+                &PieceMove::Drop(Point::new(5, 3)),
+            ],
+        );
+        move_piece(&mut board, bishop_id, PieceMove::Drop(Point::new(5, 3)));
+        assert!(!board.is_checked(&Color::White));
+    }
+}
+
+mod position_key {
+    use super::*;
+
+    fn zposition_after_capture_of(name: &str, drops: bool) -> u128 {
+        let mut board = Board::empty(config_8x8(drops));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(4, 1));
+        add_piece(&mut board, name, Color::Black, vec![], vec![], Point::new(4, 6));
+
+        move_piece_at(&mut board, rook.current_position, PieceMove::Point(Point::new(4, 6)));
+        board.stats().zposition.0
+    }
+
+    #[test]
+    fn it_depends_on_the_pocket() {
+        assert_ne!(
+            zposition_after_capture_of("Knight", true),
+            zposition_after_capture_of("Bishop", true)
+        );
+        assert_eq!(
+            zposition_after_capture_of("Knight", false),
+            zposition_after_capture_of("Bishop", false)
+        );
+    }
+}
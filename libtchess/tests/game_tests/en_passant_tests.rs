@@ -5,6 +5,7 @@ use libtchess::board::Board;
 use libtchess::buff::Buff;
 use libtchess::color::Color;
 use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
 use libtchess::piece_id::PieceId;
 use libtchess::piece_move::PieceMove;
 use libtchess::point::Point;
@@ -396,3 +397,108 @@ mod en_passant_for_two_pawns {
             .to(|_board| Point::new(3, 3));
     }
 }
+
+mod when_the_long_move_of_the_pawn_checks_the_king {
+    use super::*;
+
+    #[test]
+    fn it_allows_to_capture_the_pawn_en_passant() {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+        let config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+        let mut board = Board::empty(config);
+        board.pass_turn(&Color::Black);
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 4));
+        let pawn = add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(5, 5));
+        add_piece(
+            &mut board,
+            "Pawn",
+            Color::Black,
+            vec![Buff::AdditionalPoint],
+            vec![],
+            Point::new(4, 7),
+        );
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+
+        move_piece_at(&mut board, Point::new(4, 7), PieceMove::LongMove(Point::new(4, 5)));
+        println!("{}", board.pp());
+        assert!(board.is_checked(&Color::White));
+        move_piece(
+            &mut board,
+            *pawn.id(),
+            PieceMove::EnPassant(Point::new(4, 6), Point::new(4, 5)),
+        );
+        println!("{}", board.pp());
+        assert!(board.piece_at(&Point::new(4, 5)).is_none());
+    }
+}
+
+mod when_the_en_passant_is_not_utilized {
+    use super::*;
+
+    #[test]
+    fn it_is_removed_from_the_moves_of_the_pawn() {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+        let config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+        let mut board = Board::empty(config);
+        board.pass_turn(&Color::Black);
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(8, 1));
+        let pawn = add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(2, 4));
+        add_piece(
+            &mut board,
+            "Pawn",
+            Color::Black,
+            vec![Buff::AdditionalPoint],
+            vec![],
+            Point::new(3, 6),
+        );
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+
+        move_piece_at(&mut board, Point::new(3, 6), PieceMove::LongMove(Point::new(3, 4)));
+        let en_passant = PieceMove::EnPassant(Point::new(3, 5), Point::new(3, 4));
+        assert!(board.moves_of(pawn.id()).unwrap().contains_key(&en_passant));
+        // Neither move affects the pawn, but the en passant is not available after the turn
+        move_piece_at(&mut board, Point::new(8, 1), PieceMove::Point(Point::new(8, 2)));
+        move_piece_at(&mut board, Point::new(8, 8), PieceMove::Point(Point::new(8, 7)));
+        println!("{}", board.pp());
+        assert!(!board.moves_of(pawn.id()).unwrap().contains_key(&en_passant));
+    }
+}
+
+mod when_the_pawn_is_captured_en_passant {
+    use super::*;
+
+    #[test]
+    fn it_opens_the_line_through_its_square() {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+        let config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+        let mut board = Board::empty(config);
+        board.pass_turn(&Color::Black);
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(8, 1));
+        let pawn = add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(5, 5));
+        add_piece(
+            &mut board,
+            "Pawn",
+            Color::Black,
+            vec![Buff::AdditionalPoint],
+            vec![],
+            Point::new(4, 7),
+        );
+        let rook = add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(4, 2));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+
+        move_piece_at(&mut board, Point::new(4, 7), PieceMove::LongMove(Point::new(4, 5)));
+        move_piece(
+            &mut board,
+            *pawn.id(),
+            PieceMove::EnPassant(Point::new(4, 6), Point::new(4, 5)),
+        );
+        println!("{}", board.pp());
+        // The rook is not blocked by the captured pawn anymore
+        assert!(
+            board
+                .moves_of(rook.id())
+                .unwrap()
+                .contains_key(&PieceMove::Point(Point::new(4, 6)))
+        );
+    }
+}
@@ -0,0 +1,176 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::fen;
+use libtchess::fen::FenError;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::perft::perft;
+use libtchess::utils::pretty_print::PrettyPrint;
+use libtchess::variant::Variant;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::*;
+
+const INITIAL_POSITION: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+
+fn config_8x8() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    board_config(dimension, TestSquaresMap::from_dimension(&dimension))
+}
+
+fn three_check_config() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let mut config = config_8x8();
+    config.set_variant(Variant::CheckLimit(3));
+    config
+}
+
+fn parse(
+    config: BoardConfig<TestHeatMap, TestSquaresMap>,
+    fen: &str,
+) -> Board<TestHeatMap, TestSquaresMap> {
+    let board = fen::parse(config, fen).unwrap();
+    println!("{}", board.pp());
+    board
+}
+
+mod when_importing_the_initial_position {
+    use super::*;
+
+    #[test]
+    fn it_matches_the_prefilled_board() {
+        let board = parse(config_8x8(), INITIAL_POSITION);
+        assert_eq!(board.stats().zposition, classic_8x8_prefilled().stats().zposition);
+        assert_eq!(perft(&board, 3), 8902);
+    }
+
+    #[test]
+    fn it_exports_the_same_fen() {
+        let board = parse(config_8x8(), INITIAL_POSITION);
+        assert_eq!(fen::export(&board), Ok(INITIAL_POSITION.to_string()));
+    }
+
+    #[test]
+    fn it_exports_the_turn_counters_after_the_moves() {
+        let mut board = parse(config_8x8(), INITIAL_POSITION);
+        move_piece_at(&mut board, Point::new(5, 2), PieceMove::LongMove(Point::new(5, 4)));
+        move_piece_at(&mut board, Point::new(7, 8), PieceMove::Point(Point::new(6, 6)));
+        move_piece_at(&mut board, Point::new(7, 1), PieceMove::Point(Point::new(6, 3)));
+        assert_eq!(
+            fen::export(&board),
+            Ok("rnbqkb1r/pppppppp/5n2/8/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 2 2".to_string())
+        );
+    }
+}
+
+mod when_importing_kiwipete {
+    use super::*;
+
+    #[test]
+    fn it_counts_moves_with_castles() {
+        let board = parse(config_8x8(), KIWIPETE);
+        assert_eq!(perft(&board, 1), 48);
+        assert_eq!(perft(&board, 2), 2039);
+    }
+
+    #[test]
+    fn it_exports_the_same_fen() {
+        let board = parse(config_8x8(), KIWIPETE);
+        assert_eq!(fen::export(&board), Ok(KIWIPETE.to_string()));
+    }
+}
+
+mod when_importing_the_en_passant_square {
+    use super::*;
+
+    const FEN: &str = "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3";
+
+    #[test]
+    fn the_pawn_can_capture_en_passant() {
+        let mut board = parse(config_8x8(), FEN);
+        move_piece_at(
+            &mut board,
+            Point::new(5, 5),
+            PieceMove::EnPassant(Point::new(6, 6), Point::new(6, 5)),
+        );
+        assert!(board.piece_at(&Point::new(6, 5)).is_none());
+    }
+
+    #[test]
+    fn it_matches_the_position_after_the_long_move() {
+        let mut board = parse(
+            config_8x8(),
+            "rnbqkbnr/ppp1pppp/8/3pP3/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 2",
+        );
+        move_piece_at(&mut board, Point::new(6, 7), PieceMove::LongMove(Point::new(6, 5)));
+        assert_eq!(fen::export(&board), Ok(FEN.to_string()));
+        assert_eq!(board.stats().zposition, parse(config_8x8(), FEN).stats().zposition);
+    }
+
+    #[test]
+    fn it_finds_the_square_on_the_offset_board() {
+        // The board starts from the second row and column of the squares map
+        let squares_map_dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+        let dimension = Dimension::new(Point::new(2, 2), Point::new(7, 7));
+        let config = board_config(dimension, TestSquaresMap::from_dimension(&squares_map_dimension));
+        let fen = "k5/6/2pP2/6/6/5K w - c5 0 1";
+        let mut board = parse(config, fen);
+        assert_eq!(fen::export(&board), Ok(fen.to_string()));
+        move_piece_at(
+            &mut board,
+            Point::new(5, 5),
+            PieceMove::EnPassant(Point::new(4, 6), Point::new(4, 5)),
+        );
+        assert!(board.piece_at(&Point::new(4, 5)).is_none());
+    }
+
+    #[test]
+    fn it_rejects_the_square_without_the_pawn_behind() {
+        let result = fen::parse(
+            config_8x8(),
+            "rnbqkbnr/ppp1pppp/8/3pP3/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        );
+        assert_eq!(result.err(), Some(FenError::InvalidEnPassant("f6".to_string())));
+    }
+}
+
+mod when_the_checks_are_limited {
+    use super::*;
+
+    const FEN: &str = "7k/8/8/8/8/8/8/R3K3 w - - 4 3 +1+0";
+
+    #[test]
+    fn it_imports_the_checks_given() {
+        let board = parse(three_check_config(), FEN);
+        assert_eq!(board.stats().checks_given[&Color::White], 1);
+        assert_eq!(board.stats().checks_given[&Color::Black], 0);
+    }
+
+    #[test]
+    fn it_exports_the_checks_given() {
+        let board = parse(three_check_config(), FEN);
+        assert_eq!(fen::export(&board), Ok(FEN.to_string()));
+    }
+
+    #[test]
+    fn the_checks_given_are_part_of_the_position() {
+        let mut board = parse(three_check_config(), "7k/8/8/8/8/8/8/R3K3 w - - 0 1 +0+0");
+        move_piece_at(&mut board, Point::new(1, 1), PieceMove::Point(Point::new(1, 8)));
+        move_piece_at(&mut board, Point::new(8, 8), PieceMove::Point(Point::new(8, 7)));
+        move_piece_at(&mut board, Point::new(1, 8), PieceMove::Point(Point::new(1, 1)));
+        move_piece_at(&mut board, Point::new(8, 7), PieceMove::Point(Point::new(8, 8)));
+        assert_eq!(fen::export(&board), Ok(FEN.to_string()));
+        assert_eq!(board.stats().zposition, parse(three_check_config(), FEN).stats().zposition);
+        assert_ne!(
+            board.stats().zposition,
+            parse(three_check_config(), "7k/8/8/8/8/8/8/R3K3 w - - 4 3 +0+0")
+                .stats()
+                .zposition
+        );
+    }
+}
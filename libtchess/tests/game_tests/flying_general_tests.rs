@@ -0,0 +1,73 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+fn config_5x5() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_flying_general(true);
+    config
+}
+
+mod when_the_kings_are_on_the_same_file {
+    use super::*;
+
+    #[test]
+    fn the_only_piece_in_between_can_not_leave_the_file() {
+        let mut board = Board::empty(config_5x5());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(3, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(3, 5));
+        let knight = add_piece(&mut board, "Knight", Color::White, vec![], vec![], Point::new(3, 3));
+
+        println!("{}", board.pp());
+        assert!(board.moves_of(knight.id()).to_vec().is_empty());
+        assert!(!board.is_checked(knight.color()));
+    }
+
+    #[test]
+    fn the_piece_can_leave_when_another_one_stays() {
+        let mut board = Board::empty(config_5x5());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(3, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(3, 5));
+        let knight = add_piece(&mut board, "Knight", Color::White, vec![], vec![], Point::new(3, 3));
+        add_piece(&mut board, "Bishop", Color::Black, vec![], vec![], Point::new(3, 4));
+
+        move_piece(&mut board, *knight.id(), PieceMove::Point(Point::new(1, 4)));
+        println!("{}", board.pp());
+        assert!(board.piece_at(knight.current_position()).is_none());
+    }
+}
+
+mod when_the_file_is_open {
+    use super::*;
+
+    #[test]
+    fn the_king_can_not_step_onto_it() {
+        let mut board = Board::empty(config_5x5());
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(2, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(3, 5));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(king.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(1, 1)),
+                &PieceMove::Point(Point::new(1, 2)),
+                &PieceMove::Point(Point::new(2, 2)),
+            ],
+        );
+        assert_eq!(king.current_position, Point::new(2, 1));
+    }
+}
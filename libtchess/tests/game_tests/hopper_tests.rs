@@ -0,0 +1,103 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::betza::BetzaPiece;
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_move::PieceMove;
+use libtchess::piece_registry::PieceRegistry;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+// The cannon moves like the rook, but captures by jumping over exactly one piece
+fn config_7x7() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(7, 7));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    let mut piece_registry = PieceRegistry::classic();
+    piece_registry.register(BetzaPiece::new("Cannon", ColoredProperty::from(['C', 'c']), 450, "mRcpR").unwrap());
+    config.set_piece_registry(piece_registry);
+    config
+}
+
+mod when_there_is_a_screen {
+    use super::*;
+
+    #[test]
+    fn it_captures_the_first_piece_behind_the_screen() {
+        let mut board = Board::empty(config_7x7());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(7, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(7, 7));
+        let cannon = add_piece(&mut board, "Cannon", Color::White, vec![], vec![], Point::new(4, 1));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(4, 3));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(4, 6));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(cannon.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(4, 2)),
+                &PieceMove::Point(Point::new(4, 6)),
+                &PieceMove::Point(Point::new(1, 1)),
+                &PieceMove::Point(Point::new(2, 1)),
+                &PieceMove::Point(Point::new(3, 1)),
+                &PieceMove::Point(Point::new(5, 1)),
+                &PieceMove::Point(Point::new(6, 1)),
+            ],
+        );
+    }
+
+    #[test]
+    fn it_stops_capturing_when_the_screen_leaves() {
+        let mut board = Board::empty(config_7x7());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(7, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(7, 7));
+        let cannon = add_piece(&mut board, "Cannon", Color::White, vec![], vec![], Point::new(4, 1));
+        let knight = add_piece(&mut board, "Knight", Color::White, vec![], vec![], Point::new(4, 3));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(4, 6));
+
+        move_piece(&mut board, *knight.id(), PieceMove::Point(Point::new(5, 5)));
+        println!("{}", board.pp());
+        let moves = board.moves_of(cannon.id());
+        let moves = moves.to_vec();
+        assert!(!moves.contains(&&PieceMove::Point(Point::new(4, 6))));
+        assert!(moves.contains(&&PieceMove::Point(knight.current_position)));
+    }
+}
+
+mod when_the_king_is_behind_the_screen {
+    use super::*;
+
+    #[test]
+    fn it_is_in_check() {
+        let mut board = Board::empty(config_7x7());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(7, 1));
+        let king = add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(6, 4));
+        add_piece(&mut board, "Cannon", Color::White, vec![], vec![], Point::new(1, 2));
+        let knight = add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(3, 4));
+
+        move_piece_at(&mut board, Point::new(1, 2), PieceMove::Point(Point::new(1, 4)));
+        println!("{}", board.pp());
+        assert!(board.is_checked(king.color()));
+        // The points behind the screen are attacked
+        let king_moves = board.moves_of(king.id());
+        let king_moves = king_moves.to_vec();
+        assert!(!king_moves.contains(&&PieceMove::Point(Point::new(5, 4))));
+        assert!(!king_moves.contains(&&PieceMove::Point(Point::new(7, 4))));
+        assert!(king_moves.contains(&&PieceMove::Point(Point::new(6, 5))));
+        // Taking the screen away parries the check
+        assert!(
+            board
+                .moves_of(knight.id())
+                .is_some_and(|moves| moves.contains_key(&PieceMove::Point(Point::new(2, 6))))
+        );
+        assert_eq!(board.piece_at(king.current_position()).unwrap().name(), "King");
+    }
+}
@@ -0,0 +1,83 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::buff::Buff;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+fn config_8x8() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    board_config(dimension, TestSquaresMap::from_dimension(&dimension))
+}
+
+mod when_the_side_has_no_king {
+    use super::*;
+
+    #[test]
+    fn its_pieces_are_not_pinned() {
+        let mut board = Board::empty(config_8x8());
+        let pawn = add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(5, 3));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(5, 8));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 8));
+
+        println!("{}", board.pp());
+        assert!(board.royals(pawn.color()).is_empty());
+        assert!(!board.is_checked(pawn.color()));
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &vec![&PieceMove::Point(Point::new(5, 4))],
+        );
+    }
+
+    #[test]
+    fn it_has_no_moves_when_all_pieces_are_captured() {
+        let mut board = Board::empty(config_8x8());
+        let pawn = add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(1, 4));
+        let rook = add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(1, 8));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+
+        board.pass_turn(rook.color());
+        move_piece(&mut board, *rook.id(), PieceMove::Point(pawn.current_position));
+        println!("{}", board.pp());
+        assert!(board.active_pieces(pawn.color()).is_empty());
+        assert!(board.has_no_moves(pawn.color()));
+        assert_eq!(board.piece_at(pawn.current_position()).unwrap().id(), rook.id());
+    }
+}
+
+mod when_the_pawn_is_on_the_first_rank {
+    use super::*;
+
+    #[test]
+    fn it_can_go_further_on_its_first_move() {
+        let mut board = Board::empty(config_8x8());
+        let pawn = add_piece(
+            &mut board,
+            "Pawn",
+            Color::White,
+            vec![Buff::AdditionalPoint],
+            vec![],
+            Point::new(3, 1),
+        );
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(3, 2)),
+                &PieceMove::LongMove(Point::new(3, 3)),
+            ],
+        );
+    }
+}
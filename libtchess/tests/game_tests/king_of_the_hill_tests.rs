@@ -0,0 +1,66 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::*;
+
+fn config_8x8(hill_squares: Vec<Point>) -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_hill_squares(hill_squares);
+    config
+}
+
+mod when_the_king_reaches_the_hill {
+    use super::*;
+
+    #[test]
+    fn it_is_on_the_hill() {
+        let mut board = Board::empty(config_8x8(vec![Point::new(4, 4), Point::new(5, 5)]));
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(4, 3));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+
+        assert!(!board.is_king_on_the_hill(king.color()));
+        move_piece(&mut board, *king.id(), PieceMove::Point(Point::new(4, 4)));
+        println!("{}", board.pp());
+        assert!(board.is_king_on_the_hill(king.color()));
+        assert!(!board.is_king_on_the_hill(&Color::Black));
+    }
+
+    #[test]
+    fn other_pieces_do_not_count() {
+        let mut board = Board::empty(config_8x8(vec![Point::new(4, 4)]));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(4, 1));
+
+        move_piece(&mut board, *rook.id(), PieceMove::Point(Point::new(4, 4)));
+        println!("{}", board.pp());
+        assert_eq!(board.piece_at(&Point::new(4, 4)).unwrap().id(), rook.id());
+        assert!(!board.is_king_on_the_hill(rook.color()));
+    }
+}
+
+mod when_there_is_no_hill {
+    use super::*;
+
+    #[test]
+    fn the_king_is_never_on_the_hill() {
+        let mut board = Board::empty(config_8x8(vec![]));
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(4, 4));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+
+        println!("{}", board.pp());
+        assert_eq!(board.piece_at(king.current_position()).unwrap().id(), king.id());
+        assert!(!board.config().is_hill_square(&king.current_position));
+        assert!(!board.is_king_on_the_hill(king.color()));
+    }
+}
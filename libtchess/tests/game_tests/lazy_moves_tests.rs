@@ -0,0 +1,187 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::piece_id::PieceId;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::{CloneMoves, ToVecRef};
+use support::*;
+
+fn lazy_config(dimension: Dimension) -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_lazy_moves(true);
+    config
+}
+
+mod when_comparing_with_eager_moves_generation {
+    use super::*;
+
+    // Picks a move in a deterministic way, so both boards make the same moves
+    fn pick_move(board: &Board<TestHeatMap, TestSquaresMap>, ply: usize) -> (PieceId, PieceMove) {
+        let mut moves = vec![];
+        for (_, piece_to_moves) in board.score_to_moves(board.current_turn()) {
+            for (piece_id, piece_moves) in piece_to_moves {
+                for piece_move in piece_moves {
+                    moves.push((*piece_id, *piece_move));
+                }
+            }
+        }
+        moves.sort();
+        moves[(ply * 7) % moves.len()]
+    }
+
+    #[test]
+    fn it_generates_the_same_moves() {
+        let mut eager_board = classic_8x8_prefilled();
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+        let mut lazy_board = classic_8x8_prefilled_with_config(lazy_config(dimension));
+
+        for ply in 0..80 {
+            for color in [Color::White, Color::Black].iter() {
+                compare_and_assert(&all_moves(&lazy_board, color), &all_moves(&eager_board, color));
+                assert_eq!(lazy_board.has_no_moves(color), eager_board.has_no_moves(color));
+            }
+            if eager_board.has_no_moves(eager_board.current_turn()) {
+                break;
+            }
+            let (piece_id, piece_move) = pick_move(&eager_board, ply);
+            move_piece(&mut eager_board, piece_id, piece_move);
+            move_piece(&mut lazy_board, piece_id, piece_move);
+            println!("{}", lazy_board.pp());
+        }
+    }
+
+    #[test]
+    fn it_returns_the_same_moves_of_a_piece() {
+        let mut eager_board = classic_8x8_prefilled();
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+        let mut lazy_board = classic_8x8_prefilled_with_config(lazy_config(dimension));
+
+        for board in [&mut eager_board, &mut lazy_board] {
+            move_piece_at(board, Point::new(5, 2), PieceMove::LongMove(Point::new(5, 4)));
+            move_piece_at(board, Point::new(4, 7), PieceMove::LongMove(Point::new(4, 5)));
+        }
+        println!("{}", lazy_board.pp());
+
+        let bishop_id = *lazy_board.piece_id_at(&Point::new(6, 1)).unwrap();
+        compare_and_assert(
+            &lazy_board.moves_of(&bishop_id).to_vec().clone_moves(),
+            &eager_board.moves_of(&bishop_id).to_vec().clone_moves(),
+        );
+    }
+}
+
+mod when_the_king_is_checkmated {
+    use super::*;
+
+    fn setup_board() -> Board<TestHeatMap, TestSquaresMap> {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+        let mut board = Board::empty(lazy_config(dimension));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(3, 1));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 5));
+        add_piece(&mut board, "Pawn", Color::Black, vec![], vec![], Point::new(1, 4));
+        add_piece(&mut board, "Pawn", Color::Black, vec![], vec![], Point::new(2, 4));
+        board
+    }
+
+    #[test]
+    fn it_has_no_moves() {
+        let mut board = setup_board();
+        move_piece_at(&mut board, Point::new(5, 1), PieceMove::Point(Point::new(5, 5)));
+        println!("{}", board.pp());
+
+        let king = board.king(&Color::Black).unwrap();
+        assert!(board.debuffs(&Color::Black).has_check(king.id()));
+        assert!(board.has_no_moves(&Color::Black));
+    }
+}
+
+mod when_the_king_is_stalemated {
+    use super::*;
+
+    fn setup_board() -> Board<TestHeatMap, TestSquaresMap> {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+        let mut board = Board::empty(lazy_config(dimension));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "Queen", Color::White, vec![], vec![], Point::new(3, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 5));
+        board
+    }
+
+    #[test]
+    fn it_has_no_moves() {
+        let mut board = setup_board();
+        assert!(!board.has_no_moves(&Color::Black));
+        move_piece_at(&mut board, Point::new(3, 1), PieceMove::Point(Point::new(3, 4)));
+        println!("{}", board.pp());
+
+        let king = board.king(&Color::Black).unwrap();
+        assert!(!board.debuffs(&Color::Black).has_check(king.id()));
+        assert!(board.has_no_moves(&Color::Black));
+    }
+}
+
+mod when_the_turn_is_passed_into_check {
+    use super::*;
+    use libtchess::game_board::GameBoard;
+
+    fn setup_board(
+        config: BoardConfig<TestHeatMap, TestSquaresMap>,
+    ) -> Board<TestHeatMap, TestSquaresMap> {
+        let mut board = Board::empty(config);
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(3, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(3, 5));
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(1, 5));
+        board
+    }
+
+    fn sorted_legal_moves(
+        board: &Board<TestHeatMap, TestSquaresMap>,
+        color: &Color,
+    ) -> Vec<(PieceId, PieceMove)> {
+        let mut moves = board.legal_moves(color);
+        moves.sort();
+        moves
+    }
+
+    #[test]
+    fn it_generates_the_same_moves_as_eager_board() {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+        let squares_map = TestSquaresMap::from_dimension(&dimension);
+        let mut eager_board = setup_board(board_config(dimension, squares_map));
+        let mut lazy_board = setup_board(lazy_config(dimension));
+        println!("{}", lazy_board.pp());
+        assert_eq!(
+            sorted_legal_moves(&lazy_board, &Color::Black),
+            sorted_legal_moves(&eager_board, &Color::Black)
+        );
+
+        eager_board.pass_turn(&Color::Black);
+        lazy_board.pass_turn(&Color::Black);
+        assert!(GameBoard::is_checked(&lazy_board, &Color::Black));
+        assert_eq!(
+            sorted_legal_moves(&lazy_board, &Color::Black),
+            sorted_legal_moves(&eager_board, &Color::Black)
+        );
+
+        for board in [&mut eager_board, &mut lazy_board] {
+            move_piece_at(board, Point::new(3, 5), PieceMove::Point(Point::new(2, 5)));
+        }
+        println!("{}", lazy_board.pp());
+        for color in [Color::White, Color::Black].iter() {
+            assert_eq!(
+                sorted_legal_moves(&lazy_board, color),
+                sorted_legal_moves(&eager_board, color)
+            );
+        }
+    }
+}
@@ -0,0 +1,167 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::betza::BetzaPiece;
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::dimension::Dimension;
+use libtchess::movement::Movement;
+use libtchess::piece_definition::PieceDefinition;
+use libtchess::piece_move::PieceMove;
+use libtchess::piece_registry::PieceRegistry;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use libtchess::vector::Vector;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+// (1, 4) leaper
+#[derive(Debug)]
+struct Giraffe;
+
+impl PieceDefinition for Giraffe {
+    fn name(&self) -> &str {
+        "Giraffe"
+    }
+
+    fn symbol(&self, color: &Color) -> char {
+        match color.appearance() {
+            Color::White => 'G',
+            _ => 'g',
+        }
+    }
+
+    fn value(&self) -> i16 {
+        200
+    }
+
+    fn movements(&self, _color: &Color) -> Vec<Movement> {
+        Vector::leap_vectors(1, 4).into_iter().map(Movement::leap).collect()
+    }
+}
+
+fn config_8x8() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    let mut piece_registry = PieceRegistry::classic();
+    for (name, notation) in [("Camel", "C"), ("Zebra", "Z"), ("Nightrider", "NN")] {
+        let piece = BetzaPiece::new(name, ColoredProperty::from(['X', 'x']), 300, notation).unwrap();
+        piece_registry.register(piece);
+    }
+    piece_registry.register(Giraffe);
+    config.set_piece_registry(piece_registry);
+    config
+}
+
+mod when_there_are_no_pieces_around {
+    use super::*;
+
+    #[test]
+    fn camel_leaps_by_one_and_three_squares() {
+        let mut board = Board::empty(config_8x8());
+        let camel = add_piece(&mut board, "Camel", Color::White, vec![], vec![], Point::new(4, 4));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(camel.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(3, 7)),
+                &PieceMove::Point(Point::new(5, 7)),
+                &PieceMove::Point(Point::new(3, 1)),
+                &PieceMove::Point(Point::new(5, 1)),
+                &PieceMove::Point(Point::new(1, 5)),
+                &PieceMove::Point(Point::new(7, 5)),
+                &PieceMove::Point(Point::new(1, 3)),
+                &PieceMove::Point(Point::new(7, 3)),
+            ],
+        );
+    }
+
+    #[test]
+    fn giraffe_leaps_by_one_and_four_squares() {
+        let mut board = Board::empty(config_8x8());
+        let giraffe =
+            add_piece(&mut board, "Giraffe", Color::White, vec![], vec![], Point::new(1, 1));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(giraffe.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(2, 5)),
+                &PieceMove::Point(Point::new(5, 2)),
+            ],
+        );
+    }
+}
+
+mod when_the_leaper_checks_the_king {
+    use super::*;
+
+    #[test]
+    fn it_does_not_allow_to_block_the_check() {
+        let mut board = Board::empty(config_8x8());
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(3, 4));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 8));
+        let zebra = add_piece(&mut board, "Zebra", Color::Black, vec![], vec![], Point::new(1, 1));
+
+        println!("{}", board.pp());
+        assert!(board.debuffs(king.color()).has_check(king.id()));
+        compare_and_assert(
+            &board.moves_of(rook.id()).to_vec(),
+            &vec![&PieceMove::Point(*zebra.current_position())],
+        );
+    }
+}
+
+mod when_the_rider_checks_the_king {
+    use super::*;
+
+    #[test]
+    fn it_allows_to_block_the_check_on_the_landing_square() {
+        let mut board = Board::empty(config_8x8());
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(3, 5));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(2, 8));
+        add_piece(&mut board, "Nightrider", Color::Black, vec![], vec![], Point::new(1, 1));
+
+        println!("{}", board.pp());
+        assert!(board.debuffs(king.color()).has_check(king.id()));
+        compare_and_assert(
+            &board.moves_of(rook.id()).to_vec(),
+            &vec![&PieceMove::Point(Point::new(2, 3))],
+        );
+    }
+}
+
+mod when_the_rider_is_directed_to_the_king {
+    use super::*;
+
+    #[test]
+    fn it_pins_the_piece() {
+        let mut board = Board::empty(config_8x8());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(4, 7));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(3, 5));
+        add_piece(&mut board, "Nightrider", Color::Black, vec![], vec![], Point::new(1, 1));
+
+        println!("{}", board.pp());
+        assert!(board.debuffs(rook.color()).has_pin(rook.id()));
+        compare_and_assert(&board.moves_of(rook.id()).to_vec(), &vec![]);
+    }
+
+    #[test]
+    fn it_pins_the_piece_when_another_piece_leaves_the_way() {
+        let mut board = Board::empty(config_8x8());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(4, 7));
+        let bishop = add_piece(&mut board, "Bishop", Color::White, vec![], vec![], Point::new(2, 3));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(3, 5));
+        add_piece(&mut board, "Nightrider", Color::Black, vec![], vec![], Point::new(1, 1));
+        assert!(!board.debuffs(rook.color()).has_pin(rook.id()));
+
+        move_piece(&mut board, *bishop.id(), PieceMove::Point(Point::new(1, 2)));
+        println!("{}", board.pp());
+        assert!(board.debuffs(rook.color()).has_pin(rook.id()));
+    }
+}
@@ -1,7 +1,38 @@
 mod capture_the_piece_tests;
 mod castle_tests;
+mod castle_rules_tests;
 mod checking_the_king_tests;
 mod en_passant_tests;
+mod pawn_initial_steps_tests;
 mod pin_tests;
 mod promote_pawn_tests;
+mod promotion_zones_tests;
 mod evaluated_moves_tests;
+mod lazy_moves_tests;
+mod custom_pieces_tests;
+mod betza_tests;
+mod leaper_tests;
+mod ride_distance_tests;
+mod royal_pieces_tests;
+mod drops_tests;
+mod atomic_tests;
+mod antichess_tests;
+mod three_check_tests;
+mod king_of_the_hill_tests;
+mod horde_tests;
+mod racing_kings_tests;
+mod custom_promotions_tests;
+mod promotion_limits_tests;
+mod drop_restrictions_tests;
+mod hopper_tests;
+mod board_regions_tests;
+mod flying_general_tests;
+mod multiple_sides_tests;
+mod perft_tests;
+mod fen_tests;
+mod search_tests;
+mod classic_rules_game_tests;
+#[cfg(feature = "sync")]
+mod thread_safety_tests;
+#[cfg(feature = "sync")]
+mod parallel_recalculation_tests;
@@ -0,0 +1,161 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::orientation::Orientation;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::search::Search;
+use libtchess::utils::pretty_print::PrettyPrint;
+use libtchess::vector::line_vector::LineVector;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+// Four sides around the board, the opposite sides play in the same team
+const SOUTH: Color = Color::new(0, 0, Orientation::BOTTOM);
+const WEST: Color = Color::new(1, 1, Orientation::new(LineVector::Right, LineVector::Bottom));
+const NORTH: Color = Color::new(2, 0, Orientation::new(LineVector::Bottom, LineVector::Left));
+const EAST: Color = Color::new(3, 1, Orientation::new(LineVector::Left, LineVector::Top));
+
+fn config_8x8() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_sides(vec![SOUTH, WEST, NORTH, EAST]);
+    config
+}
+
+mod when_four_sides_play_in_two_teams {
+    use super::*;
+
+    #[test]
+    fn its_turns_go_around_the_sides() {
+        let mut board = Board::empty(config_8x8());
+        let pawns = [
+            (SOUTH, Point::new(2, 2), Point::new(2, 3)),
+            (WEST, Point::new(2, 5), Point::new(3, 5)),
+            (NORTH, Point::new(7, 7), Point::new(7, 6)),
+            (EAST, Point::new(7, 4), Point::new(6, 4)),
+        ]
+        .map(|(color, point, destination)| {
+            let pawn = add_piece(&mut board, "Pawn", color, vec![], vec![], point);
+            (pawn, destination)
+        });
+
+        println!("{}", board.pp());
+        for (pawn, destination) in pawns {
+            assert_eq!(board.current_turn(), pawn.color());
+            compare_and_assert(
+                &board.moves_of(pawn.id()).to_vec(),
+                &vec![&PieceMove::Point(destination)],
+            );
+            move_piece(&mut board, *pawn.id(), PieceMove::Point(destination));
+        }
+        assert_eq!(board.current_turn(), &SOUTH);
+    }
+
+    #[test]
+    fn its_pieces_do_not_capture_their_teammates() {
+        let mut board = Board::empty(config_8x8());
+        let rook = add_piece(&mut board, "Rook", SOUTH, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "Rook", NORTH, vec![], vec![], Point::new(1, 3));
+        add_piece(&mut board, "Rook", WEST, vec![], vec![], Point::new(3, 1));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(rook.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(1, 2)),
+                &PieceMove::Point(Point::new(2, 1)),
+                &PieceMove::Point(Point::new(3, 1)),
+            ],
+        );
+    }
+
+    #[test]
+    fn its_king_avoids_the_points_attacked_by_any_enemy() {
+        let mut board = Board::empty(config_8x8());
+        let king = add_piece(&mut board, "King", SOUTH, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "Rook", EAST, vec![], vec![], Point::new(5, 8));
+        add_piece(&mut board, "Rook", WEST, vec![], vec![], Point::new(1, 2));
+
+        println!("{}", board.pp());
+        assert!(board.is_checked(&SOUTH));
+        compare_and_assert(
+            &board.moves_of(king.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(4, 1)),
+                &PieceMove::Point(Point::new(6, 1)),
+            ],
+        );
+    }
+
+    #[test]
+    fn its_pawns_promote_on_the_last_rank_of_their_side() {
+        let mut board = Board::empty(config_8x8());
+        let pawn = add_piece(&mut board, "Pawn", SOUTH, vec![], vec![], Point::new(2, 2));
+        move_piece(&mut board, *pawn.id(), PieceMove::Point(Point::new(2, 3)));
+        let pawn = add_piece(&mut board, "Pawn", WEST, vec![], vec![], Point::new(7, 5));
+
+        println!("{}", board.pp());
+        let moves = board.moves_of(pawn.id());
+        let moves = moves.to_vec();
+        assert!(!moves.is_empty());
+        assert!(moves.iter().all(|piece_move| {
+            matches!(piece_move, PieceMove::Promote(point, _) if *point == Point::new(8, 5))
+        }));
+    }
+}
+
+mod when_the_side_is_removed_from_the_board {
+    use super::*;
+
+    #[test]
+    fn its_pieces_leave_void_points_behind() {
+        let mut board = Board::empty(config_8x8());
+        let rook = add_piece(&mut board, "Rook", SOUTH, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "Knight", WEST, vec![], vec![], Point::new(1, 3));
+
+        board.remove_side(&WEST, true);
+        println!("{}", board.pp());
+        assert!(board.active_pieces(&WEST).is_empty());
+        compare_and_assert(
+            &board.moves_of(rook.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(1, 2)),
+                &PieceMove::Point(Point::new(2, 1)),
+                &PieceMove::Point(Point::new(3, 1)),
+                &PieceMove::Point(Point::new(4, 1)),
+                &PieceMove::Point(Point::new(5, 1)),
+                &PieceMove::Point(Point::new(6, 1)),
+                &PieceMove::Point(Point::new(7, 1)),
+                &PieceMove::Point(Point::new(8, 1)),
+            ],
+        );
+    }
+}
+
+mod when_searching_for_the_best_move {
+    use super::*;
+
+    #[test]
+    fn it_captures_the_undefended_piece_of_any_enemy() {
+        let mut board = Board::empty(config_8x8());
+        let rook = add_piece(&mut board, "Rook", SOUTH, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", SOUTH, vec![], vec![], Point::new(8, 1));
+        add_piece(&mut board, "Queen", WEST, vec![], vec![], Point::new(1, 4));
+        add_piece(&mut board, "King", WEST, vec![], vec![], Point::new(8, 8));
+        add_piece(&mut board, "King", NORTH, vec![], vec![], Point::new(5, 8));
+        add_piece(&mut board, "King", EAST, vec![], vec![], Point::new(8, 5));
+
+        println!("{}", board.pp());
+        let result = Search::new(2).best_move(&board).unwrap();
+        assert_eq!(result.piece_id(), rook.id());
+        assert_eq!(result.piece_move(), &PieceMove::Point(Point::new(1, 4)));
+    }
+}
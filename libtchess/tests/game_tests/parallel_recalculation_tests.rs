@@ -0,0 +1,96 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::piece_id::PieceId;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::*;
+
+fn parallel_config(lazy_moves: bool) -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_parallel_recalculation_threshold(Some(1));
+    config.set_lazy_moves(lazy_moves);
+    config
+}
+
+// Picks a move in a deterministic way, so both boards make the same moves
+fn pick_move(board: &Board<TestHeatMap, TestSquaresMap>, ply: usize) -> (PieceId, PieceMove) {
+    let mut moves = vec![];
+    for (_, piece_to_moves) in board.score_to_moves(board.current_turn()) {
+        for (piece_id, piece_moves) in piece_to_moves {
+            for piece_move in piece_moves {
+                moves.push((*piece_id, *piece_move));
+            }
+        }
+    }
+    moves.sort();
+    moves[(ply * 5) % moves.len()]
+}
+
+fn assert_same_game(mut parallel_board: Board<TestHeatMap, TestSquaresMap>) {
+    let mut sequential_board = classic_8x8_prefilled();
+
+    for ply in 0..80 {
+        for color in [Color::White, Color::Black].iter() {
+            compare_and_assert(
+                &all_moves(&parallel_board, color),
+                &all_moves(&sequential_board, color),
+            );
+            assert_eq!(
+                parallel_board.has_no_moves(color),
+                sequential_board.has_no_moves(color)
+            );
+        }
+        if sequential_board.has_no_moves(sequential_board.current_turn()) {
+            break;
+        }
+        let (piece_id, piece_move) = pick_move(&sequential_board, ply);
+        move_piece(&mut sequential_board, piece_id, piece_move);
+        move_piece(&mut parallel_board, piece_id, piece_move);
+        println!("{}", parallel_board.pp());
+    }
+}
+
+mod when_comparing_with_sequential_recalculation {
+    use super::*;
+
+    #[test]
+    fn it_generates_the_same_moves() {
+        assert_same_game(classic_8x8_prefilled_with_config(parallel_config(false)));
+    }
+
+    #[test]
+    fn it_generates_the_same_moves_with_lazy_moves() {
+        assert_same_game(classic_8x8_prefilled_with_config(parallel_config(true)));
+    }
+
+    #[test]
+    fn it_calculates_the_same_strategy_points() {
+        let mut sequential_board = classic_8x8_prefilled();
+        let mut parallel_board = classic_8x8_prefilled_with_config(parallel_config(false));
+
+        for board in [&mut sequential_board, &mut parallel_board] {
+            move_piece_at(board, Point::new(4, 2), PieceMove::LongMove(Point::new(4, 4)));
+            move_piece_at(board, Point::new(5, 7), PieceMove::LongMove(Point::new(5, 5)));
+            move_piece_at(board, Point::new(4, 4), PieceMove::Point(Point::new(5, 5)));
+        }
+        println!("{}", parallel_board.pp());
+
+        for color in [Color::White, Color::Black].iter() {
+            for piece in parallel_board.active_pieces(color).values() {
+                assert_eq!(
+                    parallel_board.strategy_points(color).get_points(piece.id()),
+                    sequential_board.strategy_points(color).get_points(piece.id()),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::buff::Buff;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+fn config_5x7(pawn_initial_steps: u8) -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 7));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_pawn_initial_steps(pawn_initial_steps);
+    config
+}
+
+mod when_the_pawn_can_go_three_steps {
+    use super::*;
+
+    #[test]
+    fn it_can_stop_on_any_of_them() {
+        let mut board = Board::empty(config_5x7(3));
+        let pawn = add_piece(
+            &mut board,
+            "Pawn",
+            Color::White,
+            vec![Buff::AdditionalPoint],
+            vec![],
+            Point::new(3, 2),
+        );
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(3, 3)),
+                &PieceMove::LongMove(Point::new(3, 4)),
+                &PieceMove::LongMove(Point::new(3, 5)),
+            ],
+        );
+    }
+
+    #[test]
+    fn it_can_not_jump_over_the_piece() {
+        let mut board = Board::empty(config_5x7(3));
+        let pawn = add_piece(
+            &mut board,
+            "Pawn",
+            Color::White,
+            vec![Buff::AdditionalPoint],
+            vec![],
+            Point::new(3, 2),
+        );
+        add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(3, 4));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &vec![&PieceMove::Point(Point::new(3, 3))],
+        );
+    }
+
+    #[test]
+    fn it_goes_one_step_after_the_first_move() {
+        let mut board = Board::empty(config_5x7(3));
+        let pawn = add_piece(
+            &mut board,
+            "Pawn",
+            Color::White,
+            vec![Buff::AdditionalPoint],
+            vec![],
+            Point::new(3, 2),
+        );
+        let king = add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 7));
+        move_piece(&mut board, *pawn.id(), PieceMove::LongMove(Point::new(3, 5)));
+        move_piece_at(&mut board, *king.current_position(), PieceMove::Point(Point::new(1, 6)));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &vec![&PieceMove::Point(Point::new(3, 6))],
+        );
+    }
+}
+
+mod when_the_long_move_skips_several_points {
+    use super::*;
+
+    fn setup_board() -> Board<TestHeatMap, TestSquaresMap> {
+        let mut board = Board::empty(config_5x7(3));
+        board.pass_turn(&Color::Black);
+        add_piece(
+            &mut board,
+            "Pawn",
+            Color::Black,
+            vec![Buff::AdditionalPoint],
+            vec![],
+            Point::new(3, 6),
+        );
+        add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(2, 4));
+        add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(4, 3));
+        move_piece_at(&mut board, Point::new(3, 6), PieceMove::LongMove(Point::new(3, 3)));
+        println!("{}", board.pp());
+        board
+    }
+
+    #[test]
+    fn every_skipped_point_can_be_captured_en_passant() {
+        let board = setup_board();
+        let near_pawn = board.piece_id_at(&Point::new(2, 4)).unwrap();
+        let far_pawn = board.piece_id_at(&Point::new(4, 3)).unwrap();
+
+        assert!(
+            board
+                .moves_of(near_pawn)
+                .unwrap()
+                .contains_key(&PieceMove::EnPassant(Point::new(3, 5), Point::new(3, 3)))
+        );
+        assert!(
+            board
+                .moves_of(far_pawn)
+                .unwrap()
+                .contains_key(&PieceMove::EnPassant(Point::new(3, 4), Point::new(3, 3)))
+        );
+    }
+
+    #[test]
+    fn it_removes_the_pawn_which_made_the_long_move() {
+        let mut board = setup_board();
+        move_piece_at(
+            &mut board,
+            Point::new(2, 4),
+            PieceMove::EnPassant(Point::new(3, 5), Point::new(3, 3)),
+        );
+
+        println!("{}", board.pp());
+        assert!(board.piece_at(&Point::new(3, 3)).is_none());
+        assert_eq!(board.piece_at(&Point::new(3, 5)).unwrap().color(), &Color::White);
+        assert!(board.active_pieces(&Color::Black).is_empty());
+    }
+}
+
+mod when_the_long_move_checks_the_king {
+    use super::*;
+
+    #[test]
+    fn the_king_can_be_defended_by_capturing_en_passant_on_the_far_point() {
+        let mut board = Board::empty(config_5x7(3));
+        board.pass_turn(&Color::Black);
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(4, 2));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 7));
+        add_piece(
+            &mut board,
+            "Pawn",
+            Color::Black,
+            vec![Buff::AdditionalPoint],
+            vec![],
+            Point::new(3, 6),
+        );
+        let white_pawn =
+            add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(2, 4));
+        move_piece_at(&mut board, Point::new(3, 6), PieceMove::LongMove(Point::new(3, 3)));
+
+        println!("{}", board.pp());
+        assert!(board.is_checked(&Color::White));
+        compare_and_assert(
+            &board.moves_of(white_pawn.id()).to_vec(),
+            &vec![&PieceMove::EnPassant(Point::new(3, 5), Point::new(3, 3))],
+        );
+        move_piece_at(
+            &mut board,
+            white_pawn.current_position,
+            PieceMove::EnPassant(Point::new(3, 5), Point::new(3, 3)),
+        );
+        assert!(!board.is_checked(white_pawn.color()));
+    }
+}
@@ -0,0 +1,314 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::bitboard::BitBoard;
+use libtchess::board::Board;
+use libtchess::buff::Buff;
+use libtchess::castle_points::CastleSide;
+use libtchess::castle_rule::{CastleDestination, CastleRule};
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_id::PieceId;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::perft::perft;
+use libtchess::utils::pretty_print::PrettyPrint;
+use libtchess::variant::Variant;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::*;
+
+const KIWIPETE: [&str; 8] = [
+    "r...k..r", "p.ppqpb.", "bn..pnp.", "...PN...", ".p..P...", "..N..Q.p", "PPPBBPPP",
+    "R...K..R",
+];
+const ENDGAME: [&str; 8] = [
+    "........", "..p.....", "...p....", "KP.....r", ".R...p.k", "........", "....P.P.",
+    "........",
+];
+const RACING_KINGS: [&str; 8] = [
+    "........", "........", "........", "........", "........", "........", "krbnNBRK",
+    "qrbnNBRQ",
+];
+const PROMOTIONS: [&str; 8] = [
+    "r...k..r", "Pppp.ppp", ".b...nbN", "nP......", "BBP.P...", "q....N..", "Pp.P..PP",
+    "R..Q.RK.",
+];
+
+fn classic_dimension() -> Dimension {
+    Dimension::new(Point::new(1, 1), Point::new(8, 8))
+}
+
+fn classic_config() -> libtchess::board_config::BoardConfig<TestHeatMap, TestSquaresMap> {
+    board_config(
+        classic_dimension(),
+        TestSquaresMap::from_dimension(&classic_dimension()),
+    )
+}
+
+// Builds the board from its rows, listed from the 8th to the 1st one. Upper case letters are white
+// pieces, lower case letters are black pieces. Pawns on their initial rows can make a long move.
+// Kings on their initial squares and rooks in the corners of the same row can castle.
+fn board_from_rows<B: GameBoard<TestHeatMap, TestSquaresMap>>(rows: [&str; 8]) -> B {
+    board_from_rows_with_config(rows, classic_config())
+}
+
+fn board_from_rows_with_config<B: GameBoard<TestHeatMap, TestSquaresMap>>(
+    rows: [&str; 8],
+    config: libtchess::board_config::BoardConfig<TestHeatMap, TestSquaresMap>,
+) -> B {
+    let mut board = B::empty(config);
+    for (row_index, row) in rows.iter().enumerate() {
+        let y = 8 - row_index as i16;
+        for (column_index, symbol) in row.chars().enumerate() {
+            let x = column_index as i16 + 1;
+            let color = if symbol.is_uppercase() {
+                Color::White
+            } else {
+                Color::Black
+            };
+            let initial_row = if color == Color::White { 1 } else { 8 };
+            let pawns_row = if color == Color::White { 2 } else { 7 };
+            let king_is_at_initial_square =
+                rows[(8 - initial_row) as usize].chars().nth(4) == Some(if color == Color::White {
+                    'K'
+                } else {
+                    'k'
+                });
+            let (name, buffs) = match symbol.to_ascii_lowercase() {
+                'p' if y == pawns_row => ("Pawn", vec![Buff::AdditionalPoint]),
+                'p' => ("Pawn", vec![]),
+                'n' => ("Knight", vec![]),
+                'b' => ("Bishop", vec![]),
+                'r' if y == initial_row && (x == 1 || x == 8) && king_is_at_initial_square => {
+                    ("Rook", vec![Buff::Castle])
+                }
+                'r' => ("Rook", vec![]),
+                'q' => ("Queen", vec![]),
+                'k' if y == initial_row && x == 5 => ("King", vec![Buff::Castle]),
+                'k' => ("King", vec![]),
+                _ => continue,
+            };
+            board.add_piece(name, color, buffs, vec![], Point::new(x, y));
+        }
+    }
+    board
+}
+
+fn sorted_moves<B: GameBoard<TestHeatMap, TestSquaresMap>>(
+    board: &B,
+) -> Vec<(PieceId, PieceMove)> {
+    let mut moves = board.legal_moves(board.current_turn());
+    moves.sort();
+    moves
+}
+
+fn assert_boards_match(
+    board: &Board<TestHeatMap, TestSquaresMap>,
+    bitboard: &BitBoard<TestHeatMap, TestSquaresMap>,
+) {
+    assert_eq!(sorted_moves(board), sorted_moves(bitboard));
+    assert_eq!(board.stats().zposition, bitboard.stats().zposition);
+    assert_eq!(GameBoard::is_checked(board, &Color::White), bitboard.is_checked(&Color::White));
+    assert_eq!(GameBoard::is_checked(board, &Color::Black), bitboard.is_checked(&Color::Black));
+}
+
+mod when_counting_moves_from_the_initial_position {
+    use super::*;
+
+    #[test]
+    fn it_counts_moves_of_board() {
+        let board = classic_8x8_prefilled();
+        assert_eq!(perft(&board, 1), 20);
+        assert_eq!(perft(&board, 2), 400);
+        assert_eq!(perft(&board, 3), 8902);
+    }
+
+    #[test]
+    fn it_counts_moves_of_bitboard() {
+        let board: BitBoard<_, _> = classic_8x8_prefilled_game_board(classic_config());
+        println!("{}", board.pp());
+        assert_eq!(perft(&board, 1), 20);
+        assert_eq!(perft(&board, 2), 400);
+        assert_eq!(perft(&board, 3), 8902);
+        assert_eq!(perft(&board, 4), 197281);
+    }
+}
+
+mod when_counting_moves_of_positions_with_castles_en_passant_and_promotions {
+    use super::*;
+
+    #[test]
+    fn it_counts_moves_of_kiwipete_position() {
+        let board: Board<_, _> = board_from_rows(KIWIPETE);
+        let bitboard: BitBoard<_, _> = board_from_rows(KIWIPETE);
+        println!("{}", bitboard.pp());
+        assert_eq!(perft(&bitboard, 1), 48);
+        assert_eq!(perft(&bitboard, 2), 2039);
+        assert_eq!(perft(&bitboard, 3), 97862);
+        assert_eq!(perft(&board, 1), 48);
+        assert_eq!(perft(&board, 2), 2039);
+    }
+
+    #[test]
+    fn it_counts_moves_of_endgame_position() {
+        let board: Board<_, _> = board_from_rows(ENDGAME);
+        let bitboard: BitBoard<_, _> = board_from_rows(ENDGAME);
+        println!("{}", bitboard.pp());
+        assert_eq!(perft(&bitboard, 1), 14);
+        assert_eq!(perft(&bitboard, 2), 191);
+        assert_eq!(perft(&bitboard, 3), 2812);
+        assert_eq!(perft(&bitboard, 4), 43238);
+        assert_eq!(perft(&board, 3), 2812);
+    }
+
+    #[test]
+    fn it_counts_moves_of_promotions_position() {
+        let board: Board<_, _> = board_from_rows(PROMOTIONS);
+        let bitboard: BitBoard<_, _> = board_from_rows(PROMOTIONS);
+        println!("{}", bitboard.pp());
+        assert_eq!(perft(&bitboard, 1), 6);
+        assert_eq!(perft(&bitboard, 2), 264);
+        assert_eq!(perft(&bitboard, 3), 9467);
+        assert_eq!(perft(&board, 2), 264);
+    }
+}
+
+mod when_counting_moves_of_racing_kings {
+    use super::*;
+
+    // Every move is tried for giving check, which is done only for the side to move
+    #[test]
+    fn it_counts_moves_from_the_initial_position() {
+        let mut config = classic_config();
+        config.set_variant(Variant::RacingKings);
+        let board: Board<_, _> = board_from_rows_with_config(RACING_KINGS, config);
+        println!("{}", board.pp());
+        assert_eq!(perft(&board, 1), 21);
+        assert_eq!(perft(&board, 2), 421);
+        assert_eq!(perft(&board, 3), 11264);
+    }
+}
+
+mod when_cross_checking_board_and_bitboard {
+    use super::*;
+
+    #[test]
+    fn they_generate_the_same_moves_during_the_game() {
+        let mut board = classic_8x8_prefilled();
+        let mut bitboard: BitBoard<_, _> = classic_8x8_prefilled_game_board(classic_config());
+
+        for ply in 0..120 {
+            assert_boards_match(&board, &bitboard);
+            let moves = sorted_moves(&board);
+            if moves.is_empty() {
+                break;
+            }
+            let (piece_id, piece_move) = moves[(ply * 7) % moves.len()];
+            assert_eq!(
+                board.move_piece(&piece_id, &piece_move),
+                bitboard.move_piece(&piece_id, &piece_move)
+            );
+            assert_eq!(perft(&board, 2), perft(&bitboard, 2));
+        }
+        println!("{}", bitboard.pp());
+    }
+
+    #[test]
+    fn they_generate_the_same_moves_when_pawns_go_three_steps() {
+        let mut config = classic_config();
+        config.set_pawn_initial_steps(3);
+        let mut board: Board<_, _> = classic_8x8_prefilled_game_board(config.clone());
+        let mut bitboard: BitBoard<_, _> = classic_8x8_prefilled_game_board(config);
+
+        for ply in 0..60 {
+            assert_boards_match(&board, &bitboard);
+            let moves = sorted_moves(&board);
+            if moves.is_empty() {
+                break;
+            }
+            let (piece_id, piece_move) = moves[(ply * 5) % moves.len()];
+            assert_eq!(
+                board.move_piece(&piece_id, &piece_move),
+                bitboard.move_piece(&piece_id, &piece_move)
+            );
+        }
+        println!("{}", bitboard.pp());
+    }
+
+    #[test]
+    fn they_generate_the_same_castles_by_custom_rules() {
+        let mut config = classic_config();
+        let rule = |side, king, rook| {
+            CastleRule::new(side, CastleDestination::Distance { king, rook }, false)
+        };
+        config.set_castle_rules(vec![
+            rule(CastleSide::King, 2, 1),
+            rule(CastleSide::Queen, 2, 1),
+            rule(CastleSide::Queen, 3, 2),
+        ]);
+        let board: Board<_, _> = board_from_rows_with_config(KIWIPETE, config.clone());
+        let bitboard: BitBoard<_, _> = board_from_rows_with_config(KIWIPETE, config);
+        assert_boards_match(&board, &bitboard);
+        assert_eq!(perft(&bitboard, 1), 49);
+        assert_eq!(perft(&board, 2), perft(&bitboard, 2));
+    }
+
+    #[test]
+    fn they_generate_the_same_castles_when_the_king_and_the_rook_swap() {
+        let mut config = classic_config();
+        let rule = |side, king, rook| {
+            CastleRule::new(side, CastleDestination::Distance { king, rook }, false)
+        };
+        // The rook lands on the initial point of the king, the king lands next to the rook or on
+        // its initial point
+        config.set_castle_rules(vec![
+            rule(CastleSide::King, 2, 0),
+            rule(CastleSide::Queen, 4, 0),
+        ]);
+        let board: Board<_, _> = board_from_rows_with_config(KIWIPETE, config.clone());
+        let bitboard: BitBoard<_, _> = board_from_rows_with_config(KIWIPETE, config);
+        assert_boards_match(&board, &bitboard);
+        for (piece_id, piece_move) in sorted_moves(&board) {
+            let mut next_board = board.clone();
+            let mut next_bitboard = bitboard.clone();
+            next_board.move_piece(&piece_id, &piece_move);
+            next_bitboard.move_piece(&piece_id, &piece_move);
+            assert_boards_match(&next_board, &next_bitboard);
+        }
+        assert_eq!(perft(&board, 2), perft(&bitboard, 2));
+    }
+
+    #[test]
+    fn they_generate_the_same_moves_in_kiwipete_position() {
+        let board: Board<_, _> = board_from_rows(KIWIPETE);
+        let bitboard: BitBoard<_, _> = board_from_rows(KIWIPETE);
+        for (piece_id, piece_move) in sorted_moves(&board) {
+            let mut next_board = board.clone();
+            let mut next_bitboard = bitboard.clone();
+            next_board.move_piece(&piece_id, &piece_move);
+            next_bitboard.move_piece(&piece_id, &piece_move);
+            assert_boards_match(&next_board, &next_bitboard);
+        }
+    }
+}
+
+mod when_the_move_is_illegal {
+    use super::*;
+
+    #[test]
+    fn bitboard_does_not_make_it() {
+        let mut bitboard: BitBoard<_, _> = classic_8x8_prefilled_game_board(classic_config());
+        let pawn_id = *bitboard.piece_id_at(&Point::new(5, 7)).unwrap();
+        assert_eq!(
+            bitboard.move_piece(&pawn_id, &PieceMove::Point(Point::new(5, 6))),
+            None
+        );
+        let knight_id = *bitboard.piece_id_at(&Point::new(2, 1)).unwrap();
+        assert_eq!(
+            bitboard.move_piece(&knight_id, &PieceMove::Point(Point::new(4, 2))),
+            None
+        );
+    }
+}
@@ -843,3 +843,22 @@ mod pinning_a_piece_on_cloned_boards {
         move_piece_at(&mut next_pos, Point::new(2, 5), PieceMove::Point(Point::new(4, 7)));
     }
 }
+
+mod placing_the_king_behind_a_piece_on_the_x_ray_of_the_enemy_rook {
+    use super::*;
+
+    #[test]
+    fn it_pins_the_piece() {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+        let config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+        let mut board = Board::empty(config);
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 1));
+        let knight =
+            add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(1, 3));
+        // X-rays of the rook are calculated once the king appears on the board
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 4));
+
+        println!("{}", board.pp());
+        assert_eq!(board.moves_of(knight.id()).to_vec(), Vec::<&PieceMove>::new());
+    }
+}
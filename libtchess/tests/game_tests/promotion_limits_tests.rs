@@ -0,0 +1,191 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::promote_piece::PromotePiece;
+use libtchess::promotion_zone::PromotionZone;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+// Pawns promote to the queen or to the rook on the last three ranks, and may stay unpromoted
+// before the last rank
+fn config_6x6() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(6, 6));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_promotion_zone(&Color::White, PromotionZone::Ranks(4..=6));
+    config.set_promotion_zone(&Color::Black, PromotionZone::Ranks(1..=3));
+    config.set_promote_pieces(vec![PromotePiece::Rook, PromotePiece::Queen]);
+    config.set_optional_pawn_promotions(true);
+    config
+}
+
+mod when_promotions_are_optional {
+    use super::*;
+
+    #[test]
+    fn the_pawn_can_stay_unpromoted_before_the_last_rank() {
+        let mut board = Board::empty(config_6x6());
+        let pawn = add_piece(
+            &mut board,
+            "Pawn",
+            Color::White,
+            vec![],
+            vec![],
+            Point::new(3, 3),
+        );
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(3, 4)),
+                &PieceMove::Promote(Point::new(3, 4), PromotePiece::Rook),
+                &PieceMove::Promote(Point::new(3, 4), PromotePiece::Queen),
+            ],
+        );
+    }
+
+    #[test]
+    fn the_pawn_must_promote_on_the_last_rank() {
+        let mut board = Board::empty(config_6x6());
+        let pawn = add_piece(
+            &mut board,
+            "Pawn",
+            Color::Black,
+            vec![],
+            vec![],
+            Point::new(3, 2),
+        );
+        add_piece(
+            &mut board,
+            "Knight",
+            Color::White,
+            vec![],
+            vec![],
+            Point::new(2, 1),
+        );
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &vec![
+                &PieceMove::Promote(Point::new(3, 1), PromotePiece::Rook),
+                &PieceMove::Promote(Point::new(3, 1), PromotePiece::Queen),
+                &PieceMove::Promote(Point::new(2, 1), PromotePiece::Rook),
+                &PieceMove::Promote(Point::new(2, 1), PromotePiece::Queen),
+            ],
+        );
+    }
+}
+
+mod when_promotions_are_limited {
+    use super::*;
+
+    #[test]
+    fn the_pawn_cant_promote_to_the_piece_the_side_has_enough_of() {
+        let mut config = config_6x6();
+        config.set_promotion_limit("Queen", 1);
+        let mut board = Board::empty(config);
+        let pawn = add_piece(
+            &mut board,
+            "Pawn",
+            Color::White,
+            vec![],
+            vec![],
+            Point::new(3, 5),
+        );
+        add_piece(
+            &mut board,
+            "Queen",
+            Color::White,
+            vec![],
+            vec![],
+            Point::new(6, 1),
+        );
+        add_piece(
+            &mut board,
+            "Queen",
+            Color::Black,
+            vec![],
+            vec![],
+            Point::new(1, 1),
+        );
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &vec![&PieceMove::Promote(Point::new(3, 6), PromotePiece::Rook)],
+        );
+    }
+
+    #[test]
+    fn the_pawn_can_promote_to_the_piece_once_it_is_captured() {
+        let mut config = config_6x6();
+        config.set_promotion_limit("Queen", 1);
+        config.set_promotion_limit("Rook", 1);
+        let mut board = Board::empty(config);
+        let pawn = add_piece(
+            &mut board,
+            "Pawn",
+            Color::White,
+            vec![],
+            vec![],
+            Point::new(3, 5),
+        );
+        let queen = add_piece(
+            &mut board,
+            "Queen",
+            Color::White,
+            vec![],
+            vec![],
+            Point::new(6, 1),
+        );
+        add_piece(
+            &mut board,
+            "Rook",
+            Color::White,
+            vec![],
+            vec![],
+            Point::new(5, 2),
+        );
+        add_piece(
+            &mut board,
+            "Queen",
+            Color::Black,
+            vec![],
+            vec![],
+            Point::new(1, 1),
+        );
+
+        // The pawn has nowhere to go until the queen is captured
+        assert!(board.moves_of(pawn.id()).to_vec().is_empty());
+        move_piece_at(
+            &mut board,
+            Point::new(5, 2),
+            PieceMove::Point(Point::new(5, 3)),
+        );
+        move_piece_at(
+            &mut board,
+            Point::new(1, 1),
+            PieceMove::Point(*queen.current_position()),
+        );
+
+        println!("{}", board.pp());
+        assert_ne!(
+            board.piece_at(&Point::new(6, 1)).unwrap().color(),
+            queen.color()
+        );
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &vec![&PieceMove::Promote(Point::new(3, 6), PromotePiece::Queen)],
+        );
+    }
+}
@@ -0,0 +1,174 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::buff::Buff;
+use libtchess::collections::HashSet;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::promote_piece::PromotePiece;
+use libtchess::promotion_zone::PromotionZone;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+fn config_5x5() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+    board_config(dimension, TestSquaresMap::from_dimension(&dimension))
+}
+
+fn promotions(point: Point, promote_pieces: &[PromotePiece]) -> Vec<PieceMove> {
+    promote_pieces
+        .iter()
+        .map(|promote_piece| PieceMove::Promote(point, *promote_piece))
+        .collect()
+}
+
+mod when_the_zone_is_a_range_of_ranks {
+    use super::*;
+
+    #[test]
+    fn it_promotes_the_pawn_before_the_last_rank() {
+        let mut config = config_5x5();
+        config.set_promotion_zone(&Color::White, PromotionZone::Ranks(4..=5));
+        let mut board = Board::empty(config);
+        let pawn = add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(3, 3));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &promotions(Point::new(3, 4), &PromotePiece::all_variants()[..4])
+                .iter()
+                .collect(),
+        );
+    }
+
+    #[test]
+    fn it_promotes_the_pawn_making_the_long_move_into_the_zone() {
+        let mut config = config_5x5();
+        config.set_promotion_zone(&Color::White, PromotionZone::Ranks(4..=5));
+        let mut board = Board::empty(config);
+        let pawn = add_piece(
+            &mut board,
+            "Pawn",
+            Color::White,
+            vec![Buff::AdditionalPoint],
+            vec![],
+            Point::new(3, 2),
+        );
+
+        println!("{}", board.pp());
+        let mut expected = promotions(Point::new(3, 4), &PromotePiece::all_variants()[..4]);
+        expected.push(PieceMove::Point(Point::new(3, 3)));
+        compare_and_assert(&board.moves_of(pawn.id()).to_vec(), &expected.iter().collect());
+
+        let promote_move = PieceMove::Promote(Point::new(3, 4), PromotePiece::Queen);
+        move_piece(&mut board, *pawn.id(), promote_move);
+        println!("{}", board.pp());
+        assert_eq!(board.piece_at(&Point::new(3, 4)).unwrap().name(), "Queen");
+    }
+
+    #[test]
+    fn it_does_not_affect_the_other_side() {
+        let mut config = config_5x5();
+        config.set_promotion_zone(&Color::White, PromotionZone::Ranks(2..=5));
+        let mut board = Board::empty(config);
+        let pawn = add_piece(&mut board, "Pawn", Color::Black, vec![], vec![], Point::new(3, 4));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &vec![&PieceMove::Point(Point::new(3, 3))],
+        );
+    }
+}
+
+mod when_the_zone_is_a_set_of_squares {
+    use super::*;
+
+    #[test]
+    fn it_promotes_the_pawn_on_the_given_squares_only() {
+        let mut config = config_5x5();
+        let squares = HashSet::from(vec![Point::new(2, 2)]);
+        config.set_promotion_zone(&Color::Black, PromotionZone::Squares(squares));
+        let mut board = Board::empty(config);
+        let pawn = add_piece(&mut board, "Pawn", Color::Black, vec![], vec![], Point::new(3, 3));
+        add_piece(&mut board, "Knight", Color::White, vec![], vec![], Point::new(2, 2));
+
+        println!("{}", board.pp());
+        let mut expected = promotions(Point::new(2, 2), &PromotePiece::all_variants()[..4]);
+        expected.push(PieceMove::Point(Point::new(3, 2)));
+        compare_and_assert(&board.moves_of(pawn.id()).to_vec(), &expected.iter().collect());
+    }
+}
+
+mod when_the_zone_comes_from_the_squares_map {
+    use super::*;
+
+    #[test]
+    fn it_promotes_the_pawn_on_the_squares_marked_by_the_map() {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+        let mut squares_map = TestSquaresMap::from_dimension(&dimension);
+        squares_map.add_promotion_square(Point::new(3, 4), Color::White);
+        let mut config = board_config(dimension, squares_map);
+        config.set_promotion_zone(&Color::White, PromotionZone::SquaresMap);
+        let mut board = Board::empty(config);
+        let pawn = add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(3, 3));
+        let other_pawn =
+            add_piece(&mut board, "Pawn", *pawn.color(), vec![], vec![], Point::new(2, 3));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &promotions(Point::new(3, 4), &PromotePiece::all_variants()[..4])
+                .iter()
+                .collect(),
+        );
+        // The square next to the marked one is not a promotion square
+        let next_square = Point::new(*other_pawn.current_position().x().value(), 4);
+        compare_and_assert(
+            &board.moves_of(other_pawn.id()).to_vec(),
+            &vec![&PieceMove::Point(next_square)],
+        );
+    }
+}
+
+mod when_promote_pieces_are_limited {
+    use super::*;
+
+    #[test]
+    fn it_promotes_the_pawn_to_the_given_pieces_only() {
+        let mut config = config_5x5();
+        config.set_promote_pieces(vec![PromotePiece::Queen]);
+        let mut board = Board::empty(config);
+        let pawn = add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(3, 4));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(pawn.id()).to_vec(),
+            &vec![&PieceMove::Promote(Point::new(3, 5), PromotePiece::Queen)],
+        );
+    }
+
+    #[test]
+    fn it_rejects_the_promotion_to_other_pieces() {
+        let mut config = config_5x5();
+        config.set_promote_pieces(vec![PromotePiece::Queen]);
+        let mut board = Board::empty(config);
+        let pawn = add_piece(&mut board, "Pawn", Color::White, vec![], vec![], Point::new(3, 4));
+
+        println!("{}", board.pp());
+        assert!(board
+            .move_piece(
+                pawn.id(),
+                &PieceMove::Promote(Point::new(3, 5), PromotePiece::Knight)
+            )
+            .is_none());
+        assert!(board.piece_at(&pawn.current_position).is_some());
+    }
+}
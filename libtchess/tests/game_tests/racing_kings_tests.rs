@@ -0,0 +1,94 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use libtchess::variant::Variant;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+fn config_8x8() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_variant(Variant::RacingKings);
+    config
+}
+
+mod when_the_move_gives_check {
+    use super::*;
+
+    #[test]
+    fn it_is_not_allowed() {
+        let mut board = Board::empty(config_8x8());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(8, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 8));
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(2, 2));
+
+        println!("{}", board.pp());
+        let moves_of_rook = board.moves_of(rook.id());
+        let moves = moves_of_rook.to_vec();
+        assert!(!moves.contains(&&PieceMove::Point(Point::new(1, 2))));
+        assert!(!moves.contains(&&PieceMove::Point(Point::new(2, 8))));
+        assert!(moves.contains(&&PieceMove::Point(Point::new(2, 7))));
+        assert!(board.move_piece(rook.id(), &PieceMove::Point(Point::new(2, 8))).is_none());
+    }
+
+    #[test]
+    fn the_discovered_check_is_not_allowed_as_well() {
+        let mut board = Board::empty(config_8x8());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(8, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 8));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 1));
+        let knight = add_piece(&mut board, "Knight", Color::White, vec![], vec![], Point::new(1, 4));
+
+        println!("{}", board.pp());
+        assert_eq!(board.moves_of(knight.id()).to_vec(), Vec::<&PieceMove>::new());
+    }
+}
+
+mod when_the_turn_passes_to_the_other_side {
+    use super::*;
+
+    #[test]
+    fn its_checks_are_not_allowed_as_well() {
+        let mut board = Board::empty(config_8x8());
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(8, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 8));
+        let rook = add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(7, 7));
+        board.pass_turn(&Color::Black);
+
+        println!("{}", board.pp());
+        let moves_of_rook = board.moves_of(rook.id());
+        let moves = moves_of_rook.to_vec();
+        assert!(!moves.contains(&&PieceMove::Point(Point::new(7, 1))));
+        assert!(!moves.contains(&&PieceMove::Point(Point::new(8, 7))));
+        assert!(moves.contains(&&PieceMove::Point(Point::new(7, 2))));
+    }
+}
+
+mod when_the_king_races {
+    use super::*;
+
+    #[test]
+    fn it_reaches_the_last_rank() {
+        let mut config = config_8x8();
+        config.set_hill_squares((1..=8).map(|x| Point::new(x, 8)).collect());
+        let mut board = Board::empty(config);
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(8, 7));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 1));
+
+        move_piece(&mut board, *king.id(), PieceMove::Point(Point::new(8, 8)));
+        println!("{}", board.pp());
+        assert_eq!(board.piece_at(&Point::new(8, 8)).unwrap().id(), king.id());
+        assert!(board.is_king_on_the_hill(king.color()));
+        assert!(board.piece_at(&king.current_position).is_none());
+        assert!(board.piece_at(king.current_position()).is_none());
+    }
+}
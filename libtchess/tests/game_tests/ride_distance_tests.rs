@@ -0,0 +1,150 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::betza::BetzaPiece;
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_move::PieceMove;
+use libtchess::piece_registry::PieceRegistry;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+fn config_8x8() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    board_config(dimension, TestSquaresMap::from_dimension(&dimension))
+}
+
+mod when_the_ride_distance_of_the_rook_is_limited {
+    use super::*;
+
+    #[test]
+    fn it_moves_within_the_distance_only() {
+        let mut config = config_8x8();
+        config.set_max_ride_distance("Rook", 2);
+        let mut board = Board::empty(config);
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 1));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(rook.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(1, 2)),
+                &PieceMove::Point(Point::new(1, 3)),
+                &PieceMove::Point(Point::new(2, 1)),
+                &PieceMove::Point(Point::new(3, 1)),
+            ],
+        );
+    }
+
+    #[test]
+    fn it_does_not_check_the_king_out_of_its_reach() {
+        let mut config = config_8x8();
+        config.set_max_ride_distance("Rook", 2);
+        let mut board = Board::empty(config);
+        let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 1));
+        // The king is three points away from the rook
+        let king_position = Point::new(1, rook.current_position().y().value() + 3);
+        add_piece(&mut board, "King", rook.color().inverse(), vec![], vec![], king_position);
+
+        println!("{}", board.pp());
+        assert!(!board.is_checked(&Color::Black));
+    }
+
+    #[test]
+    fn it_does_not_affect_other_pieces() {
+        let mut config = config_8x8();
+        config.set_max_ride_distance("Rook", 2);
+        let mut board = Board::empty(config);
+        add_piece(&mut board, "Queen", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 8));
+
+        println!("{}", board.pp());
+        assert!(board.is_checked(&Color::Black));
+    }
+}
+
+mod when_the_limited_rook_x_rays_the_king {
+    use super::*;
+
+    #[test]
+    fn it_pins_the_piece_when_the_king_is_within_the_distance() {
+        let mut config = config_8x8();
+        config.set_max_ride_distance("Rook", 3);
+        let mut board = Board::empty(config);
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 1));
+        let knight = add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(1, 3));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 4));
+
+        println!("{}", board.pp());
+        assert_eq!(board.moves_of(knight.id()).to_vec(), Vec::<&PieceMove>::new());
+    }
+
+    #[test]
+    fn it_does_not_pin_the_piece_when_the_king_is_out_of_reach() {
+        let mut config = config_8x8();
+        config.set_max_ride_distance("Rook", 3);
+        let mut board = Board::empty(config);
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 1));
+        let knight = add_piece(&mut board, "Knight", Color::Black, vec![], vec![], Point::new(1, 3));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 5));
+
+        println!("{}", board.pp());
+        assert_eq!(board.moves_of(knight.id()).to_vec().len(), 4);
+    }
+}
+
+mod when_the_limited_queen_checks_the_king {
+    use super::*;
+
+    #[test]
+    fn the_check_can_be_blocked_on_the_way() {
+        let mut config = config_8x8();
+        config.set_max_ride_distance("Queen", 2);
+        let mut board = Board::empty(config);
+        board.pass_turn(&Color::Black);
+        add_piece(&mut board, "Queen", Color::White, vec![], vec![], Point::new(3, 3));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(5, 5));
+        let rook = add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(8, 4));
+
+        println!("{}", board.pp());
+        assert!(board.is_checked(&Color::Black));
+        compare_and_assert(
+            &board.moves_of(rook.id()).to_vec(),
+            &vec![&PieceMove::Point(Point::new(4, *rook.current_position.y().value()))],
+        );
+    }
+}
+
+mod when_the_ride_distance_of_the_custom_piece_is_limited {
+    use super::*;
+
+    #[test]
+    fn it_limits_the_riding_movements() {
+        let mut config = config_8x8();
+        let mut piece_registry = PieceRegistry::classic();
+        piece_registry
+            .register(BetzaPiece::new("Nightrider", ColoredProperty::from(['X', 'x']), 500, "NN").unwrap());
+        config.set_piece_registry(piece_registry);
+        config.set_max_ride_distance("Nightrider", 1);
+        let mut board = Board::empty(config);
+        let nightrider =
+            add_piece(&mut board, "Nightrider", Color::White, vec![], vec![], Point::new(1, 1));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(nightrider.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(2, 3)),
+                &PieceMove::Point(Point::new(3, 2)),
+            ],
+        );
+    }
+}
@@ -0,0 +1,171 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::royal_rule::RoyalRule;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::traits::ToVecRef;
+use support::*;
+
+fn config_8x8(royal_rule: RoyalRule) -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    config.set_royal_rule(royal_rule);
+    config
+}
+
+mod when_any_of_two_kings_can_be_mated {
+    use super::*;
+
+    #[test]
+    fn the_piece_is_pinned_to_any_of_them() {
+        let mut board = Board::empty(config_8x8(RoyalRule::AnyMated));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(8, 1));
+        let knight = add_piece(&mut board, "Knight", Color::White, vec![], vec![], Point::new(8, 4));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(8, 8));
+
+        println!("{}", board.pp());
+        assert_eq!(board.moves_of(knight.id()).to_vec(), Vec::<&PieceMove>::new());
+    }
+
+    #[test]
+    fn the_check_to_any_of_them_must_be_resolved() {
+        let mut board = Board::empty(config_8x8(RoyalRule::AnyMated));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(8, 1));
+        let knight = add_piece(&mut board, "Knight", Color::White, vec![], vec![], Point::new(6, 2));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(8, 8));
+
+        println!("{}", board.pp());
+        assert!(board.is_checked(king.color()));
+        compare_and_assert(
+            &board.moves_of(knight.id()).to_vec(),
+            &vec![&PieceMove::Point(Point::new(8, 3))],
+        );
+    }
+
+    #[test]
+    fn the_king_does_not_retreat_along_the_checking_line() {
+        let mut board = Board::empty(config_8x8(RoyalRule::AnyMated));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(8, 2));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(8, 8));
+
+        println!("{}", board.pp());
+        compare_and_assert(
+            &board.moves_of(king.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(7, 1)),
+                &PieceMove::Point(Point::new(7, 2)),
+                &PieceMove::Point(Point::new(7, 3)),
+            ],
+        );
+    }
+}
+
+mod when_all_kings_must_be_captured {
+    use super::*;
+
+    fn setup_board() -> Board<TestHeatMap, TestSquaresMap> {
+        let mut board = Board::empty(config_8x8(RoyalRule::AllCaptured));
+        board.pass_turn(&Color::Black);
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(8, 1));
+        add_piece(&mut board, "Knight", Color::White, vec![], vec![], Point::new(1, 3));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(8, 8));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(1, 8));
+        board
+    }
+
+    #[test]
+    fn the_king_can_be_captured_while_there_is_another_one() {
+        let mut board = setup_board();
+
+        println!("{}", board.pp());
+        assert!(!board.is_checked(&Color::White));
+        move_piece_at(&mut board, Point::new(8, 8), PieceMove::Point(Point::new(8, 1)));
+        assert_eq!(board.royals(&Color::White).len(), 1);
+        assert!(!board.is_out_of_royals(&Color::White));
+    }
+
+    #[test]
+    fn the_last_king_is_protected_from_check() {
+        let mut board = setup_board();
+        move_piece_at(&mut board, Point::new(8, 8), PieceMove::Point(Point::new(8, 1)));
+
+        println!("{}", board.pp());
+        assert!(board.is_checked(&Color::White));
+        let knight = board.piece_at(&Point::new(1, 3)).unwrap();
+        // The knight is pinned by the other rook and can't capture the checking one
+        assert_eq!(board.moves_of(knight.id()).to_vec(), Vec::<&PieceMove>::new());
+    }
+}
+
+mod when_the_other_piece_is_royal {
+    use super::*;
+
+    fn config() -> BoardConfig<TestHeatMap, TestSquaresMap> {
+        let mut config = config_8x8(RoyalRule::AnyMated);
+        config.set_royal_pieces(&["Queen"]);
+        config
+    }
+
+    #[test]
+    fn it_does_not_move_under_attack() {
+        let mut board = Board::empty(config());
+        let queen = add_piece(&mut board, "Queen", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(2, 8));
+
+        println!("{}", board.pp());
+        let moves = board.moves_of(queen.id()).unwrap();
+        assert!(moves.contains_key(&PieceMove::Point(Point::new(1, 2))));
+        assert!(moves.contains_key(&PieceMove::Point(Point::new(3, 3))));
+        // The rook attacks the whole column next to the queen
+        let attacked_x = queen.current_position().x().value() + 1;
+        assert!(moves.keys().all(|piece_move| *piece_move.destination().x().value() != attacked_x));
+    }
+
+    #[test]
+    fn the_check_can_be_blocked() {
+        let mut board = Board::empty(config());
+        let queen = add_piece(&mut board, "Queen", Color::White, vec![], vec![], Point::new(1, 1));
+        let bishop = add_piece(&mut board, "Bishop", Color::White, vec![], vec![], Point::new(3, 3));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(1, 8));
+
+        println!("{}", board.pp());
+        assert!(board.is_checked(queen.color()));
+        compare_and_assert(
+            &board.moves_of(bishop.id()).to_vec(),
+            &vec![&PieceMove::Point(Point::new(1, 5))],
+        );
+    }
+
+    #[test]
+    fn the_king_is_not_royal_anymore() {
+        let mut board = Board::empty(config());
+        let king = add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "Rook", Color::Black, vec![], vec![], Point::new(1, 8));
+
+        println!("{}", board.pp());
+        assert!(!board.is_checked(&Color::White));
+        assert!(board.royals(king.color()).is_empty());
+        assert!(board.king(&Color::White).is_none());
+        assert_eq!(board.piece_at(&king.current_position).unwrap().name(), "King");
+        compare_and_assert(
+            &board.moves_of(king.id()).to_vec(),
+            &vec![
+                &PieceMove::Point(Point::new(2, 1)),
+                &PieceMove::Point(Point::new(2, 2)),
+            ],
+        );
+    }
+}
@@ -0,0 +1,108 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::search::Search;
+use libtchess::utils::pretty_print::PrettyPrint;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::*;
+
+fn board_5x5() -> Board<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(5, 5));
+    Board::empty(board_config(dimension, TestSquaresMap::from_dimension(&dimension)))
+}
+
+mod when_there_is_mate_in_one {
+    use super::*;
+
+    fn setup_board() -> Board<TestHeatMap, TestSquaresMap> {
+        let mut board = board_5x5();
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(3, 1));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 5));
+        add_piece(&mut board, "Pawn", Color::Black, vec![], vec![], Point::new(1, 4));
+        add_piece(&mut board, "Pawn", Color::Black, vec![], vec![], Point::new(2, 4));
+        board
+    }
+
+    #[test]
+    fn it_finds_the_mate() {
+        let board = setup_board();
+        println!("{}", board.pp());
+
+        let result = Search::new(3).best_move(&board).unwrap();
+        assert_eq!(board.piece_at(&Point::new(5, 1)).unwrap().id(), result.piece_id());
+        assert_eq!(result.piece_move(), &PieceMove::Point(Point::new(5, 5)));
+    }
+
+    #[test]
+    fn it_finds_the_mate_using_several_threads() {
+        let board = setup_board();
+        println!("{}", board.pp());
+
+        let mut search = Search::new(3);
+        search.set_threads_number(4);
+        let result = search.best_move(&board).unwrap();
+        assert_eq!(board.piece_at(&Point::new(5, 1)).unwrap().id(), result.piece_id());
+        assert_eq!(result.piece_move(), &PieceMove::Point(Point::new(5, 5)));
+    }
+}
+
+mod when_there_is_undefended_piece {
+    use super::*;
+
+    fn setup_board() -> Board<TestHeatMap, TestSquaresMap> {
+        let mut board = board_5x5();
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 1));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(5, 5));
+        add_piece(&mut board, "Queen", Color::Black, vec![], vec![], Point::new(1, 4));
+        board
+    }
+
+    #[test]
+    fn it_captures_it() {
+        let board = setup_board();
+        println!("{}", board.pp());
+
+        let result = Search::new(2).best_move(&board).unwrap();
+        assert_eq!(board.piece_at(&Point::new(1, 1)).unwrap().id(), result.piece_id());
+        assert_eq!(result.piece_move(), &PieceMove::Point(Point::new(1, 4)));
+    }
+}
+
+mod when_there_are_no_moves {
+    use super::*;
+
+    #[test]
+    fn it_returns_nothing() {
+        let mut board = board_5x5();
+        add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+        add_piece(&mut board, "Queen", Color::White, vec![], vec![], Point::new(3, 4));
+        add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(1, 5));
+        board.pass_turn(&Color::Black);
+        println!("{}", board.pp());
+
+        assert_eq!(Search::new(2).best_move(&board), None);
+    }
+}
+
+mod when_searching_the_same_position_twice {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_same_result_in_single_thread() {
+        let mut board = classic_8x8_prefilled();
+        move_piece_at(&mut board, Point::new(5, 2), PieceMove::LongMove(Point::new(5, 4)));
+        println!("{}", board.pp());
+
+        let search = Search::new(2);
+        assert_eq!(search.best_move(&board), search.best_move(&board));
+        assert_eq!(search.best_move(&board), Search::new(2).best_move(&board));
+    }
+}
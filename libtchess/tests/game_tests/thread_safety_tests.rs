@@ -0,0 +1,57 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::color::Color;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use std::thread;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::*;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn board_is_send_and_sync() {
+    assert_send_sync::<Board<TestHeatMap, TestSquaresMap>>();
+}
+
+mod when_moving_the_board_to_another_thread {
+    use super::*;
+
+    #[test]
+    fn it_calculates_the_same_moves() {
+        let mut board = classic_8x8_prefilled();
+        let board_copy = board.clone();
+        move_piece_at(&mut board, Point::new(5, 2), PieceMove::LongMove(Point::new(5, 4)));
+        println!("{}", board.pp());
+
+        let moves = thread::spawn(move || {
+            let mut board = board_copy;
+            move_piece_at(&mut board, Point::new(5, 2), PieceMove::LongMove(Point::new(5, 4)));
+            all_moves(&board, &Color::Black)
+        })
+        .join()
+        .unwrap();
+
+        compare_and_assert(&moves, &all_moves(&board, &Color::Black));
+    }
+
+    #[test]
+    fn it_shares_the_board_between_threads() {
+        let mut board = classic_8x8_prefilled();
+        move_piece_at(&mut board, Point::new(5, 2), PieceMove::LongMove(Point::new(5, 4)));
+        let expected = all_moves(&board, &Color::Black);
+
+        thread::scope(|scope| {
+            let handles = (0..4)
+                .map(|_| scope.spawn(|| all_moves(&board, &Color::Black)))
+                .collect::<Vec<_>>();
+            for handle in handles {
+                compare_and_assert(&handle.join().unwrap(), &expected);
+            }
+        });
+    }
+}
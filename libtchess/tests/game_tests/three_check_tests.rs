@@ -0,0 +1,88 @@
+#[path = "../support/mod.rs"]
+mod support;
+
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::piece_move::PieceMove;
+use libtchess::point::Point;
+use libtchess::utils::pretty_print::PrettyPrint;
+use libtchess::variant::Variant;
+use support::test_heat_map::TestHeatMap;
+use support::test_squares_map::TestSquaresMap;
+use support::*;
+
+fn config_8x8(check_limit: Option<u8>) -> BoardConfig<TestHeatMap, TestSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+    let mut config = board_config(dimension, TestSquaresMap::from_dimension(&dimension));
+    if let Some(check_limit) = check_limit {
+        config.set_variant(Variant::CheckLimit(check_limit));
+    }
+    config
+}
+
+// White rook checks the black king from a8 and returns back to a1, the king steps aside and
+// returns back as well
+fn check_and_return(board: &mut Board<TestHeatMap, TestSquaresMap>) {
+    move_piece_at(board, Point::new(1, 1), PieceMove::Point(Point::new(1, 8)));
+    move_piece_at(board, Point::new(8, 8), PieceMove::Point(Point::new(8, 7)));
+    move_piece_at(board, Point::new(1, 8), PieceMove::Point(Point::new(1, 1)));
+    move_piece_at(board, Point::new(8, 7), PieceMove::Point(Point::new(8, 8)));
+}
+
+fn board_with_rook(check_limit: Option<u8>) -> (Board<TestHeatMap, TestSquaresMap>, PieceRepr) {
+    let mut board = Board::empty(config_8x8(check_limit));
+    add_piece(&mut board, "King", Color::White, vec![], vec![], Point::new(5, 1));
+    let rook = add_piece(&mut board, "Rook", Color::White, vec![], vec![], Point::new(1, 1));
+    add_piece(&mut board, "King", Color::Black, vec![], vec![], Point::new(8, 8));
+    (board, rook)
+}
+
+mod when_the_checks_are_limited {
+    use super::*;
+
+    #[test]
+    fn the_checks_are_counted() {
+        let (mut board, rook) = board_with_rook(Some(2));
+
+        check_and_return(&mut board);
+        println!("{}", board.pp());
+        assert_eq!(board.stats().checks_given[&Color::White], 1);
+        assert_eq!(board.stats().checks_given[&Color::Black], 0);
+        assert!(!board.is_check_limit_reached(rook.color()));
+
+        check_and_return(&mut board);
+        assert_eq!(board.stats().checks_given[&Color::White], 2);
+        assert!(board.is_check_limit_reached(rook.color()));
+        assert!(!board.is_check_limit_reached(&Color::Black));
+        assert_eq!(board.piece_at(rook.current_position()).unwrap().id(), rook.id());
+    }
+
+    #[test]
+    fn the_number_of_checks_is_a_part_of_the_position() {
+        let (mut board, rook) = board_with_rook(Some(3));
+        let zposition = *board.stats().zposition;
+
+        check_and_return(&mut board);
+        println!("{}", board.pp());
+        assert_eq!(board.piece_at(&rook.current_position).unwrap().id(), rook.id());
+        assert_ne!(*board.stats().zposition, zposition);
+    }
+}
+
+mod when_the_checks_are_not_limited {
+    use super::*;
+
+    #[test]
+    fn the_checks_are_not_counted() {
+        let (mut board, _) = board_with_rook(None);
+        let zposition = *board.stats().zposition;
+
+        check_and_return(&mut board);
+        println!("{}", board.pp());
+        assert_eq!(board.stats().checks_given[&Color::White], 0);
+        assert!(!board.is_check_limit_reached(&Color::White));
+        assert_eq!(*board.stats().zposition, zposition);
+    }
+}
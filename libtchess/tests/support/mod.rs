@@ -15,6 +15,7 @@ use libtchess::castle_x_points::{CastleXPoints, KingCastleXPoint, RookCastleXPoi
 use libtchess::color::Color;
 use libtchess::debuff::Debuff;
 use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
 use libtchess::piece::Piece;
 use libtchess::piece_id::PieceId;
 use libtchess::piece_move::PieceMove;
@@ -278,7 +279,21 @@ pub fn classic_8x8_prefilled() -> Board<TestHeatMap, TestSquaresMap> {
         Player::Human,
         Player::Human,
     );
-    let mut board = Board::empty(config);
+    classic_8x8_prefilled_with_config(config)
+}
+
+#[allow(unused)]
+pub fn classic_8x8_prefilled_with_config(
+    config: BoardConfig<TestHeatMap, TestSquaresMap>,
+) -> Board<TestHeatMap, TestSquaresMap> {
+    classic_8x8_prefilled_game_board(config)
+}
+
+#[allow(unused)]
+pub fn classic_8x8_prefilled_game_board<B: GameBoard<TestHeatMap, TestSquaresMap>>(
+    config: BoardConfig<TestHeatMap, TestSquaresMap>,
+) -> B {
+    let mut board = B::empty(config);
 
     for y in board.dimension().get_rows_range() {
         for x in board.dimension().get_columns_range() {
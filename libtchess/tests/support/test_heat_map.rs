@@ -103,6 +103,7 @@ impl HeatMap for TestHeatMap {
             Piece::Pawn(_) => self.map_pawn[y][x],
             Piece::Queen(_) => self.map_queen[y][x],
             Piece::Rook(_) => self.map_rook[y][x],
+            Piece::Custom(p) => p.definition().value(),
             Piece::UnknownPiece(_) => panic!("Can't evaluate an unknown piece."),
         }
     }
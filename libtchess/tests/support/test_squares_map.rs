@@ -1,3 +1,4 @@
+use libtchess::board_region::BoardRegion;
 use libtchess::board_square::BoardSquare;
 use libtchess::color::Color;
 use libtchess::dimension::Dimension;
@@ -8,6 +9,8 @@ use libtchess::squares_map::SquaresMap;
 #[derive(Clone)]
 pub struct TestSquaresMap {
     map: Vec<Vec<Option<Color>>>,
+    promotion_squares: Vec<(Point, Color)>,
+    region_squares: Vec<(Point, BoardRegion, Color)>,
 }
 
 impl TestSquaresMap {
@@ -28,21 +31,18 @@ impl TestSquaresMap {
             map.push(squares_row);
         }
 
-        match pov {
-            Color::White => {
-                map.reverse();
-            }
-            Color::Black => {
-                map = map
-                    .into_iter()
-                    .map(|mut row| {
-                        row.reverse();
-                        row
-                    })
-                    .collect::<Vec<_>>();
-            }
+        if *pov == Color::White {
+            map.reverse();
+        } else {
+            map = map
+                .into_iter()
+                .map(|mut row| {
+                    row.reverse();
+                    row
+                })
+                .collect::<Vec<_>>();
         }
-        Self { map }
+        Self { map, promotion_squares: vec![], region_squares: vec![] }
     }
 
     pub fn from_dimension(dimension: &Dimension) -> Self {
@@ -59,7 +59,17 @@ impl TestSquaresMap {
             }
             map.push(squares_row);
         }
-        Self { map }
+        Self { map, promotion_squares: vec![], region_squares: vec![] }
+    }
+
+    #[allow(unused)]
+    pub fn add_promotion_square(&mut self, point: Point, color: Color) {
+        self.promotion_squares.push((point, color));
+    }
+
+    #[allow(unused)]
+    pub fn add_region_square(&mut self, point: Point, region: BoardRegion, color: Color) {
+        self.region_squares.push((point, region, color));
     }
 }
 
@@ -71,4 +81,12 @@ impl SquaresMap for TestSquaresMap {
             None => None,
         }
     }
+
+    fn is_promotion_square(&self, point: &Point, color: &Color) -> bool {
+        self.promotion_squares.contains(&(*point, *color))
+    }
+
+    fn is_in_region(&self, point: &Point, region: &BoardRegion, color: &Color) -> bool {
+        self.region_squares.contains(&(*point, *region, *color))
+    }
 }
@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use im_rc::{HashMap, HashSet, OrdSet};
+use libtchess::collections::{HashMap, HashSet, OrdSet};
 use libtchess::piece_move::PieceMove;
 
 #[allow(unused)]
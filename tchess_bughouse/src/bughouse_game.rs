@@ -0,0 +1,368 @@
+use crate::bughouse_heat_map::BughouseHeatMap;
+use crate::bughouse_squares_map::BughouseSquaresMap;
+use crate::clock::Clock;
+use crate::game_result::{BoardResult, GameResult};
+use crate::move_result::MoveResult;
+use crate::team::{BoardNumber, Team};
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::buff::Buff;
+use libtchess::castle_x_points::{CastleXPoints, KingCastleXPoint, RookCastleXPoint};
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_id::PieceId;
+use libtchess::piece_move::PieceMove;
+use libtchess::player::Player;
+use libtchess::point::Point;
+use std::time::{Duration, Instant};
+
+// Back rank of each board, from a to h file
+pub const BACK_RANK: [&str; 8] = [
+    "Rook", "Knight", "Bishop", "Queen", "King", "Bishop", "Knight", "Rook",
+];
+
+// Two games of the classic chess, played at once by two teams. A piece, captured on one board, is
+// passed to the partner of the capturing side, who can drop it on the other board.
+pub struct BughouseGame {
+    boards: [Board<BughouseHeatMap, BughouseSquaresMap>; 2],
+    clocks: [Clock; 2],
+    game_result: Option<GameResult>,
+}
+
+impl BughouseGame {
+    pub fn empty(time: Duration, started_at: Instant) -> Self {
+        Self {
+            boards: [Board::empty(Self::config()), Board::empty(Self::config())],
+            clocks: [Clock::new(time, started_at), Clock::new(time, started_at)],
+            game_result: None,
+        }
+    }
+
+    // Places the back rank and a row of pawns in front of it for each side on both boards
+    pub fn new(time: Duration, started_at: Instant) -> Self {
+        let mut bughouse_game = Self::empty(time, started_at);
+        for board in bughouse_game.boards.iter_mut() {
+            for (color, row, pawns_row) in [(Color::White, 1, 2), (Color::Black, 8, 7)] {
+                for (x, name) in (1..).zip(BACK_RANK.iter()) {
+                    let buffs = match *name {
+                        "Rook" | "King" => vec![Buff::Castle],
+                        _ => vec![],
+                    };
+                    board.add_piece(name, color, buffs, vec![], Point::new(x, row));
+                    board.add_piece(
+                        "Pawn",
+                        color,
+                        vec![Buff::AdditionalPoint],
+                        vec![],
+                        Point::new(x, pawns_row),
+                    );
+                }
+            }
+        }
+        bughouse_game
+    }
+
+    pub fn config() -> BoardConfig<BughouseHeatMap, BughouseSquaresMap> {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
+        let mut config = BoardConfig::new(
+            CastleXPoints(KingCastleXPoint(7), RookCastleXPoint(6)),
+            CastleXPoints(KingCastleXPoint(3), RookCastleXPoint(4)),
+            BughouseHeatMap::init(),
+            BughouseSquaresMap::init(),
+            dimension,
+            Player::Human,
+            Player::Human,
+        );
+        config.set_drops(true);
+        config.set_pass_captures(true);
+        config
+    }
+
+    pub fn board(&self, board_number: &BoardNumber) -> &Board<BughouseHeatMap, BughouseSquaresMap> {
+        &self.boards[board_number.index()]
+    }
+
+    pub fn clock(&self, board_number: &BoardNumber) -> &Clock {
+        &self.clocks[board_number.index()]
+    }
+
+    pub fn game_result(&self) -> Option<&GameResult> {
+        self.game_result.as_ref()
+    }
+
+    pub fn move_piece_at(
+        &mut self,
+        board_number: &BoardNumber,
+        position: &Point,
+        piece_move: &PieceMove,
+        now: Instant,
+    ) -> MoveResult {
+        let &piece_id = self
+            .board(board_number)
+            .piece_id_at(position)
+            .unwrap_or_else(|| panic!("Could not find piece at {} position", position));
+        self.move_piece(board_number, &piece_id, piece_move, now)
+    }
+
+    // Makes the move on the given board at the given moment. Pieces from the pocket are moved by
+    // their ids in the pocket.
+    pub fn move_piece(
+        &mut self,
+        board_number: &BoardNumber,
+        piece_id: &PieceId,
+        piece_move: &PieceMove,
+        now: Instant,
+    ) -> MoveResult {
+        if let Some(game_result) = self.check_time(now) {
+            return MoveResult::GameEnded(game_result);
+        }
+        let board = &mut self.boards[board_number.index()];
+        let color = *board.current_turn();
+        if board.move_piece(piece_id, piece_move).is_none() {
+            return MoveResult::IllegalMove;
+        }
+
+        self.clocks[board_number.index()].press(now);
+        self.pass_captured_pieces(board_number, &color);
+        self.calculate_game_result(board_number);
+
+        match self.game_result {
+            Some(game_result) => MoveResult::GameEnded(game_result),
+            None => MoveResult::PieceMoved,
+        }
+    }
+
+    // The game ends as soon as the time of the side to move is out on any of the boards
+    pub fn check_time(&mut self, now: Instant) -> Option<GameResult> {
+        if self.game_result.is_some() {
+            return self.game_result;
+        }
+        for board_number in BoardNumber::all_variants() {
+            let color = *self.board(&board_number).current_turn();
+            if self.clock(&board_number).is_time_out(&color, now) {
+                self.game_result = Some(GameResult::TeamWon(
+                    Team::of(&board_number, &color).opponent(),
+                    board_number,
+                    BoardResult::TimeOut(color),
+                ));
+                break;
+            }
+        }
+        self.game_result
+    }
+
+    // The partner of the capturing side plays the opposite color on the other board
+    fn pass_captured_pieces(&mut self, board_number: &BoardNumber, color: &Color) {
+        let passed_pieces = self.boards[board_number.index()].take_passed_pieces(color);
+        let partner_board = &mut self.boards[board_number.other().index()];
+        for name in passed_pieces {
+            partner_board.add_to_pocket(&color.inverse(), &name);
+        }
+    }
+
+    fn calculate_game_result(&mut self, board_number: &BoardNumber) {
+        let board = self.board(board_number);
+        let current_turn = *board.current_turn();
+        if !board.has_no_moves(&current_turn) {
+            return;
+        }
+        self.game_result = if board.is_checked(&current_turn) {
+            Some(GameResult::TeamWon(
+                Team::of(board_number, &current_turn).opponent(),
+                *board_number,
+                BoardResult::Checkmate(current_turn),
+            ))
+        } else {
+            Some(GameResult::Draw(
+                *board_number,
+                BoardResult::Stalemate(current_turn),
+            ))
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libtchess::utils::pretty_print::PrettyPrint;
+
+    const TIME: Duration = Duration::from_secs(60);
+
+    fn move_piece_at(
+        bughouse_game: &mut BughouseGame,
+        board_number: BoardNumber,
+        point: Point,
+        piece_move: PieceMove,
+        now: Instant,
+    ) {
+        assert_eq!(
+            bughouse_game.move_piece_at(&board_number, &point, &piece_move, now),
+            MoveResult::PieceMoved
+        );
+        println!("{}", bughouse_game.board(&board_number).pp());
+    }
+
+    // Kings of both boards with a white rook and a black knight it can capture on the first board
+    fn game_with_capture(started_at: Instant) -> BughouseGame {
+        let mut bughouse_game = BughouseGame::empty(TIME, started_at);
+        for board in bughouse_game.boards.iter_mut() {
+            board.add_piece("King", Color::White, vec![], vec![], Point::new(1, 1));
+            board.add_piece("King", Color::Black, vec![], vec![], Point::new(8, 8));
+        }
+        let first_board = &mut bughouse_game.boards[BoardNumber::First.index()];
+        first_board.add_piece("Rook", Color::White, vec![], vec![], Point::new(4, 1));
+        first_board.add_piece("Knight", Color::Black, vec![], vec![], Point::new(4, 6));
+        bughouse_game
+    }
+
+    mod initial_position {
+        use super::*;
+
+        #[test]
+        fn both_boards_have_twenty_moves() {
+            let bughouse_game = BughouseGame::new(TIME, Instant::now());
+            for board_number in BoardNumber::all_variants() {
+                let board = bughouse_game.board(&board_number);
+                println!("{}", board.pp());
+                assert_eq!(board.legal_moves(&Color::White).len(), 20);
+                assert_eq!(board.legal_moves(&Color::Black).len(), 20);
+            }
+        }
+    }
+
+    mod captures {
+        use super::*;
+
+        #[test]
+        fn captured_piece_goes_to_the_partner() {
+            let started_at = Instant::now();
+            let mut bughouse_game = game_with_capture(started_at);
+
+            move_piece_at(
+                &mut bughouse_game,
+                BoardNumber::First,
+                Point::new(4, 1),
+                PieceMove::Point(Point::new(4, 6)),
+                started_at,
+            );
+            let first_board = bughouse_game.board(&BoardNumber::First);
+            let second_board = bughouse_game.board(&BoardNumber::Second);
+            assert!(first_board.pocket(&Color::White).is_empty());
+            assert_eq!(second_board.pocket(&Color::Black).count("Knight"), 1);
+        }
+
+        #[test]
+        fn partner_drops_the_passed_piece() {
+            let started_at = Instant::now();
+            let mut bughouse_game = game_with_capture(started_at);
+            move_piece_at(
+                &mut bughouse_game,
+                BoardNumber::First,
+                Point::new(4, 1),
+                PieceMove::Point(Point::new(4, 6)),
+                started_at,
+            );
+            move_piece_at(
+                &mut bughouse_game,
+                BoardNumber::Second,
+                Point::new(1, 1),
+                PieceMove::Point(Point::new(1, 2)),
+                started_at,
+            );
+
+            let (knight_id, _) = bughouse_game
+                .board(&BoardNumber::Second)
+                .pocket(&Color::Black)
+                .droppable()[0];
+            assert_eq!(
+                bughouse_game.move_piece(
+                    &BoardNumber::Second,
+                    &knight_id,
+                    &PieceMove::Drop(Point::new(3, 3)),
+                    started_at,
+                ),
+                MoveResult::PieceMoved
+            );
+            let second_board = bughouse_game.board(&BoardNumber::Second);
+            println!("{}", second_board.pp());
+            assert_eq!(
+                second_board.piece_at(&Point::new(3, 3)).unwrap().name(),
+                "Knight"
+            );
+            assert!(second_board.is_checked(&Color::White));
+        }
+    }
+
+    mod game_result {
+        use super::*;
+
+        #[test]
+        fn checkmate_on_one_board_wins_for_the_team() {
+            let started_at = Instant::now();
+            let mut bughouse_game = BughouseGame::empty(TIME, started_at);
+            let second_board = &mut bughouse_game.boards[BoardNumber::Second.index()];
+            second_board.add_piece("King", Color::White, vec![], vec![], Point::new(8, 1));
+            second_board.add_piece("Rook", Color::White, vec![], vec![], Point::new(5, 1));
+            second_board.add_piece("King", Color::Black, vec![], vec![], Point::new(1, 8));
+            second_board.add_piece("Pawn", Color::Black, vec![], vec![], Point::new(1, 7));
+            second_board.add_piece("Pawn", Color::Black, vec![], vec![], Point::new(2, 7));
+
+            let game_result = GameResult::TeamWon(
+                Team::B,
+                BoardNumber::Second,
+                BoardResult::Checkmate(Color::Black),
+            );
+            assert_eq!(
+                bughouse_game.move_piece_at(
+                    &BoardNumber::Second,
+                    &Point::new(5, 1),
+                    &PieceMove::Point(Point::new(5, 8)),
+                    started_at,
+                ),
+                MoveResult::GameEnded(game_result)
+            );
+            assert_eq!(bughouse_game.game_result(), Some(&game_result));
+        }
+
+        #[test]
+        fn clocks_of_the_boards_run_independently() {
+            let started_at = Instant::now();
+            let mut bughouse_game = game_with_capture(started_at);
+            move_piece_at(
+                &mut bughouse_game,
+                BoardNumber::First,
+                Point::new(4, 1),
+                PieceMove::Point(Point::new(4, 6)),
+                started_at + Duration::from_secs(10),
+            );
+
+            let now = started_at + Duration::from_secs(65);
+            let first_clock = bughouse_game.clock(&BoardNumber::First);
+            assert_eq!(
+                first_clock.remaining(&Color::White, now),
+                Duration::from_secs(50)
+            );
+            assert_eq!(
+                first_clock.remaining(&Color::Black, now),
+                Duration::from_secs(5)
+            );
+            // White has not moved on the second board yet, so its time is out
+            let game_result = GameResult::TeamWon(
+                Team::A,
+                BoardNumber::Second,
+                BoardResult::TimeOut(Color::White),
+            );
+            assert_eq!(bughouse_game.check_time(now), Some(game_result));
+            assert_eq!(
+                bughouse_game.move_piece_at(
+                    &BoardNumber::First,
+                    &Point::new(8, 8),
+                    &PieceMove::Point(Point::new(8, 7)),
+                    now,
+                ),
+                MoveResult::GameEnded(game_result)
+            );
+        }
+    }
+}
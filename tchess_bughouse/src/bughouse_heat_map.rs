@@ -0,0 +1,26 @@
+use libtchess::heat_map::HeatMap;
+use libtchess::piece::Piece;
+use libtchess::point::Point;
+
+// Pieces are dropped anywhere in the bughouse, thus only their material value is taken into
+// account
+#[derive(Clone)]
+pub struct BughouseHeatMap;
+
+impl BughouseHeatMap {
+    pub fn init() -> Self {
+        Self {}
+    }
+}
+
+impl HeatMap for BughouseHeatMap {
+    fn positional_value(&self, piece: &Piece, _position: &Point) -> i16 {
+        match piece {
+            Piece::Pawn(_) => 100,
+            Piece::Knight(_) | Piece::Bishop(_) => 300,
+            Piece::Rook(_) => 500,
+            Piece::Queen(_) => 900,
+            _ => 0,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use libtchess::color::Color;
+use libtchess::colored_property::ColoredProperty;
+use std::time::{Duration, Instant};
+
+// Chess clock of one board. The time of the side to move runs since its turn has started.
+pub struct Clock {
+    remaining: ColoredProperty<Duration>,
+    running: Color,
+    turn_started_at: Instant,
+}
+
+impl Clock {
+    pub fn new(time: Duration, started_at: Instant) -> Self {
+        Self {
+            remaining: ColoredProperty::from([time, time]),
+            running: Color::White,
+            turn_started_at: started_at,
+        }
+    }
+
+    // Stops the time of the side which has moved and starts the time of its opponent
+    pub fn press(&mut self, now: Instant) {
+        self.remaining[&self.running] = self.remaining(&self.running, now);
+        self.running = self.running.inverse();
+        self.turn_started_at = now;
+    }
+
+    pub fn remaining(&self, color: &Color, now: Instant) -> Duration {
+        if color == &self.running {
+            let spent = now.saturating_duration_since(self.turn_started_at);
+            self.remaining[color].saturating_sub(spent)
+        } else {
+            self.remaining[color]
+        }
+    }
+
+    pub fn is_time_out(&self, color: &Color, now: Instant) -> bool {
+        self.remaining(color, now).is_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_of_the_side_to_move_runs() {
+        let started_at = Instant::now();
+        let mut clock = Clock::new(Duration::from_secs(60), started_at);
+        let now = started_at + Duration::from_secs(10);
+        assert_eq!(clock.remaining(&Color::White, now), Duration::from_secs(50));
+        assert_eq!(clock.remaining(&Color::Black, now), Duration::from_secs(60));
+
+        clock.press(now);
+        let now = now + Duration::from_secs(70);
+        assert_eq!(clock.remaining(&Color::White, now), Duration::from_secs(50));
+        assert!(clock.is_time_out(&Color::Black, now));
+    }
+}
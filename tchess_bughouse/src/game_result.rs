@@ -0,0 +1,18 @@
+use crate::team::{BoardNumber, Team};
+use libtchess::color::Color;
+
+// Outcome of the game on one of the boards. The color is the side which lost the game or has no
+// moves.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BoardResult {
+    Checkmate(Color),
+    Stalemate(Color),
+    TimeOut(Color),
+}
+
+// The whole bughouse game ends as soon as the game on any of the boards ends
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GameResult {
+    TeamWon(Team, BoardNumber, BoardResult),
+    Draw(BoardNumber, BoardResult),
+}
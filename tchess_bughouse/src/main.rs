@@ -0,0 +1,19 @@
+use crate::bughouse_game::BughouseGame;
+use crate::team::BoardNumber;
+use libtchess::utils::pretty_print::PrettyPrint;
+use std::time::{Duration, Instant};
+
+mod bughouse_game;
+mod bughouse_heat_map;
+mod bughouse_squares_map;
+mod clock;
+mod game_result;
+mod move_result;
+mod team;
+
+fn main() {
+    let bughouse_game = BughouseGame::new(Duration::from_secs(300), Instant::now());
+    for board_number in BoardNumber::all_variants() {
+        println!("{}", bughouse_game.board(&board_number).pp());
+    }
+}
@@ -0,0 +1,3 @@
+use crate::game_result::GameResult;
+
+pub type MoveResult = libtchess::move_result::MoveResult<GameResult>;
@@ -0,0 +1,54 @@
+use libtchess::color::Color;
+
+// Boards of the bughouse game, played at once
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BoardNumber {
+    First,
+    Second,
+}
+
+impl BoardNumber {
+    pub fn all_variants() -> [Self; 2] {
+        [Self::First, Self::Second]
+    }
+
+    pub fn index(&self) -> usize {
+        match self {
+            Self::First => 0,
+            Self::Second => 1,
+        }
+    }
+
+    pub fn other(&self) -> Self {
+        match self {
+            Self::First => Self::Second,
+            Self::Second => Self::First,
+        }
+    }
+}
+
+// Each team plays white on one board and black on the other one. Partners pass the pieces they
+// capture to each other.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Team {
+    // Plays white on the first board and black on the second one
+    A,
+    // Plays black on the first board and white on the second one
+    B,
+}
+
+impl Team {
+    pub fn of(board_number: &BoardNumber, color: &Color) -> Self {
+        match (board_number, *color == Color::White) {
+            (BoardNumber::First, true) | (BoardNumber::Second, false) => Self::A,
+            (BoardNumber::First, false) | (BoardNumber::Second, true) => Self::B,
+        }
+    }
+
+    pub fn opponent(&self) -> Self {
+        match self {
+            Self::A => Self::B,
+            Self::B => Self::A,
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use crate::capablanca_heat_map::CapablancaHeatMap;
+use crate::capablanca_pieces::capablanca_piece_registry;
+use crate::capablanca_squares_map::CapablancaSquaresMap;
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::buff::Buff;
+use libtchess::castle_x_points::{CastleXPoints, KingCastleXPoint, RookCastleXPoint};
+use libtchess::classic_rules_game::ClassicRulesGame;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::player::Player;
+use libtchess::point::Point;
+
+// Back rank of the Capablanca chess, from a to j file
+pub const CAPABLANCA_BACK_RANK: [&str; 10] = [
+    "Rook",
+    "Knight",
+    "Archbishop",
+    "Bishop",
+    "Queen",
+    "King",
+    "Bishop",
+    "Chancellor",
+    "Knight",
+    "Rook",
+];
+
+// Back rank of the Gothic chess, from a to j file
+pub const GOTHIC_BACK_RANK: [&str; 10] = [
+    "Rook",
+    "Knight",
+    "Bishop",
+    "Queen",
+    "Chancellor",
+    "King",
+    "Archbishop",
+    "Bishop",
+    "Knight",
+    "Rook",
+];
+
+pub type CapablancaGame = ClassicRulesGame<CapablancaHeatMap, CapablancaSquaresMap>;
+
+pub fn capablanca_board() -> CapablancaGame {
+    with_config(capablanca_config(), &CAPABLANCA_BACK_RANK)
+}
+
+pub fn gothic_board() -> CapablancaGame {
+    with_config(capablanca_config(), &GOTHIC_BACK_RANK)
+}
+
+pub fn capablanca_config() -> BoardConfig<CapablancaHeatMap, CapablancaSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(10, 8));
+    let mut config = BoardConfig::new(
+        CastleXPoints(KingCastleXPoint(9), RookCastleXPoint(8)),
+        CastleXPoints(KingCastleXPoint(3), RookCastleXPoint(4)),
+        CapablancaHeatMap::init(),
+        CapablancaSquaresMap::init(),
+        dimension,
+        Player::Human,
+        Player::Human,
+    );
+    config.set_piece_registry(capablanca_piece_registry());
+    config
+}
+
+// Places the given back rank and a row of pawns in front of it for each side
+pub fn with_config(
+    config: BoardConfig<CapablancaHeatMap, CapablancaSquaresMap>,
+    back_rank: &[&str; 10],
+) -> CapablancaGame {
+    ClassicRulesGame::with_setup(config, |board| place_pieces(board, back_rank))
+}
+
+fn place_pieces(
+    board: &mut Board<CapablancaHeatMap, CapablancaSquaresMap>,
+    back_rank: &[&str; 10],
+) {
+    let last_row = *board.dimension().max_point().y().value();
+
+    for (color, row, pawns_row) in [(Color::White, 1, 2), (Color::Black, last_row, last_row - 1)] {
+        for (x, name) in (1..).zip(back_rank.iter()) {
+            let buffs = match *name {
+                "Rook" | "King" => vec![Buff::Castle],
+                _ => vec![],
+            };
+            board.add_piece(name, color, buffs, vec![], Point::new(x, row));
+        }
+        for x in board.dimension().get_columns_range() {
+            board.add_piece(
+                "Pawn",
+                color,
+                vec![Buff::AdditionalPoint],
+                vec![],
+                Point::new(x, pawns_row),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libtchess::castle_points::CastlePoints;
+    use libtchess::game_board::GameBoard;
+    use libtchess::game_result::GameResult;
+    use libtchess::move_result::MoveResult;
+    use libtchess::piece_move::PieceMove;
+    use libtchess::promote_piece::PromotePiece;
+    use libtchess::utils::pretty_print::PrettyPrint;
+
+    mod initial_position {
+        use super::*;
+
+        #[test]
+        fn capablanca_has_twenty_eight_moves() {
+            let capablanca_game = capablanca_board();
+            println!("{}", capablanca_game.board().pp());
+            assert_eq!(capablanca_game.board().legal_moves(&Color::White).len(), 28);
+            assert_eq!(capablanca_game.board().legal_moves(&Color::Black).len(), 28);
+        }
+
+        #[test]
+        fn gothic_has_twenty_eight_moves() {
+            let capablanca_game = gothic_board();
+            println!("{}", capablanca_game.board().pp());
+            assert_eq!(capablanca_game.board().legal_moves(&Color::White).len(), 28);
+        }
+    }
+
+    mod castle {
+        use super::*;
+
+        #[test]
+        fn king_castles_to_the_ninth_file() {
+            let mut capablanca_game = ClassicRulesGame::with_setup(capablanca_config(), |board| {
+                board.add_piece(
+                    "King",
+                    Color::White,
+                    vec![Buff::Castle],
+                    vec![],
+                    Point::new(6, 1),
+                );
+                board.add_piece(
+                    "Rook",
+                    Color::White,
+                    vec![Buff::Castle],
+                    vec![],
+                    Point::new(10, 1),
+                );
+                board.add_piece("King", Color::Black, vec![], vec![], Point::new(6, 8));
+            });
+
+            let castle_points = CastlePoints::new(
+                Point::new(9, 1),
+                Point::new(8, 1),
+                Point::new(6, 1),
+                Point::new(10, 1),
+            );
+            assert_eq!(
+                capablanca_game.move_piece_at(&Point::new(6, 1), &PieceMove::Castle(castle_points)),
+                MoveResult::PieceMoved
+            );
+            let board = capablanca_game.board();
+            println!("{}", board.pp());
+            assert_eq!(board.piece_at(&Point::new(9, 1)).unwrap().name(), "King");
+            assert_eq!(board.piece_at(&Point::new(8, 1)).unwrap().name(), "Rook");
+        }
+    }
+
+    mod checkmate {
+        use super::*;
+
+        #[test]
+        fn chancellor_mates_along_the_back_rank() {
+            let mut capablanca_game = ClassicRulesGame::with_setup(capablanca_config(), |board| {
+                board.add_piece("King", Color::White, vec![], vec![], Point::new(5, 1));
+                board.add_piece("Rook", Color::White, vec![], vec![], Point::new(9, 7));
+                board.add_piece(
+                    "Chancellor",
+                    Color::White,
+                    vec![],
+                    vec![],
+                    Point::new(10, 1),
+                );
+                board.add_piece("King", Color::Black, vec![], vec![], Point::new(1, 8));
+            });
+
+            assert_eq!(
+                capablanca_game
+                    .move_piece_at(&Point::new(10, 1), &PieceMove::Point(Point::new(10, 8))),
+                MoveResult::GameEnded(GameResult::Checkmate(Color::Black))
+            );
+            assert_eq!(
+                capablanca_game.game_result(),
+                Some(&GameResult::Checkmate(Color::Black))
+            );
+        }
+    }
+
+    mod promotion {
+        use super::*;
+
+        #[test]
+        fn pawn_promotes_to_compound_pieces() {
+            let mut capablanca_game = ClassicRulesGame::with_setup(capablanca_config(), |board| {
+                board.add_piece("King", Color::White, vec![], vec![], Point::new(1, 1));
+                board.add_piece("King", Color::Black, vec![], vec![], Point::new(1, 8));
+                board.add_piece("Pawn", Color::White, vec![], vec![], Point::new(10, 7));
+            });
+            let pawn_id = *capablanca_game
+                .board()
+                .piece_id_at(&Point::new(10, 7))
+                .unwrap();
+
+            let mut promotions = capablanca_game
+                .board()
+                .moves_of(&pawn_id)
+                .unwrap()
+                .keys()
+                .filter_map(|piece_move| match piece_move {
+                    PieceMove::Promote(_, promote_piece) => Some(*promote_piece),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            promotions.sort();
+            assert_eq!(
+                promotions,
+                vec![
+                    PromotePiece::Knight,
+                    PromotePiece::Bishop,
+                    PromotePiece::Rook,
+                    PromotePiece::Queen,
+                    PromotePiece::Archbishop,
+                    PromotePiece::Chancellor,
+                ]
+            );
+
+            assert_eq!(
+                capablanca_game.move_piece_at(
+                    &Point::new(10, 7),
+                    &PieceMove::Promote(Point::new(10, 8), PromotePiece::Chancellor),
+                ),
+                MoveResult::PieceMoved
+            );
+            let chancellor = capablanca_game
+                .board()
+                .piece_at(&Point::new(10, 8))
+                .unwrap();
+            assert_eq!(chancellor.name(), "Chancellor");
+        }
+    }
+}
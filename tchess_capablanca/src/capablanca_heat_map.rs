@@ -0,0 +1,180 @@
+use libtchess::color::Color;
+use libtchess::heat_map::HeatMap;
+use libtchess::piece::Piece;
+use libtchess::point::Point;
+
+const ARCHBISHOP_MAP: [[i16; 10]; 8] = [
+    [822, 824, 825, 825, 825, 825, 825, 825, 824, 822],
+    [824, 828, 830, 830, 830, 830, 830, 830, 828, 824],
+    [825, 830, 835, 837, 837, 837, 837, 835, 830, 825],
+    [825, 830, 837, 841, 841, 841, 841, 837, 830, 825],
+    [825, 830, 837, 842, 842, 842, 842, 837, 830, 825],
+    [825, 830, 835, 837, 837, 837, 837, 835, 830, 825],
+    [824, 828, 830, 830, 830, 830, 830, 830, 828, 824],
+    [822, 824, 825, 825, 825, 825, 825, 825, 824, 822],
+];
+
+const BISHOP_MAP: [[i16; 10]; 8] = [
+    [324, 325, 325, 325, 325, 325, 325, 325, 325, 324],
+    [325, 328, 328, 328, 328, 328, 328, 328, 328, 325],
+    [325, 328, 332, 333, 333, 333, 333, 332, 328, 325],
+    [325, 328, 333, 336, 336, 336, 336, 333, 328, 325],
+    [325, 328, 333, 336, 336, 336, 336, 333, 328, 325],
+    [325, 328, 332, 333, 333, 333, 333, 332, 328, 325],
+    [325, 328, 328, 328, 328, 328, 328, 328, 328, 325],
+    [324, 325, 325, 325, 325, 325, 325, 325, 325, 324],
+];
+
+const CHANCELLOR_MAP: [[i16; 10]; 8] = [
+    [879, 881, 882, 883, 883, 883, 883, 882, 881, 879],
+    [880, 883, 884, 885, 885, 885, 885, 884, 883, 880],
+    [877, 880, 883, 885, 885, 885, 885, 883, 880, 877],
+    [875, 878, 882, 885, 885, 885, 885, 882, 878, 875],
+    [875, 878, 882, 885, 885, 885, 885, 882, 878, 875],
+    [874, 877, 880, 882, 882, 882, 882, 880, 877, 874],
+    [872, 875, 876, 877, 877, 877, 877, 876, 875, 872],
+    [870, 872, 874, 875, 875, 875, 875, 874, 872, 870],
+];
+
+const KING_MAP: [[i16; 10]; 8] = [
+    [10, 12, 8, 0, 0, 0, 0, 8, 12, 10],
+    [10, 10, 5, -4, -4, -4, -4, 5, 10, 10],
+    [7, 5, 0, -8, -8, -8, -8, 0, 5, 7],
+    [4, 1, -5, -12, -12, -12, -12, -5, 1, 4],
+    [2, -2, -8, -16, -16, -16, -16, -8, -2, 2],
+    [1, -4, -10, -18, -18, -18, -18, -10, -4, 1],
+    [1, -4, -10, -18, -18, -18, -18, -10, -4, 1],
+    [1, -4, -10, -18, -18, -18, -18, -10, -4, 1],
+];
+
+const KNIGHT_MAP: [[i16; 10]; 8] = [
+    [310, 312, 313, 313, 313, 313, 313, 313, 312, 310],
+    [312, 316, 318, 318, 318, 318, 318, 318, 316, 312],
+    [313, 318, 323, 325, 325, 325, 325, 323, 318, 313],
+    [313, 318, 325, 329, 329, 329, 329, 325, 318, 313],
+    [313, 318, 325, 330, 330, 330, 330, 325, 318, 313],
+    [313, 318, 323, 325, 325, 325, 325, 323, 318, 313],
+    [312, 316, 318, 318, 318, 318, 318, 318, 316, 312],
+    [310, 312, 313, 313, 313, 313, 313, 313, 312, 310],
+];
+
+const PAWN_MAP: [[i16; 10]; 8] = [
+    [100, 100, 100, 100, 100, 100, 100, 100, 100, 100],
+    [110, 112, 114, 118, 118, 118, 118, 114, 112, 110],
+    [107, 109, 111, 115, 115, 115, 115, 111, 109, 107],
+    [104, 106, 108, 112, 112, 112, 112, 108, 106, 104],
+    [102, 103, 105, 109, 109, 109, 109, 105, 103, 102],
+    [101, 102, 103, 105, 105, 105, 105, 103, 102, 101],
+    [100, 100, 100, 97, 97, 97, 97, 100, 100, 100],
+    [100, 100, 100, 100, 100, 100, 100, 100, 100, 100],
+];
+
+const QUEEN_MAP: [[i16; 10]; 8] = [
+    [894, 895, 896, 896, 896, 896, 896, 896, 895, 894],
+    [895, 897, 898, 898, 898, 898, 898, 898, 897, 895],
+    [896, 898, 900, 901, 901, 901, 901, 900, 898, 896],
+    [896, 898, 901, 903, 903, 903, 903, 901, 898, 896],
+    [896, 898, 901, 903, 903, 903, 903, 901, 898, 896],
+    [896, 898, 900, 901, 901, 901, 901, 900, 898, 896],
+    [895, 897, 898, 898, 898, 898, 898, 898, 897, 895],
+    [894, 895, 896, 896, 896, 896, 896, 896, 895, 894],
+];
+
+const ROOK_MAP: [[i16; 10]; 8] = [
+    [506, 507, 507, 508, 508, 508, 508, 507, 507, 506],
+    [506, 507, 507, 508, 508, 508, 508, 507, 507, 506],
+    [502, 503, 503, 504, 504, 504, 504, 503, 503, 502],
+    [500, 501, 501, 502, 502, 502, 502, 501, 501, 500],
+    [500, 501, 501, 502, 502, 502, 502, 501, 501, 500],
+    [499, 500, 500, 501, 501, 501, 501, 500, 500, 499],
+    [498, 499, 499, 500, 500, 500, 500, 499, 499, 498],
+    [497, 498, 499, 500, 500, 500, 500, 499, 498, 497],
+];
+// Heat map of the 10x8 board. Values of the 8x8 classic maps are spread over two extra central
+// files.
+#[derive(Clone)]
+pub struct CapablancaHeatMap;
+
+impl CapablancaHeatMap {
+    pub fn init() -> Self {
+        Self {}
+    }
+}
+
+impl HeatMap for CapablancaHeatMap {
+    fn positional_value(&self, piece: &Piece, position: &Point) -> i16 {
+        let (x, y) = if *piece.color() == Color::White {
+            (
+                *position.x().value() as usize - 1,
+                8 - *position.y().value() as usize,
+            )
+        } else {
+            (
+                10 - *position.x().value() as usize,
+                *position.y().value() as usize - 1,
+            )
+        };
+        match piece {
+            Piece::Bishop(_) => BISHOP_MAP[y][x],
+            Piece::King(_) => KING_MAP[y][x],
+            Piece::Knight(_) => KNIGHT_MAP[y][x],
+            Piece::Pawn(_) => PAWN_MAP[y][x],
+            Piece::Queen(_) => QUEEN_MAP[y][x],
+            Piece::Rook(_) => ROOK_MAP[y][x],
+            Piece::Custom(p) => match p.definition().name() {
+                "Archbishop" => ARCHBISHOP_MAP[y][x],
+                "Chancellor" => CHANCELLOR_MAP[y][x],
+                _ => p.definition().value(),
+            },
+            Piece::UnknownPiece(_) => panic!("Unknown piece can't be evaluated!"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capablanca_pieces::capablanca_piece_registry;
+    use libtchess::piece_id::PieceId;
+
+    fn piece(name: &str, color: Color) -> Piece {
+        capablanca_piece_registry().init_piece(
+            name,
+            color,
+            Point::new(1, 1),
+            PieceId::new(1, &color),
+        )
+    }
+
+    #[test]
+    fn value_of_central_archbishop() {
+        let heat_map = CapablancaHeatMap::init();
+        let archbishop = piece("Archbishop", Color::White);
+        assert_eq!(
+            heat_map.positional_value(&archbishop, &Point::new(5, 4)),
+            842
+        );
+        assert_eq!(
+            heat_map.positional_value(&archbishop, &Point::new(1, 1)),
+            822
+        );
+    }
+
+    #[test]
+    fn value_of_chancellor_is_symmetric() {
+        let heat_map = CapablancaHeatMap::init();
+        let white_chancellor = piece("Chancellor", Color::White);
+        let black_chancellor = piece("Chancellor", Color::Black);
+        assert_eq!(
+            heat_map.positional_value(&white_chancellor, &Point::new(8, 1)),
+            heat_map.positional_value(&black_chancellor, &Point::new(3, 8)),
+        );
+    }
+
+    #[test]
+    fn value_of_white_pawn_on_the_tenth_file() {
+        let heat_map = CapablancaHeatMap::init();
+        let pawn = piece("Pawn", Color::White);
+        assert_eq!(heat_map.positional_value(&pawn, &Point::new(10, 2)), 100);
+    }
+}
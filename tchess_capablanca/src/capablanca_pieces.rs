@@ -0,0 +1,23 @@
+use libtchess::betza::BetzaPiece;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::piece_registry::PieceRegistry;
+
+// Moves like a bishop and a knight
+pub fn archbishop() -> BetzaPiece {
+    BetzaPiece::new("Archbishop", ColoredProperty::from(['A', 'a']), 825, "BN")
+        .expect("Archbishop notation must be valid!")
+}
+
+// Moves like a rook and a knight
+pub fn chancellor() -> BetzaPiece {
+    BetzaPiece::new("Chancellor", ColoredProperty::from(['C', 'c']), 875, "RN")
+        .expect("Chancellor notation must be valid!")
+}
+
+// Classic pieces along with the archbishop and the chancellor
+pub fn capablanca_piece_registry() -> PieceRegistry {
+    let mut piece_registry = PieceRegistry::classic();
+    piece_registry.register(archbishop());
+    piece_registry.register(chancellor());
+    piece_registry
+}
@@ -0,0 +1,11 @@
+use libtchess::utils::pretty_print::PrettyPrint;
+
+mod capablanca_game;
+mod capablanca_heat_map;
+mod capablanca_pieces;
+mod capablanca_squares_map;
+
+fn main() {
+    let capablanca_game = capablanca_game::capablanca_board();
+    println!("{}", capablanca_game.board().pp());
+}
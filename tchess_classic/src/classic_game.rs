@@ -1,40 +1,129 @@
-use crate::board_positions::BoardPositions;
 use crate::classic_heat_map::ClassicHeatMap;
 use crate::classic_square_map::ClassicSquaresMap;
+use crate::draw_rule::{Counting, DrawRule, Honor};
 use crate::game_result::GameResult;
+use crate::makruk_pieces::makruk_piece_registry;
 use crate::move_result::MoveResult;
+use crate::variant_rules::VariantRules;
+use libtchess::bitboard::BitBoard;
 use libtchess::board::Board;
 use libtchess::board_config::BoardConfig;
+use libtchess::board_positions::BoardPositions;
 use libtchess::board_stats::BoardStats;
 use libtchess::buff::Buff;
 use libtchess::castle_x_points::{CastleXPoints, KingCastleXPoint, RookCastleXPoint};
 use libtchess::color::Color;
 use libtchess::colored_property::ColoredProperty;
 use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
 use libtchess::heat_map::HeatMap;
 use libtchess::piece_id::PieceId;
 use libtchess::piece_move::PieceMove;
 use libtchess::player::Player;
 use libtchess::point::Point;
+use libtchess::promote_piece::PromotePiece;
+use libtchess::promotion_zone::PromotionZone;
 use libtchess::squares_map::SquaresMap;
-
-const FIFTY_MOVE_RULE_TURNS_COUNT: usize = 100;
-const MAX_NUMBER_OF_EQUAL_POSITIONS: u8 = 3;
+use libtchess::variant::Variant;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
 
 #[derive(Clone)]
-pub struct ClassicGame<HT: HeatMap, SQ: SquaresMap> {
+pub struct ClassicGame<HT: HeatMap, SQ: SquaresMap, B: GameBoard<HT, SQ> = Board<HT, SQ>> {
     board_positions: BoardPositions,
-    board: Board<HT, SQ>,
+    board: B,
     game_result: Option<GameResult>,
+    draw_rule: DrawRule,
+    counting: Option<Counting>,
     last_turn_pieces_changed: usize,
     initial_pieces_phase_weight: isize,
     positional_weight: ColoredProperty<i32>,
+    config_types: PhantomData<(HT, SQ)>,
 }
 
 impl ClassicGame<ClassicHeatMap, ClassicSquaresMap> {
     pub fn classic_board() -> Self {
+        Self::with_config(Self::classic_config())
+    }
+
+    // Classic set of pieces, where each capture explodes the pieces around the captured one
+    pub fn atomic_board() -> Self {
+        let mut config = Self::classic_config();
+        config.set_variant(Variant::Atomic);
+        Self::with_config(config)
+    }
+
+    // Classic set of pieces, played by the losing chess rules. Castling is not allowed and a pawn
+    // can promote to the king as well.
+    pub fn antichess_board() -> Self {
+        let mut config = Self::classic_config();
+        config.set_variant(Variant::Antichess);
+        config.set_castle_rules(vec![]);
+        let mut promote_pieces = config.promote_pieces().to_vec();
+        promote_pieces.push(PromotePiece::King);
+        config.set_promote_pieces(promote_pieces);
+        Self::with_config(config)
+    }
+
+    // Classic set of pieces, where the side wins by giving three checks
+    pub fn three_check_board() -> Self {
+        let mut config = Self::classic_config();
+        config.set_variant(Variant::CheckLimit(3));
+        Self::with_config(config)
+    }
+
+    // Classic set of pieces, where the side wins when its king reaches any of the central squares
+    pub fn king_of_the_hill_board() -> Self {
+        let mut config = Self::classic_config();
+        config.set_hill_squares(vec![
+            Point::new(4, 4),
+            Point::new(5, 4),
+            Point::new(4, 5),
+            Point::new(5, 5),
+        ]);
+        Self::with_config(config)
+    }
+
+    // White pawns play against the classic set of black pieces. White has no king and loses when
+    // all its pieces are captured.
+    pub fn horde_board() -> Self {
+        Self::with_setup(Self::classic_config(), Self::place_horde_pieces)
+    }
+
+    // Kings race to the last rank, while giving check is not allowed
+    pub fn racing_kings_board() -> Self {
+        let mut config = Self::classic_config();
+        config.set_variant(Variant::RacingKings);
+        config.set_castle_rules(vec![]);
+        config.set_hill_squares((1..=8).map(|x| Point::new(x, 8)).collect());
+        Self::with_setup(config, Self::place_racing_kings_pieces)
+    }
+
+    // Thai chess. The met and the khon replace the queen and the bishop, pawns start on the third
+    // rank and promote to the met on the sixth one. Instead of the fifty-move rule, the endgame
+    // is drawn by the counting rules.
+    pub fn makruk_board() -> Self {
+        Self::with_draw_rule(
+            Self::makruk_config(),
+            DrawRule::Counting,
+            Self::place_makruk_pieces,
+        )
+    }
+
+    pub fn makruk_config() -> BoardConfig<ClassicHeatMap, ClassicSquaresMap> {
+        let mut config = Self::classic_config();
+        config.set_piece_registry(makruk_piece_registry());
+        config.set_castle_rules(vec![]);
+        config.set_promotion_zone(&Color::White, PromotionZone::Ranks(6..=8));
+        config.set_promotion_zone(&Color::Black, PromotionZone::Ranks(1..=3));
+        config.set_promote_pieces(vec![PromotePiece::Promoted]);
+        config.set_promotion("Pawn", "Met");
+        config
+    }
+
+    pub fn classic_config() -> BoardConfig<ClassicHeatMap, ClassicSquaresMap> {
         let dimension = Dimension::new(Point::new(1, 1), Point::new(8, 8));
-        let config = BoardConfig::new(
+        BoardConfig::new(
             CastleXPoints(KingCastleXPoint(7), RookCastleXPoint(6)),
             CastleXPoints(KingCastleXPoint(3), RookCastleXPoint(4)),
             ClassicHeatMap::init(),
@@ -42,79 +131,41 @@ impl ClassicGame<ClassicHeatMap, ClassicSquaresMap> {
             dimension,
             Player::Human,
             Player::Human,
-        );
-        let mut board = Board::empty(config);
+        )
+    }
+}
 
-        for y in board.dimension().get_rows_range() {
-            for x in board.dimension().get_columns_range() {
-                let point = Point::new(x, y);
-                match (y, x) {
-                    // White pieces
-                    (1, 1) | (1, 8) => {
-                        board.add_piece("Rook", Color::White, vec![Buff::Castle], vec![], point);
-                        ()
-                    }
-                    (1, 2) | (1, 7) => {
-                        board.add_piece("Knight", Color::White, vec![], vec![], point);
-                        ()
-                    }
-                    (1, 3) | (1, 6) => {
-                        board.add_piece("Bishop", Color::White, vec![], vec![], point);
-                        ()
-                    }
-                    (1, 4) => {
-                        board.add_piece("Queen", Color::White, vec![], vec![], point);
-                        ()
-                    }
-                    (1, 5) => {
-                        board.add_piece("King", Color::White, vec![Buff::Castle], vec![], point);
-                        ()
-                    }
-                    (2, _) => {
-                        board.add_piece(
-                            "Pawn",
-                            Color::White,
-                            vec![Buff::AdditionalPoint],
-                            vec![],
-                            point,
-                        );
-                        ()
-                    }
-                    // Black pieces
-                    (8, 1) | (8, 8) => {
-                        board.add_piece("Rook", Color::Black, vec![Buff::Castle], vec![], point);
-                        ()
-                    }
-                    (8, 2) | (8, 7) => {
-                        board.add_piece("Knight", Color::Black, vec![], vec![], point);
-                        ()
-                    }
-                    (8, 3) | (8, 6) => {
-                        board.add_piece("Bishop", Color::Black, vec![], vec![], point);
-                        ()
-                    }
-                    (8, 5) => {
-                        board.add_piece("King", Color::Black, vec![Buff::Castle], vec![], point);
-                        ()
-                    }
-                    (8, 4) => {
-                        board.add_piece("Queen", Color::Black, vec![], vec![], point);
-                        ()
-                    }
-                    (7, _) => {
-                        board.add_piece(
-                            "Pawn",
-                            Color::Black,
-                            vec![Buff::AdditionalPoint],
-                            vec![],
-                            point,
-                        );
-                        ()
-                    }
-                    _ => (),
-                };
-            }
-        }
+// Same classic game, played on the board with bitboards moves generation
+impl ClassicGame<ClassicHeatMap, ClassicSquaresMap, BitBoard<ClassicHeatMap, ClassicSquaresMap>> {
+    pub fn classic_bitboard() -> Self {
+        Self::with_config(ClassicGame::classic_config())
+    }
+}
+
+impl<B: GameBoard<ClassicHeatMap, ClassicSquaresMap>>
+    ClassicGame<ClassicHeatMap, ClassicSquaresMap, B>
+{
+    // Places classic set of pieces on the board, built from the given config
+    pub fn with_config(config: BoardConfig<ClassicHeatMap, ClassicSquaresMap>) -> Self {
+        Self::with_setup(config, Self::place_classic_pieces)
+    }
+
+    // Places the pieces on the board, built from the given config, with the given function
+    pub fn with_setup<F: FnOnce(&mut B)>(
+        config: BoardConfig<ClassicHeatMap, ClassicSquaresMap>,
+        place_pieces: F,
+    ) -> Self {
+        Self::with_draw_rule(config, DrawRule::FiftyMoveRule, place_pieces)
+    }
+
+    // Same as with_setup, but the game, going on for too long, is drawn by the given rule
+    pub fn with_draw_rule<F: FnOnce(&mut B)>(
+        config: BoardConfig<ClassicHeatMap, ClassicSquaresMap>,
+        draw_rule: DrawRule,
+        place_pieces: F,
+    ) -> Self {
+        let mut board = B::empty(config);
+        place_pieces(&mut board);
 
         let initial_pieces_phase_weight = Self::all_pieces_phase_weight(&board.stats());
         let white_positional_weight = board
@@ -142,13 +193,116 @@ impl ClassicGame<ClassicHeatMap, ClassicSquaresMap> {
             board,
             board_positions: BoardPositions::empty(),
             game_result: None,
+            draw_rule,
+            counting: None,
             last_turn_pieces_changed: 0,
             initial_pieces_phase_weight,
-            positional_weight: ColoredProperty([white_positional_weight, black_positional_weight]),
+            positional_weight: ColoredProperty::from([white_positional_weight, black_positional_weight]),
+            config_types: PhantomData,
         };
         classic_board
     }
 
+    fn place_classic_pieces(board: &mut B) {
+        Self::place_classic_army(board, Color::White);
+        Self::place_classic_army(board, Color::Black);
+    }
+
+    fn place_classic_army(board: &mut B, color: Color) {
+        let (back_rank, pawns_rank) = if color == Color::White { (1, 2) } else { (8, 7) };
+        for y in board.dimension().get_rows_range() {
+            for x in board.dimension().get_columns_range() {
+                let point = Point::new(x, y);
+                if y == pawns_rank {
+                    board.add_piece("Pawn", color, vec![Buff::AdditionalPoint], vec![], point);
+                    continue;
+                }
+                if y != back_rank {
+                    continue;
+                }
+                match x {
+                    1 | 8 => {
+                        board.add_piece("Rook", color, vec![Buff::Castle], vec![], point);
+                        ()
+                    }
+                    2 | 7 => {
+                        board.add_piece("Knight", color, vec![], vec![], point);
+                        ()
+                    }
+                    3 | 6 => {
+                        board.add_piece("Bishop", color, vec![], vec![], point);
+                        ()
+                    }
+                    4 => {
+                        board.add_piece("Queen", color, vec![], vec![], point);
+                        ()
+                    }
+                    5 => {
+                        board.add_piece("King", color, vec![Buff::Castle], vec![], point);
+                        ()
+                    }
+                    _ => (),
+                };
+            }
+        }
+    }
+
+    // 36 white pawns against the classic black army. Pawns of the first and the second ranks can
+    // go further on their first move.
+    fn place_horde_pieces(board: &mut B) {
+        for y in board.dimension().get_rows_range() {
+            for x in board.dimension().get_columns_range() {
+                let point = Point::new(x, y);
+                match (y, x) {
+                    (1 | 2, _) => {
+                        board.add_piece(
+                            "Pawn",
+                            Color::White,
+                            vec![Buff::AdditionalPoint],
+                            vec![],
+                            point,
+                        );
+                        ()
+                    }
+                    (3 | 4, _) | (5, 2 | 3 | 6 | 7) => {
+                        board.add_piece("Pawn", Color::White, vec![], vec![], point);
+                        ()
+                    }
+                    _ => (),
+                };
+            }
+        }
+        Self::place_classic_army(board, Color::Black);
+    }
+
+    // The kings face the mets of the opposite side
+    fn place_makruk_pieces(board: &mut B) {
+        let back_rank = ["Rook", "Knight", "Khon", "Met", "King", "Khon", "Knight", "Rook"];
+        for (x, name) in (1..).zip(back_rank) {
+            board.add_piece(name, Color::White, vec![], vec![], Point::new(x, 1));
+            board.add_piece(name, Color::Black, vec![], vec![], Point::new(9 - x, 8));
+            board.add_piece("Pawn", Color::White, vec![], vec![], Point::new(x, 3));
+            board.add_piece("Pawn", Color::Black, vec![], vec![], Point::new(x, 6));
+        }
+    }
+
+    // Both sides start on the first two ranks, black pieces on the left and white pieces on the
+    // right, without pawns
+    fn place_racing_kings_pieces(board: &mut B) {
+        let pieces = [
+            ("Queen", "King"),
+            ("Rook", "Rook"),
+            ("Bishop", "Bishop"),
+            ("Knight", "Knight"),
+        ];
+        for (x, (first_rank_name, second_rank_name)) in (1..).zip(pieces) {
+            for (color, x) in [(Color::Black, x), (Color::White, 9 - x)] {
+                board.add_piece(first_rank_name, color, vec![], vec![], Point::new(x, 1));
+                board.add_piece(second_rank_name, color, vec![], vec![], Point::new(x, 2));
+            }
+        }
+    }
+
     pub fn move_piece_at(&mut self, position: &Point, piece_move: &PieceMove) -> MoveResult {
         let &piece_id = self
             .board
@@ -179,18 +333,28 @@ impl ClassicGame<ClassicHeatMap, ClassicSquaresMap> {
             return MoveResult::IllegalMove;
         };
 
+        let is_check = self.board.is_checked(self.board.current_turn());
         self.board_positions
-            .persist_position(&self.board.stats().zposition.0);
+            .persist_position(&self.board.stats().zposition.0, is_check);
         self.calculate_game_result();
 
         if let Some(game_result) = self.game_result {
             match game_result {
-                GameResult::Checkmate(color) => {
+                GameResult::Checkmate(color)
+                | GameResult::KingExploded(color)
+                | GameResult::CheckLimitReached(color)
+                | GameResult::AllPiecesCaptured(color) => {
                     self.positional_weight[&color.inverse()] = i32::MAX;
                 }
+                GameResult::AntichessWin(color) | GameResult::KingOnTheHill(color) => {
+                    self.positional_weight[&color] = i32::MAX;
+                }
                 GameResult::InsufficientMaterialDraw
+                | GameResult::BothKingsOnTheHillDraw
                 | GameResult::DrawByRepetition
                 | GameResult::FiftyMoveRuleDraw
+                | GameResult::BoardHonorDraw
+                | GameResult::PieceHonorDraw
                 | GameResult::Stalemate(_) => {
                     self.positional_weight[&Color::White] = 0;
                     self.positional_weight[&Color::Black] = 0;
@@ -202,7 +366,7 @@ impl ClassicGame<ClassicHeatMap, ClassicSquaresMap> {
         }
     }
 
-    pub fn board(&self) -> &Board<ClassicHeatMap, ClassicSquaresMap> {
+    pub fn board(&self) -> &B {
         &self.board
     }
 
@@ -215,33 +379,34 @@ impl ClassicGame<ClassicHeatMap, ClassicSquaresMap> {
     }
 
     fn calculate_game_result(&mut self) {
-        if self.board.has_no_moves(self.board.current_turn()) {
-            if let Some(king) = self.board.king(self.board.current_turn()) {
-                if self.board.debuffs(king.color()).has_check(king.id()) {
-                    self.game_result = Some(GameResult::Checkmate(*self.board.current_turn()))
-                } else {
-                    self.game_result = Some(GameResult::Stalemate(*self.board.current_turn()))
-                }
-            } else {
-                self.game_result = Some(GameResult::InsufficientMaterialDraw)
-            }
+        let variant = *self.board.config().variant();
+        let current_turn = *self.board.current_turn();
+        if let Some(game_result) = variant.game_result(&self.board) {
+            self.game_result = Some(game_result);
             return;
         }
-        if self.board.stats().turn_number - self.board.stats().last_capture_turn_number
-            >= FIFTY_MOVE_RULE_TURNS_COUNT
-            || self.board.stats().turn_number - self.board.stats().last_pawn_move_turn_number
-                >= FIFTY_MOVE_RULE_TURNS_COUNT
-        {
-            self.game_result = Some(GameResult::FiftyMoveRuleDraw);
+        if self.board.active_pieces(&current_turn).is_empty() {
+            self.game_result = Some(GameResult::AllPiecesCaptured(current_turn));
             return;
         }
-        if let Some((_, occurrences_num)) = self.board_positions.most_frequent_position()
-            && occurrences_num == &MAX_NUMBER_OF_EQUAL_POSITIONS
-        {
+        if self.board.is_out_of_royals(&current_turn) {
+            self.game_result = Some(GameResult::KingExploded(current_turn));
+            return;
+        }
+        if self.board.has_no_moves(&current_turn) {
+            self.game_result = Some(variant.no_moves_result(&self.board));
+            return;
+        }
+        if let Some(game_result) = self.draw_rule_result() {
+            self.game_result = Some(game_result);
+            return;
+        }
+        if self.board_positions.is_threefold_repetition() {
             self.game_result = Some(GameResult::DrawByRepetition);
             return;
         }
-        if Self::is_insufficient_material(&Color::White, self.board.stats())
+        if variant.has_insufficient_material_draw()
+            && Self::is_insufficient_material(&Color::White, self.board.stats())
             && Self::is_insufficient_material(&Color::Black, self.board.stats())
         {
             self.game_result = Some(GameResult::InsufficientMaterialDraw);
@@ -249,6 +414,75 @@ impl ClassicGame<ClassicHeatMap, ClassicSquaresMap> {
         }
     }
 
+    fn draw_rule_result(&mut self) -> Option<GameResult> {
+        match self.draw_rule {
+            DrawRule::FiftyMoveRule => self
+                .board
+                .stats()
+                .is_fifty_move_rule_over()
+                .then_some(GameResult::FiftyMoveRuleDraw),
+            DrawRule::Counting => {
+                self.update_counting();
+                let turn_number = *self.board.stats().turn_number;
+                self.counting
+                    .filter(|counting| counting.is_over(turn_number))
+                    .map(|counting| counting.game_result())
+            }
+        }
+    }
+
+    // Starts the board's honor count once no pawns are left, and restarts it as the piece's honor
+    // count once a side is left with the bare king
+    fn update_counting(&mut self) {
+        let stats = self.board.stats();
+        let pieces_stats = stats.active_pieces_stats;
+        if pieces_stats[&Color::White].pawns_count > 0
+            || pieces_stats[&Color::Black].pawns_count > 0
+            || self
+                .counting
+                .is_some_and(|counting| counting.honor() == &Honor::Piece)
+        {
+            return;
+        }
+        let turn_number = *stats.turn_number;
+        let current_turn = self.board.current_turn();
+        let white_pieces_count = self.board.active_pieces(&Color::White).len();
+        let black_pieces_count = self.board.active_pieces(&Color::Black).len();
+        let bare_king_color = match (white_pieces_count, black_pieces_count) {
+            (1, _) => Some(Color::White),
+            (_, 1) => Some(Color::Black),
+            _ => None,
+        };
+        if let Some(color) = bare_king_color {
+            let strong_side_stats = &pieces_stats[&color.inverse()];
+            let moves_count = Counting::piece_honor_moves_count(
+                strong_side_stats.rooks_count,
+                strong_side_stats
+                    .custom_pieces_count
+                    .get("Khon")
+                    .copied()
+                    .unwrap_or(0),
+                strong_side_stats.knights_count,
+            );
+            self.counting = Some(Counting::piece_honor(
+                color,
+                current_turn,
+                turn_number,
+                moves_count,
+                white_pieces_count + black_pieces_count,
+            ));
+        } else if self.counting.is_none() {
+            // The side with fewer pieces is the disadvantaged one. With equal numbers of pieces
+            // either side may count, so the side to move does.
+            let color = match white_pieces_count.cmp(&black_pieces_count) {
+                Ordering::Less => Color::White,
+                Ordering::Greater => Color::Black,
+                Ordering::Equal => *current_turn,
+            };
+            self.counting = Some(Counting::board_honor(color, current_turn, turn_number));
+        }
+    }
+
     fn update_pieces_balance(&mut self) {
         let stats = self.board.stats();
         let last_modification_was =
@@ -290,7 +524,8 @@ impl ClassicGame<ClassicHeatMap, ClassicSquaresMap> {
         let active_pieces = &stats.active_pieces_stats[color];
         let no_other_pieces = active_pieces.rooks_count == 0
             && active_pieces.queens_count == 0
-            && active_pieces.pawns_count == 0;
+            && active_pieces.pawns_count == 0
+            && active_pieces.custom_pieces_count.values().all(|count| *count == 0);
         no_other_pieces
             && ((active_pieces.knights_count == 2 && active_pieces.bishops_count == 0)
                 || (active_pieces.knights_count == 1 && active_pieces.bishops_count == 0)
@@ -304,10 +539,9 @@ mod tests {
     use super::*;
     use libtchess::castle_points::CastlePoints;
     use libtchess::promote_piece::PromotePiece;
-    use libtchess::utils::pretty_print::PrettyPrint;
 
-    fn move_piece(
-        classic_game: &mut ClassicGame<ClassicHeatMap, ClassicSquaresMap>,
+    fn move_piece<B: GameBoard<ClassicHeatMap, ClassicSquaresMap>>(
+        classic_game: &mut ClassicGame<ClassicHeatMap, ClassicSquaresMap, B>,
         point: Point,
         piece_move: PieceMove,
     ) {
@@ -323,8 +557,8 @@ mod tests {
         println!("{}", classic_game.board().pp());
     }
 
-    fn checkmate(
-        classic_game: &mut ClassicGame<ClassicHeatMap, ClassicSquaresMap>,
+    fn checkmate<B: GameBoard<ClassicHeatMap, ClassicSquaresMap>>(
+        classic_game: &mut ClassicGame<ClassicHeatMap, ClassicSquaresMap, B>,
         point: Point,
         piece_move: PieceMove,
         color: Color,
@@ -341,8 +575,8 @@ mod tests {
         println!("{}", classic_game.board().pp());
     }
 
-    fn draw_by_repetition(
-        classic_game: &mut ClassicGame<ClassicHeatMap, ClassicSquaresMap>,
+    fn draw_by_repetition<B: GameBoard<ClassicHeatMap, ClassicSquaresMap>>(
+        classic_game: &mut ClassicGame<ClassicHeatMap, ClassicSquaresMap, B>,
         point: Point,
         piece_move: PieceMove,
     ) {
@@ -366,7 +600,25 @@ mod tests {
 
             #[test]
             fn game() {
-                let mut classic_game = ClassicGame::classic_board();
+                play(ClassicGame::classic_board());
+            }
+
+            #[test]
+            fn game_with_lazy_moves() {
+                let mut config = ClassicGame::classic_config();
+                config.set_lazy_moves(true);
+                let classic_game: ClassicGame<_, _> = ClassicGame::with_config(config);
+                play(classic_game);
+            }
+
+            #[test]
+            fn game_on_bitboard() {
+                play(ClassicGame::classic_bitboard());
+            }
+
+            fn play<B: GameBoard<ClassicHeatMap, ClassicSquaresMap>>(
+                mut classic_game: ClassicGame<ClassicHeatMap, ClassicSquaresMap, B>,
+            ) {
 
                 // 1. e4 c5 2. d4 d6 3. dxc5 dxc5 4. Qxd8+ Kxd8 5. Be3 e6 6. Bb5 Bd7 7. Nc3 Bxb5 8.
                 // Nxb5 Nc6 9. O-O-O+ Kc8 10. Nf3 Nf6 11. e5 Nd5 12. Nd6+ Bxd6 13. exd6 Kd7 14.
@@ -706,7 +958,25 @@ mod tests {
 
             #[test]
             fn game() {
-                let mut classic_game = ClassicGame::classic_board();
+                play(ClassicGame::classic_board());
+            }
+
+            #[test]
+            fn game_with_lazy_moves() {
+                let mut config = ClassicGame::classic_config();
+                config.set_lazy_moves(true);
+                let classic_game: ClassicGame<_, _> = ClassicGame::with_config(config);
+                play(classic_game);
+            }
+
+            #[test]
+            fn game_on_bitboard() {
+                play(ClassicGame::classic_bitboard());
+            }
+
+            fn play<B: GameBoard<ClassicHeatMap, ClassicSquaresMap>>(
+                mut classic_game: ClassicGame<ClassicHeatMap, ClassicSquaresMap, B>,
+            ) {
 
                 // https://www.chess.com/games/view/765
                 // 1.e4 e5 2.Nf3 d6 3.d4 Bg4 4.dxe5 Bxf3 5.Qxf3 dxe5 6.Bc4 Nf6 7.Qb3 Qe7 8.Nc3 c6
@@ -925,7 +1195,25 @@ mod tests {
 
             #[test]
             fn game() {
-                let mut classic_game = ClassicGame::classic_board();
+                play(ClassicGame::classic_board());
+            }
+
+            #[test]
+            fn game_with_lazy_moves() {
+                let mut config = ClassicGame::classic_config();
+                config.set_lazy_moves(true);
+                let classic_game: ClassicGame<_, _> = ClassicGame::with_config(config);
+                play(classic_game);
+            }
+
+            #[test]
+            fn game_on_bitboard() {
+                play(ClassicGame::classic_bitboard());
+            }
+
+            fn play<B: GameBoard<ClassicHeatMap, ClassicSquaresMap>>(
+                mut classic_game: ClassicGame<ClassicHeatMap, ClassicSquaresMap, B>,
+            ) {
                 // https://www.chess.com/games/view/75289
                 // 1. Nf3 Nf6 2. c4 g6 3. Nc3 Bg7 4. d4 O-O 5. Bf4 d5 6. Qb3 $6 dxc4 7. Qxc4 c6 8.
                 // e4 Nbd7 $2 9. Rd1 Nb6 $6 10. Qc5 $2 Bg4 11. Bg5 $4 Na4 $3 12. Qa3 Nxc3 13. bxc3 Nxe4
@@ -1442,7 +1730,25 @@ mod tests {
 
             #[test]
             fn game() {
-                let mut classic_game = ClassicGame::classic_board();
+                play(ClassicGame::classic_board());
+            }
+
+            #[test]
+            fn game_with_lazy_moves() {
+                let mut config = ClassicGame::classic_config();
+                config.set_lazy_moves(true);
+                let classic_game: ClassicGame<_, _> = ClassicGame::with_config(config);
+                play(classic_game);
+            }
+
+            #[test]
+            fn game_on_bitboard() {
+                play(ClassicGame::classic_bitboard());
+            }
+
+            fn play<B: GameBoard<ClassicHeatMap, ClassicSquaresMap>>(
+                mut classic_game: ClassicGame<ClassicHeatMap, ClassicSquaresMap, B>,
+            ) {
                 // https://www.chess.com/analysis/collection/immortal-games-uZmNVXMY/2zXEwjhEni
                 // 1. e4 e5 2. Nc3 Nc6 3. f4 exf4 4. d4 Qh4+ 5. Ke2 d5 6. exd5 Bg4+ 7. Nf3 O-O-O 8.
                 // dxc6 Bc5 9. cxb7+ Kb8 10. Nb5 Nf6 11. c3 Rhe8+ 12. Kd3 Bf5+ 13. Kc4 Be6+ 14.
@@ -1800,5 +2106,636 @@ mod tests {
                 );
             }
         }
+
+        mod atomic_knight_explodes_the_king {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let mut classic_game = ClassicGame::atomic_board();
+
+                // 1. Nf3 a6 2. Ng5 a5 3. Nxf7
+                move_piece(
+                    &mut classic_game,
+                    Point::new(7, 1),
+                    PieceMove::Point(Point::new(6, 3)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 7),
+                    PieceMove::Point(Point::new(1, 6)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(6, 3),
+                    PieceMove::Point(Point::new(7, 5)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 6),
+                    PieceMove::Point(Point::new(1, 5)),
+                );
+                assert_eq!(
+                    classic_game
+                        .move_piece_at(&Point::new(7, 5), &PieceMove::Point(Point::new(6, 7))),
+                    MoveResult::GameEnded(GameResult::KingExploded(Color::Black))
+                );
+                // The knight explodes together with the king, the bishop and the knight of g8
+                let board = classic_game.board();
+                assert!(board.piece_id_at(&Point::new(6, 7)).is_none());
+                assert!(board.piece_id_at(&Point::new(7, 8)).is_none());
+                assert!(board.piece_id_at(&Point::new(7, 7)).is_some());
+            }
+        }
+
+        mod antichess_compulsory_captures {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let mut classic_game = ClassicGame::antichess_board();
+
+                // 1. e4 d5 2. exd5 Qxd5
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 2),
+                    PieceMove::LongMove(Point::new(5, 4)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(4, 7),
+                    PieceMove::LongMove(Point::new(4, 5)),
+                );
+                // The capture can't be avoided
+                assert_eq!(
+                    classic_game
+                        .move_piece_at(&Point::new(7, 1), &PieceMove::Point(Point::new(6, 3))),
+                    MoveResult::IllegalMove
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 4),
+                    PieceMove::Point(Point::new(4, 5)),
+                );
+                assert_eq!(
+                    classic_game.board().legal_moves(&Color::Black),
+                    vec![(
+                        *classic_game.board().piece_id_at(&Point::new(4, 8)).unwrap(),
+                        PieceMove::Point(Point::new(4, 5))
+                    )]
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(4, 8),
+                    PieceMove::Point(Point::new(4, 5)),
+                );
+            }
+        }
+
+        mod three_check_bishop_and_queen {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let mut classic_game = ClassicGame::three_check_board();
+
+                // 1. e4 d5 2. Bb5+ c6 3. Bxc6+ bxc6 4. Qf3 a6 5. Qxf7+
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 2),
+                    PieceMove::LongMove(Point::new(5, 4)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(4, 7),
+                    PieceMove::LongMove(Point::new(4, 5)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(6, 1),
+                    PieceMove::Point(Point::new(2, 5)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(3, 7),
+                    PieceMove::Point(Point::new(3, 6)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(2, 5),
+                    PieceMove::Point(Point::new(3, 6)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(2, 7),
+                    PieceMove::Point(Point::new(3, 6)),
+                );
+                assert_eq!(classic_game.board().stats().checks_given[&Color::White], 2);
+                move_piece(
+                    &mut classic_game,
+                    Point::new(4, 1),
+                    PieceMove::Point(Point::new(6, 3)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 7),
+                    PieceMove::Point(Point::new(1, 6)),
+                );
+                assert_eq!(
+                    classic_game
+                        .move_piece_at(&Point::new(6, 3), &PieceMove::Point(Point::new(6, 7))),
+                    MoveResult::GameEnded(GameResult::CheckLimitReached(Color::Black))
+                );
+            }
+        }
+
+        mod king_walks_to_the_hill {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let mut classic_game = ClassicGame::king_of_the_hill_board();
+
+                // 1. e4 e5 2. Ke2 a6 3. Kd3 a5 4. Kc4 a4 5. Kd5
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 2),
+                    PieceMove::LongMove(Point::new(5, 4)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 7),
+                    PieceMove::LongMove(Point::new(5, 5)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 1),
+                    PieceMove::Point(Point::new(5, 2)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 7),
+                    PieceMove::Point(Point::new(1, 6)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 2),
+                    PieceMove::Point(Point::new(4, 3)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 6),
+                    PieceMove::Point(Point::new(1, 5)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(4, 3),
+                    PieceMove::Point(Point::new(3, 4)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 5),
+                    PieceMove::Point(Point::new(1, 4)),
+                );
+                assert_eq!(
+                    classic_game
+                        .move_piece_at(&Point::new(3, 4), &PieceMove::Point(Point::new(4, 5))),
+                    MoveResult::GameEnded(GameResult::KingOnTheHill(Color::White))
+                );
+            }
+        }
+
+        mod horde_captures_the_pawns_back {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let mut classic_game = ClassicGame::horde_board();
+                assert_eq!(classic_game.board().active_pieces(&Color::White).len(), 36);
+                assert!(classic_game.board().king(&Color::White).is_none());
+
+                // 1. f6 exf6 2. gxf6
+                move_piece(
+                    &mut classic_game,
+                    Point::new(6, 5),
+                    PieceMove::Point(Point::new(6, 6)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 7),
+                    PieceMove::Point(Point::new(6, 6)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(7, 5),
+                    PieceMove::Point(Point::new(6, 6)),
+                );
+                assert_eq!(classic_game.board().active_pieces(&Color::White).len(), 35);
+                assert_eq!(classic_game.board().active_pieces(&Color::Black).len(), 15);
+                assert_eq!(classic_game.game_result(), None);
+            }
+        }
+
+        mod horde_loses_the_last_pawn {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let config = ClassicGame::classic_config();
+                let mut classic_game: ClassicGame<_, _> =
+                    ClassicGame::with_setup(config, |board: &mut Board<_, _>| {
+                        board.add_piece("Pawn", Color::White, vec![], vec![], Point::new(1, 3));
+                        board.add_piece("King", Color::Black, vec![], vec![], Point::new(8, 8));
+                        board.add_piece("Rook", Color::Black, vec![], vec![], Point::new(1, 8));
+                    });
+
+                // 1. a4 Rxa4
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 3),
+                    PieceMove::Point(Point::new(1, 4)),
+                );
+                assert_eq!(
+                    classic_game
+                        .move_piece_at(&Point::new(1, 8), &PieceMove::Point(Point::new(1, 4))),
+                    MoveResult::GameEnded(GameResult::AllPiecesCaptured(Color::White))
+                );
+            }
+        }
+
+        mod racing_kings_draw {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let mut classic_game = ClassicGame::racing_kings_board();
+
+                // Giving check is not allowed
+                assert_eq!(
+                    classic_game
+                        .move_piece_at(&Point::new(5, 2), &PieceMove::Point(Point::new(3, 3))),
+                    MoveResult::IllegalMove
+                );
+                // 1. Kh3 Ka3 2. Kh4 Ka4 3. Kh5 Ka5 4. Kh6 Ka6 5. Kg7 Kb7 6. Kg8 Kb8
+                move_piece(
+                    &mut classic_game,
+                    Point::new(8, 2),
+                    PieceMove::Point(Point::new(8, 3)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 2),
+                    PieceMove::Point(Point::new(1, 3)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(8, 3),
+                    PieceMove::Point(Point::new(8, 4)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 3),
+                    PieceMove::Point(Point::new(1, 4)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(8, 4),
+                    PieceMove::Point(Point::new(8, 5)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 4),
+                    PieceMove::Point(Point::new(1, 5)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(8, 5),
+                    PieceMove::Point(Point::new(8, 6)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 5),
+                    PieceMove::Point(Point::new(1, 6)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(8, 6),
+                    PieceMove::Point(Point::new(7, 7)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 6),
+                    PieceMove::Point(Point::new(2, 7)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(7, 7),
+                    PieceMove::Point(Point::new(7, 8)),
+                );
+                assert_eq!(classic_game.game_result(), None);
+                assert_eq!(
+                    classic_game
+                        .move_piece_at(&Point::new(2, 7), &PieceMove::Point(Point::new(2, 8))),
+                    MoveResult::GameEnded(GameResult::BothKingsOnTheHillDraw)
+                );
+            }
+        }
+
+        mod racing_kings_white_wins {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let mut classic_game = ClassicGame::racing_kings_board();
+
+                // 1. Kh3 Ka3 2. Kh4 Ka4 3. Kh5 Ka5 4. Kh6 Ka6 5. Kg7 Kb7 6. Kg8 Ka6
+                move_piece(
+                    &mut classic_game,
+                    Point::new(8, 2),
+                    PieceMove::Point(Point::new(8, 3)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 2),
+                    PieceMove::Point(Point::new(1, 3)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(8, 3),
+                    PieceMove::Point(Point::new(8, 4)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 3),
+                    PieceMove::Point(Point::new(1, 4)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(8, 4),
+                    PieceMove::Point(Point::new(8, 5)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 4),
+                    PieceMove::Point(Point::new(1, 5)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(8, 5),
+                    PieceMove::Point(Point::new(8, 6)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 5),
+                    PieceMove::Point(Point::new(1, 6)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(8, 6),
+                    PieceMove::Point(Point::new(7, 7)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 6),
+                    PieceMove::Point(Point::new(2, 7)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(7, 7),
+                    PieceMove::Point(Point::new(7, 8)),
+                );
+                assert_eq!(
+                    classic_game
+                        .move_piece_at(&Point::new(2, 7), &PieceMove::Point(Point::new(1, 6))),
+                    MoveResult::GameEnded(GameResult::KingOnTheHill(Color::White))
+                );
+            }
+        }
+
+        mod makruk_initial_moves {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let mut classic_game = ClassicGame::makruk_board();
+                // 8 pawn moves, 3 moves of each khon, 2 of the met, 3 of the king and a single
+                // move of each knight and each rook onto the empty second rank
+                assert_eq!(classic_game.board().legal_moves(&Color::White).len(), 23);
+                assert_eq!(classic_game.board().legal_moves(&Color::Black).len(), 23);
+                // No long pawn moves
+                assert_eq!(
+                    classic_game
+                        .move_piece_at(&Point::new(5, 3), &PieceMove::LongMove(Point::new(5, 5))),
+                    MoveResult::IllegalMove
+                );
+                // 1. Se2 Se7
+                move_piece(
+                    &mut classic_game,
+                    Point::new(6, 1),
+                    PieceMove::Point(Point::new(5, 2)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(6, 8),
+                    PieceMove::Point(Point::new(5, 7)),
+                );
+                assert!(classic_game.counting.is_none());
+            }
+        }
+
+        mod makruk_pawn_promotes_to_met {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let config = ClassicGame::makruk_config();
+                let mut classic_game: ClassicGame<_, _> = ClassicGame::with_draw_rule(
+                    config,
+                    DrawRule::Counting,
+                    |board: &mut Board<_, _>| {
+                        board.add_piece("King", Color::White, vec![], vec![], Point::new(1, 1));
+                        board.add_piece("Pawn", Color::White, vec![], vec![], Point::new(5, 5));
+                        board.add_piece("King", Color::Black, vec![], vec![], Point::new(8, 8));
+                        board.add_piece("Pawn", Color::Black, vec![], vec![], Point::new(8, 6));
+                    },
+                );
+
+                // The pawn can't stay unpromoted on the sixth rank
+                assert_eq!(
+                    classic_game
+                        .move_piece_at(&Point::new(5, 5), &PieceMove::Point(Point::new(5, 6))),
+                    MoveResult::IllegalMove
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 5),
+                    PieceMove::Promote(Point::new(5, 6), PromotePiece::Promoted),
+                );
+                let met_id = classic_game.board().piece_id_at(&Point::new(5, 6)).unwrap();
+                assert_eq!(
+                    classic_game.board().active_pieces(&Color::White)[met_id].name(),
+                    "Met"
+                );
+                // The black pawn is still on the board
+                assert!(classic_game.counting.is_none());
+            }
+        }
+
+        mod makruk_piece_honor_draw {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let config = ClassicGame::makruk_config();
+                let mut classic_game: ClassicGame<_, _> = ClassicGame::with_draw_rule(
+                    config,
+                    DrawRule::Counting,
+                    |board: &mut Board<_, _>| {
+                        board.add_piece("King", Color::White, vec![], vec![], Point::new(1, 1));
+                        board.add_piece("Rook", Color::White, vec![], vec![], Point::new(1, 8));
+                        board.add_piece("Rook", Color::White, vec![], vec![], Point::new(8, 1));
+                        board.add_piece("King", Color::Black, vec![], vec![], Point::new(5, 5));
+                    },
+                );
+
+                // Two rooks give 8 moves to count up to, starting from 4 pieces on the board
+                // 1. Ra7 Ke4 2. Ra6 Ke5 3. Rb6 Ke4 4. Rb7 Ke5 5. Rc7
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 8),
+                    PieceMove::Point(Point::new(1, 7)),
+                );
+                assert_eq!(
+                    classic_game.counting.map(|counting| *counting.honor()),
+                    Some(Honor::Piece)
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 5),
+                    PieceMove::Point(Point::new(5, 4)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 7),
+                    PieceMove::Point(Point::new(1, 6)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 4),
+                    PieceMove::Point(Point::new(5, 5)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 6),
+                    PieceMove::Point(Point::new(2, 6)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 5),
+                    PieceMove::Point(Point::new(5, 4)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(2, 6),
+                    PieceMove::Point(Point::new(2, 7)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 4),
+                    PieceMove::Point(Point::new(5, 5)),
+                );
+                assert_eq!(
+                    classic_game
+                        .move_piece_at(&Point::new(2, 7), &PieceMove::Point(Point::new(3, 7))),
+                    MoveResult::GameEnded(GameResult::PieceHonorDraw)
+                );
+            }
+        }
+
+        mod makruk_board_honor_turns_into_piece_honor {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let config = ClassicGame::makruk_config();
+                let mut classic_game: ClassicGame<_, _> = ClassicGame::with_draw_rule(
+                    config,
+                    DrawRule::Counting,
+                    |board: &mut Board<_, _>| {
+                        board.add_piece("King", Color::White, vec![], vec![], Point::new(1, 1));
+                        board.add_piece("Rook", Color::White, vec![], vec![], Point::new(4, 1));
+                        board.add_piece("Khon", Color::White, vec![], vec![], Point::new(3, 3));
+                        board.add_piece("King", Color::Black, vec![], vec![], Point::new(8, 8));
+                        board.add_piece("Met", Color::Black, vec![], vec![], Point::new(4, 6));
+                    },
+                );
+
+                // 1. Kb1 Kg8 2. Rxd6
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 1),
+                    PieceMove::Point(Point::new(2, 1)),
+                );
+                assert_eq!(
+                    classic_game.counting.map(|counting| *counting.honor()),
+                    Some(Honor::Board)
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(8, 8),
+                    PieceMove::Point(Point::new(7, 8)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(4, 1),
+                    PieceMove::Point(Point::new(4, 6)),
+                );
+                assert_eq!(
+                    classic_game.counting.map(|counting| *counting.honor()),
+                    Some(Honor::Piece)
+                );
+            }
+        }
+
+        mod makruk_board_honor_is_counted_by_the_disadvantaged_side {
+            use super::*;
+
+            #[test]
+            fn game() {
+                let config = ClassicGame::makruk_config();
+                let mut classic_game: ClassicGame<_, _> = ClassicGame::with_draw_rule(
+                    config,
+                    DrawRule::Counting,
+                    |board: &mut Board<_, _>| {
+                        board.add_piece("King", Color::White, vec![], vec![], Point::new(1, 1));
+                        board.add_piece("Rook", Color::White, vec![], vec![], Point::new(4, 1));
+                        board.add_piece("Knight", Color::White, vec![], vec![], Point::new(2, 1));
+                        board.add_piece("Pawn", Color::White, vec![], vec![], Point::new(4, 5));
+                        board.add_piece("King", Color::Black, vec![], vec![], Point::new(8, 8));
+                        board.add_piece("Met", Color::Black, vec![], vec![], Point::new(5, 6));
+                    },
+                );
+
+                // 1. Kb2 Mxd5. The last pawn is captured by black, so the stronger white side is
+                // to move when the count starts.
+                move_piece(
+                    &mut classic_game,
+                    Point::new(1, 1),
+                    PieceMove::Point(Point::new(2, 2)),
+                );
+                move_piece(
+                    &mut classic_game,
+                    Point::new(5, 6),
+                    PieceMove::Point(Point::new(4, 5)),
+                );
+                let counting = classic_game.counting.unwrap();
+                assert_eq!(counting.honor(), &Honor::Board);
+                assert_eq!(counting.color(), &Color::Black);
+                // The count starts with the next move of black
+                let turn_number = *classic_game.board().stats().turn_number;
+                assert!(!counting.is_over(turn_number + 128));
+                assert!(counting.is_over(turn_number + 129));
+            }
+        }
     }
 }
@@ -2,7 +2,7 @@ use libtchess::color::Color;
 use libtchess::heat_map::HeatMap;
 use libtchess::piece::Piece;
 use libtchess::point::Point;
-use std::cell::Cell;
+use std::sync::atomic::{AtomicI32, Ordering};
 
 const BISHOP_P0_MAP: [[i16; 8]; 8] = [
     [324, 325, 325, 325, 325, 325, 325, 324],
@@ -131,37 +131,45 @@ const ROOK_P1_MAP: [[i16; 8]; 8] = [
     [498, 499, 499, 500, 500, 499, 499, 498],
 ];
 
-#[derive(Clone)]
 pub struct ClassicHeatMap {
-    phase_ratio: Cell<i32>,
+    phase_ratio: AtomicI32,
 }
 
 impl ClassicHeatMap {
     pub fn init() -> Self {
         Self {
-            phase_ratio: Cell::new(0),
+            phase_ratio: AtomicI32::new(0),
         }
     }
 
     pub fn update_phase_ratio(&self, ratio: i32) {
-        self.phase_ratio.replace(ratio);
+        self.phase_ratio.store(ratio, Ordering::Relaxed);
+    }
+}
+
+impl Clone for ClassicHeatMap {
+    fn clone(&self) -> Self {
+        Self {
+            phase_ratio: AtomicI32::new(self.phase_ratio.load(Ordering::Relaxed)),
+        }
     }
 }
 
 impl HeatMap for ClassicHeatMap {
     // The actual implementation will be presented later. For now just put stub values here.
     fn positional_value(&self, piece: &Piece, position: &Point) -> i16 {
-        let (x, y) = match piece.color() {
-            Color::White => (
+        let (x, y) = if *piece.color() == Color::White {
+            (
                 *position.x().value() as usize - 1,
                 8 - *position.y().value() as usize,
-            ),
-            Color::Black => (
+            )
+        } else {
+            (
                 8 - *position.x().value() as usize,
                 *position.y().value() as usize - 1,
-            ),
+            )
         };
-        let phase_ratio = self.phase_ratio.get();
+        let phase_ratio = self.phase_ratio.load(Ordering::Relaxed);
         let calc_value = |p0_map: &[[i16; 8]; 8], p1_map: &[[i16; 8]; 8]| {
             (((100 - phase_ratio) * (p0_map[y][x] as i32) + phase_ratio * (p1_map[y][x] as i32))
                 / 100) as i16
@@ -173,6 +181,7 @@ impl HeatMap for ClassicHeatMap {
             Piece::Pawn(_) => calc_value(&PAWN_P0_MAP, &PAWN_P1_MAP),
             Piece::Queen(_) => calc_value(&QUEEN_P0_MAP, &QUEEN_P1_MAP),
             Piece::Rook(_) => calc_value(&ROOK_P0_MAP, &ROOK_P1_MAP),
+            Piece::Custom(p) => p.definition().value(),
             Piece::UnknownPiece(_) => panic!("Unknown piece can't be evaluated!"),
         }
     }
@@ -0,0 +1,113 @@
+use crate::game_result::GameResult;
+use libtchess::color::Color;
+
+const BOARD_HONOR_MOVES_COUNT: usize = 64;
+// Number of moves, the bare king side counts up to, by the strongest pieces of the other side
+const TWO_ROOKS_MOVES_COUNT: usize = 8;
+const ROOK_MOVES_COUNT: usize = 16;
+const TWO_KHONS_MOVES_COUNT: usize = 22;
+const TWO_KNIGHTS_MOVES_COUNT: usize = 32;
+const KHON_MOVES_COUNT: usize = 44;
+const OTHER_PIECES_MOVES_COUNT: usize = 64;
+
+// The rule, which draws the game going on for too long without any progress
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum DrawRule {
+    // Fifty moves without captures and pawn moves
+    FiftyMoveRule,
+    // Makruk counting rules, see Counting
+    Counting,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Honor {
+    // The count starts once no pawns are left on the board
+    Board,
+    // The count restarts once a side is left with the bare king
+    Piece,
+}
+
+// The count of the Makruk endgame. The game is drawn when the count reaches its limit, unless the
+// side with more pieces has mated the other one before.
+#[derive(Copy, Clone, Debug)]
+pub struct Counting {
+    honor: Honor,
+    color: Color,
+    started_at: usize,
+    turns_limit: usize,
+}
+
+impl Counting {
+    // The disadvantaged side counts its own moves. When the other side is to move, the count
+    // starts from the next turn.
+    pub fn board_honor(color: Color, current_turn: &Color, turn_number: usize) -> Self {
+        Self {
+            honor: Honor::Board,
+            color,
+            started_at: Self::first_counted_turn(&color, current_turn, turn_number),
+            turns_limit: BOARD_HONOR_MOVES_COUNT * 2,
+        }
+    }
+
+    // The bare king side starts counting from the number of pieces on the board, including both
+    // kings, up to the given number of moves
+    pub fn piece_honor(
+        color: Color,
+        current_turn: &Color,
+        turn_number: usize,
+        moves_count: usize,
+        pieces_count: usize,
+    ) -> Self {
+        Self {
+            honor: Honor::Piece,
+            color,
+            started_at: Self::first_counted_turn(&color, current_turn, turn_number),
+            turns_limit: moves_count.saturating_sub(pieces_count) * 2,
+        }
+    }
+
+    fn first_counted_turn(color: &Color, current_turn: &Color, turn_number: usize) -> usize {
+        if color == current_turn {
+            turn_number
+        } else {
+            turn_number + 1
+        }
+    }
+
+    // Number of moves to count up to by the strongest pieces of the side, which has not only the
+    // king
+    pub fn piece_honor_moves_count(
+        rooks_count: isize,
+        khons_count: isize,
+        knights_count: isize,
+    ) -> usize {
+        match (rooks_count, khons_count, knights_count) {
+            (2.., _, _) => TWO_ROOKS_MOVES_COUNT,
+            (1, _, _) => ROOK_MOVES_COUNT,
+            (_, 2.., _) => TWO_KHONS_MOVES_COUNT,
+            (_, _, 2..) => TWO_KNIGHTS_MOVES_COUNT,
+            (_, 1, _) => KHON_MOVES_COUNT,
+            _ => OTHER_PIECES_MOVES_COUNT,
+        }
+    }
+
+    pub fn honor(&self) -> &Honor {
+        &self.honor
+    }
+
+    // The side which counts its moves
+    pub fn color(&self) -> &Color {
+        &self.color
+    }
+
+    pub fn is_over(&self, turn_number: usize) -> bool {
+        turn_number.saturating_sub(self.started_at) >= self.turns_limit
+    }
+
+    pub fn game_result(&self) -> GameResult {
+        match self.honor {
+            Honor::Board => GameResult::BoardHonorDraw,
+            Honor::Piece => GameResult::PieceHonorDraw,
+        }
+    }
+}
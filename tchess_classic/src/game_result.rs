@@ -4,7 +4,23 @@ use libtchess::color::Color;
 pub enum GameResult {
     Stalemate(Color),
     Checkmate(Color),
+    // The king of the given color has exploded in atomic chess
+    KingExploded(Color),
+    // The given color has won the antichess game by losing all its pieces or by being stalemated
+    AntichessWin(Color),
+    // The given color has been checked the limit number of times in three-check
+    CheckLimitReached(Color),
+    // The king of the given color has reached the hill
+    KingOnTheHill(Color),
+    // All pieces of the given color have been captured, like the white pawns in Horde
+    AllPiecesCaptured(Color),
+    // Black king has reached the last rank right after the white one in Racing Kings
+    BothKingsOnTheHillDraw,
     InsufficientMaterialDraw,
     FiftyMoveRuleDraw,
+    // The count of Makruk has reached 64 moves, since no pawns are left on the board
+    BoardHonorDraw,
+    // The bare king side of Makruk has reached the limit of its count
+    PieceHonorDraw,
     DrawByRepetition,
 }
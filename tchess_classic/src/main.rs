@@ -6,7 +6,9 @@ mod classic_square_map;
 mod classic_heat_map;
 mod game_result;
 mod move_result;
-mod board_positions;
+mod draw_rule;
+mod makruk_pieces;
+mod variant_rules;
 // mod game_runner;
 
 fn main() {
@@ -0,0 +1,23 @@
+use libtchess::betza::BetzaPiece;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::piece_registry::PieceRegistry;
+
+// Steps one point diagonally. The pawn promotes to it.
+pub fn met() -> BetzaPiece {
+    BetzaPiece::new("Met", ColoredProperty::from(['M', 'm']), 200, "F")
+        .expect("Met notation must be valid!")
+}
+
+// Steps one point diagonally or one point forward
+pub fn khon() -> BetzaPiece {
+    BetzaPiece::new("Khon", ColoredProperty::from(['S', 's']), 250, "FfW")
+        .expect("Khon notation must be valid!")
+}
+
+// Classic pieces along with the met and the khon, which replace the queen and the bishop
+pub fn makruk_piece_registry() -> PieceRegistry {
+    let mut piece_registry = PieceRegistry::classic();
+    piece_registry.register(met());
+    piece_registry.register(khon());
+    piece_registry
+}
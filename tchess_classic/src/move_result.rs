@@ -1,8 +1,3 @@
 use crate::game_result::GameResult;
 
-#[derive(Eq, PartialEq, Debug)]
-pub enum MoveResult {
-    PieceMoved,
-    IllegalMove,
-    GameEnded(GameResult),
-}
+pub type MoveResult = libtchess::move_result::MoveResult<GameResult>;
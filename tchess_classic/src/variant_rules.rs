@@ -0,0 +1,112 @@
+use crate::game_result::GameResult;
+use libtchess::color::Color;
+use libtchess::game_board::GameBoard;
+use libtchess::heat_map::HeatMap;
+use libtchess::squares_map::SquaresMap;
+use libtchess::variant::Variant;
+
+// Results of the game, which depend on its variant. The game asks the variant first, and only
+// then applies the common rules, like the draw by repetition.
+pub trait VariantRules {
+    // Result, the variant decides on by its own rules right after the move
+    fn game_result<HT: HeatMap, SQ: SquaresMap, B: GameBoard<HT, SQ>>(
+        &self,
+        board: &B,
+    ) -> Option<GameResult>;
+
+    // Result of the game, when the side to move has no moves
+    fn no_moves_result<HT: HeatMap, SQ: SquaresMap, B: GameBoard<HT, SQ>>(
+        &self,
+        board: &B,
+    ) -> GameResult;
+
+    // Whether the game is drawn, when neither side has enough pieces to mate
+    fn has_insufficient_material_draw(&self) -> bool;
+}
+
+impl VariantRules for Variant {
+    fn game_result<HT: HeatMap, SQ: SquaresMap, B: GameBoard<HT, SQ>>(
+        &self,
+        board: &B,
+    ) -> Option<GameResult> {
+        let current_turn = *board.current_turn();
+        let moved_color = current_turn.inverse();
+        match self {
+            Variant::CheckLimit(_) if board.is_check_limit_reached(&moved_color) => {
+                Some(GameResult::CheckLimitReached(current_turn))
+            }
+            // The side wins by losing all its pieces or by being stalemated
+            Variant::Antichess if board.has_no_moves(&current_turn) => {
+                Some(GameResult::AntichessWin(current_turn))
+            }
+            Variant::RacingKings => racing_kings_result(board, &moved_color),
+            _ if board.is_king_on_the_hill(&moved_color) => {
+                Some(GameResult::KingOnTheHill(moved_color))
+            }
+            _ => None,
+        }
+    }
+
+    fn no_moves_result<HT: HeatMap, SQ: SquaresMap, B: GameBoard<HT, SQ>>(
+        &self,
+        board: &B,
+    ) -> GameResult {
+        let current_turn = *board.current_turn();
+        // The king is not tracked by the atomic board, though it is still on the board. The side
+        // without royal pieces, like the horde, is stalemated as well.
+        if board.king(&current_turn).is_some()
+            || self == &Variant::Atomic
+            || board
+                .active_pieces(&current_turn)
+                .values()
+                .all(|piece| !board.config().is_royal(piece.name()))
+        {
+            if board.is_checked(&current_turn) {
+                GameResult::Checkmate(current_turn)
+            } else {
+                GameResult::Stalemate(current_turn)
+            }
+        } else {
+            GameResult::InsufficientMaterialDraw
+        }
+    }
+
+    // The lone king can still be captured in antichess, and it can still reach the last rank in
+    // Racing Kings
+    fn has_insufficient_material_draw(&self) -> bool {
+        !matches!(self, Variant::Antichess | Variant::RacingKings)
+    }
+}
+
+// Black gets the last move to reach the last rank as well, once the white king is there
+fn racing_kings_result<HT: HeatMap, SQ: SquaresMap, B: GameBoard<HT, SQ>>(
+    board: &B,
+    moved_color: &Color,
+) -> Option<GameResult> {
+    if moved_color == &Color::Black && board.is_king_on_the_hill(&Color::White) {
+        return if board.is_king_on_the_hill(&Color::Black) {
+            Some(GameResult::BothKingsOnTheHillDraw)
+        } else {
+            Some(GameResult::KingOnTheHill(Color::White))
+        };
+    }
+    (board.is_king_on_the_hill(moved_color)
+        && !(moved_color == &Color::White && can_reach_the_hill(board, &Color::Black)))
+    .then_some(GameResult::KingOnTheHill(*moved_color))
+}
+
+// Whether the king of the given color can step on the hill with its next move
+fn can_reach_the_hill<HT: HeatMap, SQ: SquaresMap, B: GameBoard<HT, SQ>>(
+    board: &B,
+    color: &Color,
+) -> bool {
+    let Some(king) = board.king(color) else {
+        return false;
+    };
+    board
+        .legal_moves(color)
+        .iter()
+        .any(|(piece_id, piece_move)| {
+            piece_id == king.id() && board.config().is_hill_square(piece_move.destination())
+        })
+}
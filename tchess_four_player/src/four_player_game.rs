@@ -0,0 +1,587 @@
+use crate::four_player_heat_map::FourPlayerHeatMap;
+use crate::four_player_squares_map::FourPlayerSquaresMap;
+use crate::game_mode::{EliminatedPieces, GameMode};
+use crate::game_result::GameResult;
+use crate::move_result::MoveResult;
+use crate::side::{BOARD_SIZE, Side};
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::buff::Buff;
+use libtchess::castle_points::CastleSide;
+use libtchess::castle_rule::{CastleDestination, CastleRule};
+use libtchess::castle_x_points::{CastleXPoints, KingCastleXPoint, RookCastleXPoint};
+use libtchess::collections::HashMap;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece::Piece;
+use libtchess::piece_move::PieceMove;
+use libtchess::player::Player;
+use libtchess::point::Point;
+use libtchess::promotion_zone::PromotionZone;
+use rustc_hash::FxBuildHasher;
+
+// Back rank of every side, from the left to the right as the side sees it
+pub const BACK_RANK: [&str; 8] = [
+    "Rook", "Knight", "Bishop", "Queen", "King", "Bishop", "Knight", "Rook",
+];
+// Column of the side's view, the back rank starts from
+const BACK_RANK_X: i16 = 4;
+// Rows of the side's view, where its pawns get promoted
+const FREE_FOR_ALL_PROMOTION_ROW: i16 = 8;
+const TEAMS_PROMOTION_ROW: i16 = 11;
+// The king goes two points towards the rook, the rook lands right next to the initial point of
+// the king on both sides
+const CASTLE_DESTINATION: CastleDestination = CastleDestination::Distance { king: 2, rook: 1 };
+
+// Points of the free-for-all game. The side scores for checkmating another side, for being
+// stalemated and for capturing the pieces.
+const MATE_POINTS: u16 = 20;
+const STALEMATE_POINTS: u16 = 20;
+
+fn capture_points(piece_name: &str) -> u16 {
+    match piece_name {
+        "Pawn" => 1,
+        "Knight" => 3,
+        "Bishop" | "Rook" => 5,
+        "Queen" => 9,
+        // The king can only be captured when it is left in check by the move of another side
+        "King" => MATE_POINTS,
+        _ => 0,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FourPlayerPiece {
+    name: String,
+    side: Side,
+}
+
+impl FourPlayerPiece {
+    fn from_piece(piece: &Piece) -> Self {
+        Self {
+            name: piece.name().to_string(),
+            side: Side::from_color(piece.color()),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn side(&self) -> &Side {
+        &self.side
+    }
+}
+
+// Four sides play on the cross-shaped board, taking their turns clockwise. Every side is a color
+// of the board, facing the center from its own edge. Sides of the same team are allies, thus
+// pieces of the partner can't be captured. Pieces, which remain after their side has been
+// eliminated, are taken off the board and leave void points behind, so they can neither move nor
+// be captured.
+pub struct FourPlayerGame {
+    mode: GameMode,
+    eliminated_pieces: EliminatedPieces,
+    board: Board<FourPlayerHeatMap, FourPlayerSquaresMap>,
+    remaining_pieces: HashMap<Point, FourPlayerPiece, FxBuildHasher>,
+    eliminated_sides: Vec<Side>,
+    scores: HashMap<Side, u16, FxBuildHasher>,
+    game_result: Option<GameResult>,
+}
+
+impl FourPlayerGame {
+    pub fn empty(mode: GameMode, eliminated_pieces: EliminatedPieces) -> Self {
+        Self {
+            mode,
+            eliminated_pieces,
+            board: Board::empty(Self::config(mode)),
+            remaining_pieces: HashMap::default(),
+            eliminated_sides: vec![],
+            scores: HashMap::default(),
+            game_result: None,
+        }
+    }
+
+    // Places the back rank and a row of pawns in front of it for each side
+    pub fn new(mode: GameMode, eliminated_pieces: EliminatedPieces) -> Self {
+        let mut game = Self::empty(mode, eliminated_pieces);
+        for side in Side::all_variants() {
+            for (x, name) in (BACK_RANK_X..).zip(BACK_RANK.iter()) {
+                let buffs = match *name {
+                    "Rook" | "King" => vec![Buff::Castle],
+                    _ => vec![],
+                };
+                game.add_piece(name, side, buffs, side.rotate_from_view(&Point::new(x, 1)));
+                game.add_piece(
+                    "Pawn",
+                    side,
+                    vec![Buff::AdditionalPoint],
+                    side.rotate_from_view(&Point::new(x, 2)),
+                );
+            }
+        }
+        game
+    }
+
+    fn config(mode: GameMode) -> BoardConfig<FourPlayerHeatMap, FourPlayerSquaresMap> {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(BOARD_SIZE, BOARD_SIZE));
+        let mut config = BoardConfig::new(
+            CastleXPoints(KingCastleXPoint(10), RookCastleXPoint(9)),
+            CastleXPoints(KingCastleXPoint(6), RookCastleXPoint(7)),
+            FourPlayerHeatMap::init(),
+            FourPlayerSquaresMap::init(),
+            dimension,
+            Player::Human,
+            Player::Human,
+        );
+        // The kings of the opposite sides stand on the different columns, thus the castle is given
+        // by the distance rather than by the points
+        config.set_castle_rules(vec![
+            CastleRule::new(CastleSide::King, CASTLE_DESTINATION, false),
+            CastleRule::new(CastleSide::Queen, CASTLE_DESTINATION, false),
+        ]);
+        config.set_sides(Side::all_variants().map(|side| side.color(&mode)).to_vec());
+        let promotion_row = match mode {
+            GameMode::Teams => TEAMS_PROMOTION_ROW,
+            GameMode::FreeForAll => FREE_FOR_ALL_PROMOTION_ROW,
+        };
+        for side in Side::all_variants() {
+            let promotion_squares = (1..=BOARD_SIZE)
+                .map(|x| side.rotate_from_view(&Point::new(x, promotion_row)))
+                .collect();
+            config.set_promotion_zone(
+                &side.color(&mode),
+                PromotionZone::Squares(promotion_squares),
+            );
+        }
+        config
+    }
+
+    pub fn add_piece(&mut self, name: &str, side: Side, buffs: Vec<Buff>, position: Point) {
+        self.board
+            .add_piece(name, side.color(&self.mode), buffs, vec![], position);
+    }
+
+    pub fn board(&self) -> &Board<FourPlayerHeatMap, FourPlayerSquaresMap> {
+        &self.board
+    }
+
+    pub fn piece_at(&self, point: &Point) -> Option<FourPlayerPiece> {
+        match self.board.piece_at(point) {
+            Some(piece) => Some(FourPlayerPiece::from_piece(piece)),
+            None => self.remaining_pieces.get(point).cloned(),
+        }
+    }
+
+    pub fn current_side(&self) -> Side {
+        Side::from_color(self.board.current_turn())
+    }
+
+    pub fn score(&self, side: &Side) -> u16 {
+        self.scores.get(side).copied().unwrap_or(0)
+    }
+
+    pub fn is_eliminated(&self, side: &Side) -> bool {
+        self.eliminated_sides.contains(side)
+    }
+
+    pub fn game_result(&self) -> Option<&GameResult> {
+        self.game_result.as_ref()
+    }
+
+    pub fn is_checked(&self, side: &Side) -> bool {
+        self.board.is_checked(&side.color(&self.mode))
+    }
+
+    // Moves of the side to move, from a position to a move
+    pub fn legal_moves(&self) -> Vec<(Point, PieceMove)> {
+        self.board
+            .legal_moves(self.board.current_turn())
+            .into_iter()
+            .map(|(piece_id, piece_move)| {
+                let piece = self
+                    .board
+                    .find_piece_by_id(&piece_id)
+                    .expect("Logical error: the piece with legal moves must be on the board!");
+                (*piece.current_position(), piece_move)
+            })
+            .collect()
+    }
+
+    pub fn move_piece(&mut self, position: &Point, piece_move: &PieceMove) -> MoveResult {
+        if let Some(game_result) = self.game_result {
+            return MoveResult::GameEnded(game_result);
+        }
+        if !self.legal_moves().contains(&(*position, *piece_move)) {
+            return MoveResult::IllegalMove;
+        }
+
+        let side = self.current_side();
+        let captured_point = match piece_move {
+            PieceMove::Point(point) | PieceMove::LongMove(point) | PieceMove::Promote(point, _) => {
+                Some(point)
+            }
+            PieceMove::EnPassant(_, enemy_piece_point) => Some(enemy_piece_point),
+            PieceMove::Castle(_) | PieceMove::Drop(_) => None,
+        };
+        let captured_piece = captured_point
+            .and_then(|point| self.board.piece_at(point))
+            .map(FourPlayerPiece::from_piece);
+        let &piece_id = self
+            .board
+            .piece_id_at(position)
+            .unwrap_or_else(|| panic!("Could not find piece at {} position", position));
+        self.board
+            .move_piece(&piece_id, piece_move)
+            .expect("Logical mistake: the legal move must be made on the board!");
+        if let Some(captured_piece) = captured_piece {
+            self.capture(side, captured_piece);
+        }
+        self.pass_turn(side);
+
+        match self.game_result {
+            Some(game_result) => MoveResult::GameEnded(game_result),
+            None => MoveResult::PieceMoved,
+        }
+    }
+
+    fn capture(&mut self, side: Side, captured_piece: FourPlayerPiece) {
+        if self.mode == GameMode::FreeForAll {
+            self.add_points(side, capture_points(captured_piece.name()));
+        }
+        if captured_piece.name() == "King" {
+            self.eliminate(captured_piece.side);
+        }
+    }
+
+    fn add_points(&mut self, side: Side, points: u16) {
+        self.scores.insert(side, self.score(&side) + points);
+    }
+
+    // Passes the turn to the next side which is able to move. Sides without moves are eliminated
+    // on the way.
+    fn pass_turn(&mut self, moved_side: Side) {
+        while self.game_result.is_none() {
+            let active_sides_count = Side::all_variants()
+                .iter()
+                .filter(|side| !self.is_eliminated(side))
+                .count();
+            if active_sides_count <= 1 {
+                self.game_result = Some(self.free_for_all_result());
+                return;
+            }
+            // The board passes the turn to the next side, no matter whether it has been eliminated
+            let mut side = self.current_side();
+            while self.is_eliminated(&side) {
+                side = side.next();
+            }
+            let color = side.color(&self.mode);
+            self.board.pass_turn(&color);
+            if !self.board.has_no_moves(&color) {
+                return;
+            }
+            let is_checked = self.board.is_checked(&color);
+            match self.mode {
+                GameMode::Teams if is_checked => self.eliminate(side),
+                GameMode::Teams => self.game_result = Some(GameResult::Draw),
+                GameMode::FreeForAll => {
+                    if is_checked {
+                        self.add_points(moved_side, MATE_POINTS);
+                    } else {
+                        self.add_points(side, STALEMATE_POINTS);
+                    }
+                    self.eliminate(side);
+                }
+            }
+        }
+    }
+
+    fn eliminate(&mut self, side: Side) {
+        self.eliminated_sides.push(side);
+        match self.mode {
+            GameMode::Teams => {
+                let (left_side, right_side) = (side.next(), side.next().next().next());
+                self.game_result = Some(GameResult::TeamWon(
+                    left_side.min(right_side),
+                    left_side.max(right_side),
+                ));
+            }
+            GameMode::FreeForAll => {
+                let color = side.color(&self.mode);
+                match self.eliminated_pieces {
+                    EliminatedPieces::Removed => {
+                        self.board.remove_side(&color, false);
+                    }
+                    EliminatedPieces::Remain => {
+                        for piece in self.board.remove_side(&color, true) {
+                            self.remaining_pieces.insert(
+                                *piece.current_position(),
+                                FourPlayerPiece::from_piece(&piece),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // The side with the highest score wins, no matter whether it has been eliminated
+    fn free_for_all_result(&self) -> GameResult {
+        let sides = Side::all_variants();
+        let best_score = sides.iter().map(|side| self.score(side)).max().unwrap_or(0);
+        let best_sides = sides
+            .iter()
+            .filter(|side| self.score(side) == best_score)
+            .collect::<Vec<_>>();
+        match best_sides[..] {
+            [side] => GameResult::Won(*side),
+            _ => GameResult::Draw,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libtchess::promote_piece::PromotePiece;
+    use libtchess::utils::pretty_print::PrettyPrint;
+
+    fn move_piece(four_player_game: &mut FourPlayerGame, point: Point, piece_move: PieceMove) {
+        assert_eq!(
+            four_player_game.move_piece(&point, &piece_move),
+            MoveResult::PieceMoved
+        );
+        println!("{}", four_player_game.board().pp());
+    }
+
+    fn moves_from(four_player_game: &FourPlayerGame, point: Point) -> Vec<PieceMove> {
+        four_player_game
+            .legal_moves()
+            .into_iter()
+            .filter(|(position, _)| *position == point)
+            .map(|(_, piece_move)| piece_move)
+            .collect()
+    }
+
+    // Places the kings of all sides on their initial points
+    fn game_with_kings(mode: GameMode, eliminated_pieces: EliminatedPieces) -> FourPlayerGame {
+        let mut four_player_game = FourPlayerGame::empty(mode, eliminated_pieces);
+        for side in Side::all_variants() {
+            four_player_game.add_piece(
+                "King",
+                side,
+                vec![],
+                side.rotate_from_view(&Point::new(8, 1)),
+            );
+        }
+        four_player_game
+    }
+
+    // Red queen mates Blue king on the left edge of the board, the rook covers the escape column
+    fn mate_blue(four_player_game: &mut FourPlayerGame) -> MoveResult {
+        four_player_game.add_piece("Queen", Side::Red, vec![], Point::new(5, 4));
+        four_player_game.add_piece("Rook", Side::Red, vec![], Point::new(2, 11));
+        four_player_game.move_piece(&Point::new(5, 4), &PieceMove::Point(Point::new(1, 4)))
+    }
+
+    mod initial_position {
+        use super::*;
+
+        #[test]
+        fn every_side_has_twenty_moves() {
+            let mut four_player_game =
+                FourPlayerGame::new(GameMode::FreeForAll, EliminatedPieces::Removed);
+            for side in Side::all_variants() {
+                assert_eq!(four_player_game.current_side(), side);
+                assert_eq!(four_player_game.legal_moves().len(), 20);
+                move_piece(
+                    &mut four_player_game,
+                    side.rotate_from_view(&Point::new(5, 1)),
+                    PieceMove::Point(side.rotate_from_view(&Point::new(6, 3))),
+                );
+            }
+            assert_eq!(four_player_game.current_side(), Side::Red);
+        }
+    }
+
+    mod castle {
+        use super::*;
+        use libtchess::castle_points::CastlePoints;
+
+        #[test]
+        fn king_of_the_side_on_the_left_castles_along_the_column() {
+            let mut four_player_game =
+                FourPlayerGame::empty(GameMode::FreeForAll, EliminatedPieces::Removed);
+            for side in [Side::Red, Side::Yellow, Side::Green] {
+                four_player_game.add_piece(
+                    "King",
+                    side,
+                    vec![],
+                    side.rotate_from_view(&Point::new(8, 1)),
+                );
+            }
+            four_player_game.add_piece("King", Side::Blue, vec![Buff::Castle], Point::new(1, 7));
+            four_player_game.add_piece("Rook", Side::Blue, vec![Buff::Castle], Point::new(1, 4));
+            move_piece(
+                &mut four_player_game,
+                Point::new(8, 1),
+                PieceMove::Point(Point::new(8, 2)),
+            );
+
+            move_piece(
+                &mut four_player_game,
+                Point::new(1, 7),
+                PieceMove::Castle(CastlePoints::new(
+                    Point::new(1, 5),
+                    Point::new(1, 6),
+                    Point::new(1, 7),
+                    Point::new(1, 4),
+                )),
+            );
+            assert_eq!(
+                four_player_game.piece_at(&Point::new(1, 6)).unwrap().name(),
+                "Rook"
+            );
+        }
+    }
+
+    mod pawns {
+        use super::*;
+
+        #[test]
+        fn pawns_of_the_neighbours_attack_across_the_board() {
+            let mut four_player_game =
+                game_with_kings(GameMode::FreeForAll, EliminatedPieces::Removed);
+            four_player_game.add_piece("Pawn", Side::Blue, vec![], Point::new(4, 5));
+            four_player_game.add_piece("King", Side::Red, vec![], Point::new(6, 5));
+
+            println!("{}", four_player_game.board().pp());
+            let moves = moves_from(&four_player_game, Point::new(6, 5));
+            assert!(moves.contains(&PieceMove::Point(Point::new(5, 5))));
+            assert!(!moves.contains(&PieceMove::Point(Point::new(5, 4))));
+            assert!(!moves.contains(&PieceMove::Point(Point::new(5, 6))));
+        }
+
+        #[test]
+        fn pawn_promotes_in_the_middle_of_the_board_in_free_for_all() {
+            let mut four_player_game =
+                game_with_kings(GameMode::FreeForAll, EliminatedPieces::Removed);
+            four_player_game.add_piece("Pawn", Side::Red, vec![], Point::new(5, 7));
+
+            move_piece(
+                &mut four_player_game,
+                Point::new(5, 7),
+                PieceMove::Promote(Point::new(5, 8), PromotePiece::Queen),
+            );
+            assert_eq!(
+                four_player_game.piece_at(&Point::new(5, 8)).unwrap().name(),
+                "Queen"
+            );
+        }
+
+        #[test]
+        fn pawn_promotes_further_in_teams() {
+            let mut four_player_game = game_with_kings(GameMode::Teams, EliminatedPieces::Remain);
+            four_player_game.add_piece("Pawn", Side::Red, vec![], Point::new(5, 7));
+
+            assert_eq!(
+                moves_from(&four_player_game, Point::new(5, 7)),
+                vec![PieceMove::Point(Point::new(5, 8))]
+            );
+        }
+    }
+
+    mod teams {
+        use super::*;
+
+        #[test]
+        fn partner_pieces_can_not_be_captured() {
+            let mut four_player_game = game_with_kings(GameMode::Teams, EliminatedPieces::Remain);
+            four_player_game.add_piece("Rook", Side::Red, vec![], Point::new(5, 5));
+            four_player_game.add_piece("Knight", Side::Yellow, vec![], Point::new(5, 9));
+
+            println!("{}", four_player_game.board().pp());
+            let moves = moves_from(&four_player_game, Point::new(5, 5));
+            assert!(moves.contains(&PieceMove::Point(Point::new(5, 8))));
+            assert!(!moves.contains(&PieceMove::Point(Point::new(5, 9))));
+        }
+
+        #[test]
+        fn checkmate_wins_the_game_for_the_team() {
+            let mut four_player_game = game_with_kings(GameMode::Teams, EliminatedPieces::Remain);
+
+            assert_eq!(
+                mate_blue(&mut four_player_game),
+                MoveResult::GameEnded(GameResult::TeamWon(Side::Red, Side::Yellow))
+            );
+            assert_eq!(
+                four_player_game.move_piece(&Point::new(1, 7), &PieceMove::Point(Point::new(1, 8))),
+                MoveResult::GameEnded(GameResult::TeamWon(Side::Red, Side::Yellow))
+            );
+        }
+    }
+
+    mod free_for_all {
+        use super::*;
+
+        #[test]
+        fn capture_scores_points() {
+            let mut four_player_game =
+                game_with_kings(GameMode::FreeForAll, EliminatedPieces::Removed);
+            four_player_game.add_piece("Rook", Side::Red, vec![], Point::new(10, 5));
+            four_player_game.add_piece("Knight", Side::Blue, vec![], Point::new(5, 5));
+
+            move_piece(
+                &mut four_player_game,
+                Point::new(10, 5),
+                PieceMove::Point(Point::new(5, 5)),
+            );
+            assert_eq!(four_player_game.score(&Side::Red), 3);
+            assert_eq!(four_player_game.current_side(), Side::Blue);
+        }
+
+        #[test]
+        fn checkmate_eliminates_the_side() {
+            let mut four_player_game =
+                game_with_kings(GameMode::FreeForAll, EliminatedPieces::Removed);
+
+            assert_eq!(mate_blue(&mut four_player_game), MoveResult::PieceMoved);
+            println!("{}", four_player_game.board().pp());
+            assert_eq!(four_player_game.score(&Side::Red), 20);
+            assert!(four_player_game.is_eliminated(&Side::Blue));
+            assert!(four_player_game.piece_at(&Point::new(1, 7)).is_none());
+            assert_eq!(four_player_game.current_side(), Side::Yellow);
+        }
+
+        #[test]
+        fn pieces_of_the_eliminated_side_can_remain() {
+            let mut four_player_game =
+                game_with_kings(GameMode::FreeForAll, EliminatedPieces::Remain);
+            four_player_game.add_piece("Rook", Side::Yellow, vec![], Point::new(1, 11));
+
+            assert_eq!(mate_blue(&mut four_player_game), MoveResult::PieceMoved);
+            println!("{}", four_player_game.board().pp());
+            assert_eq!(
+                four_player_game.piece_at(&Point::new(1, 7)).unwrap().side(),
+                &Side::Blue
+            );
+            // The remaining king blocks the column, but can't be captured
+            let moves = moves_from(&four_player_game, Point::new(1, 11));
+            assert!(moves.contains(&PieceMove::Point(Point::new(1, 8))));
+            assert!(!moves.contains(&PieceMove::Point(Point::new(1, 7))));
+        }
+
+        #[test]
+        fn the_best_score_wins_when_one_side_is_left() {
+            let mut four_player_game =
+                game_with_kings(GameMode::FreeForAll, EliminatedPieces::Removed);
+            four_player_game.eliminated_sides = vec![Side::Yellow, Side::Green];
+            four_player_game.add_points(Side::Yellow, 30);
+
+            assert_eq!(
+                mate_blue(&mut four_player_game),
+                MoveResult::GameEnded(GameResult::Won(Side::Yellow))
+            );
+            assert_eq!(four_player_game.score(&Side::Red), 20);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use libtchess::heat_map::HeatMap;
+use libtchess::piece::Piece;
+use libtchess::point::Point;
+
+// Moves of the four-player chess are not evaluated, thus all positions are equal
+#[derive(Clone)]
+pub struct FourPlayerHeatMap;
+
+impl FourPlayerHeatMap {
+    pub fn init() -> Self {
+        Self {}
+    }
+}
+
+impl HeatMap for FourPlayerHeatMap {
+    fn positional_value(&self, _piece: &Piece, _position: &Point) -> i16 {
+        0
+    }
+}
@@ -0,0 +1,38 @@
+use crate::side::BOARD_SIZE;
+use libtchess::board_square::BoardSquare;
+use libtchess::color::Color;
+use libtchess::point::Point;
+use libtchess::square::Square;
+use libtchess::squares_map::SquaresMap;
+
+// Number of points along each edge of the cut-off corners
+const CORNER_SIZE: i16 = 3;
+
+// The 14x14 board without 3x3 corners
+#[derive(Clone)]
+pub struct FourPlayerSquaresMap;
+
+impl FourPlayerSquaresMap {
+    pub fn init() -> Self {
+        Self {}
+    }
+
+    pub fn is_corner(point: &Point) -> bool {
+        let is_edge = |value: i16| value <= CORNER_SIZE || value > BOARD_SIZE - CORNER_SIZE;
+        is_edge(*point.x().value()) && is_edge(*point.y().value())
+    }
+}
+
+impl SquaresMap for FourPlayerSquaresMap {
+    fn square(&self, point: &Point) -> Option<BoardSquare> {
+        if Self::is_corner(point) {
+            return None;
+        }
+        let color = if point.x().value().wrapping_add(*point.y().value()) % 2 == 0 {
+            Color::Black
+        } else {
+            Color::White
+        };
+        Some(BoardSquare::Square(Square::new(color, None)))
+    }
+}
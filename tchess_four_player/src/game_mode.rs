@@ -0,0 +1,19 @@
+// Whether the opposite sides play together against the other two or every side plays for itself
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GameMode {
+    // Red and Yellow play against Blue and Green. The team wins when any of the opposing sides is
+    // checkmated.
+    Teams,
+    // Every side plays for itself and scores points for captures and checkmates. The game ends
+    // when only one side is left.
+    FreeForAll,
+}
+
+// What happens to the pieces of the side, eliminated in the free-for-all game
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum EliminatedPieces {
+    // The pieces are taken off the board
+    Removed,
+    // The pieces stay on the board. They can't move or be captured by anybody.
+    Remain,
+}
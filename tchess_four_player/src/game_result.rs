@@ -0,0 +1,11 @@
+use crate::side::Side;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GameResult {
+    // Sides of the team which checkmated one of the opposing sides
+    TeamWon(Side, Side),
+    // The side with the highest score at the end of the free-for-all game
+    Won(Side),
+    // The side in the teams game is stalemated or several sides share the highest score
+    Draw,
+}
@@ -0,0 +1,16 @@
+use crate::four_player_game::FourPlayerGame;
+use crate::game_mode::{EliminatedPieces, GameMode};
+use libtchess::utils::pretty_print::PrettyPrint;
+
+mod four_player_game;
+mod four_player_heat_map;
+mod four_player_squares_map;
+mod game_mode;
+mod game_result;
+mod move_result;
+mod side;
+
+fn main() {
+    let four_player_game = FourPlayerGame::new(GameMode::FreeForAll, EliminatedPieces::Removed);
+    println!("{}", four_player_game.board().pp());
+}
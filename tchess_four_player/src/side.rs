@@ -0,0 +1,125 @@
+use crate::game_mode::GameMode;
+use libtchess::color::Color;
+use libtchess::orientation::Orientation;
+use libtchess::point::Point;
+use libtchess::vector::line_vector::LineVector;
+
+// Number of points along each edge of the board
+pub const BOARD_SIZE: i16 = 14;
+
+// Players of the four-player chess, in the order of their turns. Red plays from the bottom edge of
+// the board, the others follow clockwise.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Side {
+    Red,
+    Blue,
+    Yellow,
+    Green,
+}
+
+impl Side {
+    pub fn all_variants() -> [Self; 4] {
+        [Self::Red, Self::Blue, Self::Yellow, Self::Green]
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            Self::Red => 0,
+            Self::Blue => 1,
+            Self::Yellow => 2,
+            Self::Green => 3,
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        Self::all_variants()[(self.index() + 1) % 4]
+    }
+
+    // Color of the side on the board. The opposite sides are allies in the teams mode, every side
+    // is a team of its own in the free-for-all mode.
+    pub fn color(&self, mode: &GameMode) -> Color {
+        let team = match mode {
+            GameMode::Teams => self.index() % 2,
+            GameMode::FreeForAll => self.index(),
+        };
+        let orientation = match self {
+            Self::Red => Orientation::BOTTOM,
+            Self::Blue => Orientation::new(LineVector::Right, LineVector::Bottom),
+            Self::Yellow => Orientation::new(LineVector::Bottom, LineVector::Left),
+            Self::Green => Orientation::new(LineVector::Left, LineVector::Top),
+        };
+        Color::new(self.index() as u8, team as u8, orientation)
+    }
+
+    pub fn from_color(color: &Color) -> Self {
+        Self::all_variants()[color.index()]
+    }
+
+    // Rotates the point, given as the side sees the board from its bottom edge, like the white
+    // side of the classic board
+    pub fn rotate_from_view(&self, point: &Point) -> Point {
+        let (&x, &y) = point.to_tuple();
+        let size = BOARD_SIZE + 1;
+        match self {
+            Self::Red => Point::new(x, y),
+            Self::Blue => Point::new(y, size - x),
+            Self::Yellow => Point::new(size - x, size - y),
+            Self::Green => Point::new(size - y, x),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn_order() {
+        assert_eq!(Side::Red.next(), Side::Blue);
+        assert_eq!(Side::Green.next(), Side::Red);
+    }
+
+    #[test]
+    fn test_colors() {
+        let colors = Side::all_variants().map(|side| side.color(&GameMode::Teams));
+        assert!(colors[0].is_ally(&colors[2]));
+        assert!(colors[1].is_ally(&colors[3]));
+        assert!(colors[0].is_enemy(&colors[1]));
+        let (blue, green) = (
+            Side::Blue.color(&GameMode::FreeForAll),
+            Side::Green.color(&GameMode::FreeForAll),
+        );
+        assert!(blue.is_enemy(&green));
+        for side in Side::all_variants() {
+            assert_eq!(Side::from_color(&side.color(&GameMode::FreeForAll)), side);
+        }
+    }
+
+    #[test]
+    fn test_view_rotation() {
+        // Back ranks of all sides appear on the first row of their views
+        assert_eq!(
+            Side::Blue.rotate_from_view(&Point::new(8, 1)),
+            Point::new(1, 7)
+        );
+        assert_eq!(
+            Side::Yellow.rotate_from_view(&Point::new(8, 1)),
+            Point::new(7, 14)
+        );
+        assert_eq!(
+            Side::Green.rotate_from_view(&Point::new(8, 1)),
+            Point::new(14, 8)
+        );
+    }
+
+    #[test]
+    fn test_orientation() {
+        // Pawns go from the first row of the side's view to the second one
+        for side in Side::all_variants() {
+            let color = side.color(&GameMode::FreeForAll);
+            let from = side.rotate_from_view(&Point::new(5, 1));
+            let to = side.rotate_from_view(&Point::new(5, 2));
+            assert_eq!(color.orientation().forward().calc_next_point(&from), to);
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::grand_heat_map::GrandHeatMap;
+use crate::grand_pieces::grand_piece_registry;
+use crate::grand_squares_map::GrandSquaresMap;
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::buff::Buff;
+use libtchess::castle_x_points::{CastleXPoints, KingCastleXPoint, RookCastleXPoint};
+use libtchess::classic_rules_game::ClassicRulesGame;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::player::Player;
+use libtchess::point::Point;
+use libtchess::promotion_zone::PromotionZone;
+
+// Pieces in front of the rooks, from b to i file
+pub const GRAND_SECOND_RANK: [&str; 8] = [
+    "Knight",
+    "Bishop",
+    "Queen",
+    "King",
+    "Marshal",
+    "Cardinal",
+    "Bishop",
+    "Knight",
+];
+
+// A pawn can only promote to the piece of its side, which has been captured before. Thus, the
+// side can't have more pieces of each kind than it has had initially.
+const PROMOTION_LIMITS: [(&str, u8); 6] = [
+    ("Queen", 1),
+    ("Marshal", 1),
+    ("Cardinal", 1),
+    ("Rook", 2),
+    ("Bishop", 2),
+    ("Knight", 2),
+];
+
+pub type GrandGame = ClassicRulesGame<GrandHeatMap, GrandSquaresMap>;
+
+pub fn grand_board() -> GrandGame {
+    ClassicRulesGame::with_setup(grand_config(), place_grand_pieces)
+}
+
+// There is no castling. Pawns promote on the last three ranks, where they may stay
+// unpromoted unless they have reached the last rank.
+pub fn grand_config() -> BoardConfig<GrandHeatMap, GrandSquaresMap> {
+    let dimension = Dimension::new(Point::new(1, 1), Point::new(10, 10));
+    let mut config = BoardConfig::new(
+        CastleXPoints(KingCastleXPoint(9), RookCastleXPoint(8)),
+        CastleXPoints(KingCastleXPoint(3), RookCastleXPoint(4)),
+        GrandHeatMap::init(),
+        GrandSquaresMap::init(),
+        dimension,
+        Player::Human,
+        Player::Human,
+    );
+    config.set_piece_registry(grand_piece_registry());
+    config.set_castle_rules(vec![]);
+    config.set_promotion_zone(&Color::White, PromotionZone::Ranks(8..=10));
+    config.set_promotion_zone(&Color::Black, PromotionZone::Ranks(1..=3));
+    config.set_optional_pawn_promotions(true);
+    for (piece_name, limit) in PROMOTION_LIMITS {
+        config.set_promotion_limit(piece_name, limit);
+    }
+    config
+}
+
+// The rooks stand in the corners, the rest of the pieces stand in front of them and the pawns
+// start on the third rank
+fn place_grand_pieces(board: &mut Board<GrandHeatMap, GrandSquaresMap>) {
+    let last_row = *board.dimension().max_point().y().value();
+
+    for (color, rooks_row, pieces_row, pawns_row) in [
+        (Color::White, 1, 2, 3),
+        (Color::Black, last_row, last_row - 1, last_row - 2),
+    ] {
+        for x in [1, 10] {
+            board.add_piece("Rook", color, vec![], vec![], Point::new(x, rooks_row));
+        }
+        for (x, name) in (2..).zip(GRAND_SECOND_RANK) {
+            board.add_piece(name, color, vec![], vec![], Point::new(x, pieces_row));
+        }
+        for x in board.dimension().get_columns_range() {
+            board.add_piece(
+                "Pawn",
+                color,
+                vec![Buff::AdditionalPoint],
+                vec![],
+                Point::new(x, pawns_row),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libtchess::game_board::GameBoard;
+    use libtchess::move_result::MoveResult;
+    use libtchess::piece_id::PieceId;
+    use libtchess::piece_move::PieceMove;
+    use libtchess::promote_piece::PromotePiece;
+    use libtchess::utils::pretty_print::PrettyPrint;
+
+    fn promotions_of(grand_game: &GrandGame, pawn_id: &PieceId) -> Vec<PromotePiece> {
+        let mut promotions = grand_game
+            .board()
+            .moves_of(pawn_id)
+            .map(|moves| {
+                moves
+                    .keys()
+                    .filter_map(|piece_move| match piece_move {
+                        PieceMove::Promote(_, promote_piece) => Some(*promote_piece),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        promotions.sort();
+        promotions
+    }
+
+    mod initial_position {
+        use super::*;
+
+        #[test]
+        fn it_has_sixty_five_moves() {
+            let grand_game = grand_board();
+            println!("{}", grand_game.board().pp());
+            assert_eq!(grand_game.board().legal_moves(&Color::White).len(), 65);
+            assert_eq!(grand_game.board().legal_moves(&Color::Black).len(), 65);
+        }
+
+        #[test]
+        fn marshal_and_cardinal_stand_next_to_the_king() {
+            let grand_game = grand_board();
+            let board = grand_game.board();
+            assert_eq!(board.piece_at(&Point::new(5, 2)).unwrap().name(), "King");
+            assert_eq!(
+                board.piece_at(&Point::new(6, 9)).unwrap().name(),
+                "Marshal"
+            );
+            assert_eq!(
+                board.piece_at(&Point::new(7, 9)).unwrap().name(),
+                "Cardinal"
+            );
+        }
+    }
+
+    mod promotion {
+        use super::*;
+
+        #[test]
+        fn pawn_may_stay_unpromoted_before_the_last_rank() {
+            let mut grand_game = ClassicRulesGame::with_setup(grand_config(), |board| {
+                board.add_piece("King", Color::White, vec![], vec![], Point::new(1, 1));
+                board.add_piece("King", Color::Black, vec![], vec![], Point::new(10, 10));
+                board.add_piece("Queen", Color::White, vec![], vec![], Point::new(2, 1));
+                board.add_piece("Marshal", Color::White, vec![], vec![], Point::new(3, 1));
+                board.add_piece("Pawn", Color::White, vec![], vec![], Point::new(5, 7));
+            });
+            let pawn_id = *grand_game.board().piece_id_at(&Point::new(5, 7)).unwrap();
+
+            // The queen and the marshal have not been captured yet
+            assert_eq!(
+                promotions_of(&grand_game, &pawn_id),
+                vec![
+                    PromotePiece::Knight,
+                    PromotePiece::Bishop,
+                    PromotePiece::Rook,
+                    PromotePiece::Cardinal,
+                ]
+            );
+            assert_eq!(
+                grand_game.move_piece_at(&Point::new(5, 7), &PieceMove::Point(Point::new(5, 8))),
+                MoveResult::PieceMoved
+            );
+            println!("{}", grand_game.board().pp());
+            assert_eq!(
+                grand_game
+                    .board()
+                    .piece_at(&Point::new(5, 8))
+                    .unwrap()
+                    .name(),
+                "Pawn"
+            );
+        }
+
+        #[test]
+        fn pawn_cant_reach_the_last_rank_until_a_piece_is_captured() {
+            let mut grand_game = ClassicRulesGame::with_setup(grand_config(), |board| {
+                board.add_piece("King", Color::White, vec![], vec![], Point::new(1, 1));
+                board.add_piece("King", Color::Black, vec![], vec![], Point::new(10, 10));
+                for (x, (name, limit)) in (2..).zip(PROMOTION_LIMITS) {
+                    for y in 1..=limit as i16 {
+                        board.add_piece(name, Color::White, vec![], vec![], Point::new(x, y));
+                    }
+                }
+                board.add_piece("Pawn", Color::White, vec![], vec![], Point::new(1, 9));
+                board.add_piece("Rook", Color::Black, vec![], vec![], Point::new(10, 2));
+            });
+            let pawn_id = *grand_game.board().piece_id_at(&Point::new(1, 9)).unwrap();
+
+            println!("{}", grand_game.board().pp());
+            assert!(grand_game.board().moves_of(&pawn_id).is_none());
+            // 1. Kb2 Rxg2
+            for (point, destination) in [
+                (Point::new(1, 1), Point::new(2, 2)),
+                (Point::new(10, 2), Point::new(7, 2)),
+            ] {
+                assert_eq!(
+                    grand_game.move_piece_at(&point, &PieceMove::Point(destination)),
+                    MoveResult::PieceMoved
+                );
+            }
+            assert_eq!(
+                promotions_of(&grand_game, &pawn_id),
+                vec![PromotePiece::Knight]
+            );
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use libtchess::betza::BetzaPiece;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::piece_registry::PieceRegistry;
+
+// Moves like a rook and a knight, like the chancellor of Capablanca chess
+pub fn marshal() -> BetzaPiece {
+    BetzaPiece::new("Marshal", ColoredProperty::from(['M', 'm']), 875, "RN")
+        .expect("Marshal notation must be valid!")
+}
+
+// Moves like a bishop and a knight, like the archbishop of Capablanca chess
+pub fn cardinal() -> BetzaPiece {
+    BetzaPiece::new("Cardinal", ColoredProperty::from(['C', 'c']), 825, "BN")
+        .expect("Cardinal notation must be valid!")
+}
+
+// Classic pieces along with the marshal and the cardinal
+pub fn grand_piece_registry() -> PieceRegistry {
+    let mut piece_registry = PieceRegistry::classic();
+    piece_registry.register(marshal());
+    piece_registry.register(cardinal());
+    piece_registry
+}
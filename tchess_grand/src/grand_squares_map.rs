@@ -0,0 +1,26 @@
+use libtchess::board_square::BoardSquare;
+use libtchess::color::Color;
+use libtchess::point::Point;
+use libtchess::square::Square;
+use libtchess::squares_map::SquaresMap;
+
+// Plain 10x10 board
+#[derive(Clone)]
+pub struct GrandSquaresMap;
+
+impl GrandSquaresMap {
+    pub fn init() -> Self {
+        Self {}
+    }
+}
+
+impl SquaresMap for GrandSquaresMap {
+    fn square(&self, point: &Point) -> Option<BoardSquare> {
+        let color = if point.x().value().wrapping_add(*point.y().value()) % 2 == 0 {
+            Color::Black
+        } else {
+            Color::White
+        };
+        Some(BoardSquare::Square(Square::new(color, None)))
+    }
+}
@@ -0,0 +1,11 @@
+use libtchess::utils::pretty_print::PrettyPrint;
+
+mod grand_game;
+mod grand_heat_map;
+mod grand_pieces;
+mod grand_squares_map;
+
+fn main() {
+    let grand_game = grand_game::grand_board();
+    println!("{}", grand_game.board().pp());
+}
@@ -0,0 +1,11 @@
+use libtchess::utils::pretty_print::PrettyPrint;
+
+mod omega_game;
+mod omega_heat_map;
+mod omega_pieces;
+mod omega_squares_map;
+
+fn main() {
+    let omega_game = omega_game::omega_board();
+    println!("{}", omega_game.board().pp());
+}
@@ -0,0 +1,223 @@
+use crate::omega_heat_map::OmegaHeatMap;
+use crate::omega_pieces::omega_piece_registry;
+use crate::omega_squares_map::OmegaSquaresMap;
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::buff::Buff;
+use libtchess::castle_x_points::{CastleXPoints, KingCastleXPoint, RookCastleXPoint};
+use libtchess::classic_rules_game::ClassicRulesGame;
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::player::Player;
+use libtchess::point::Point;
+use libtchess::promotion_zone::PromotionZone;
+
+// Back rank of the Omega chess, from a to j file
+pub const OMEGA_BACK_RANK: [&str; 10] = [
+    "Champion", "Rook", "Knight", "Bishop", "Queen", "King", "Bishop", "Knight", "Rook", "Champion",
+];
+
+pub type OmegaGame = ClassicRulesGame<OmegaHeatMap, OmegaSquaresMap>;
+
+pub fn omega_board() -> OmegaGame {
+    ClassicRulesGame::with_setup(omega_config(), place_omega_pieces)
+}
+
+// The 10x10 field starts at the first file and rank, thus the wizard squares are at the zero
+// and the eleventh ones. Pawns can go up to three points on their first move and promote on
+// the last rank of the field to the classic pieces only.
+pub fn omega_config() -> BoardConfig<OmegaHeatMap, OmegaSquaresMap> {
+    let dimension = Dimension::new(Point::new(0, 0), Point::new(11, 11));
+    let mut config = BoardConfig::new(
+        CastleXPoints(KingCastleXPoint(8), RookCastleXPoint(7)),
+        CastleXPoints(KingCastleXPoint(4), RookCastleXPoint(5)),
+        OmegaHeatMap::init(),
+        OmegaSquaresMap::init(),
+        dimension,
+        Player::Human,
+        Player::Human,
+    );
+    config.set_piece_registry(omega_piece_registry());
+    config.set_pawn_initial_steps(3);
+    config.set_promotion_zone(&Color::White, PromotionZone::Ranks(10..=10));
+    config.set_promotion_zone(&Color::Black, PromotionZone::Ranks(1..=1));
+    config
+}
+
+// Wizards start on the wizard squares behind the corners of the back rank
+fn place_omega_pieces(board: &mut Board<OmegaHeatMap, OmegaSquaresMap>) {
+    let (min_x, min_y) = board.dimension().min_point().to_tuple();
+    let (max_x, max_y) = board.dimension().max_point().to_tuple();
+    let (min_x, min_y, max_x, max_y) = (*min_x, *min_y, *max_x, *max_y);
+
+    for (color, wizards_row, row, pawns_row) in [
+        (Color::White, min_y, min_y + 1, min_y + 2),
+        (Color::Black, max_y, max_y - 1, max_y - 2),
+    ] {
+        for x in [min_x, max_x] {
+            board.add_piece("Wizard", color, vec![], vec![], Point::new(x, wizards_row));
+        }
+        for (x, name) in (min_x + 1..).zip(OMEGA_BACK_RANK) {
+            let buffs = match name {
+                "Rook" | "King" => vec![Buff::Castle],
+                _ => vec![],
+            };
+            board.add_piece(name, color, buffs, vec![], Point::new(x, row));
+        }
+        for x in min_x + 1..max_x {
+            board.add_piece(
+                "Pawn",
+                color,
+                vec![Buff::AdditionalPoint],
+                vec![],
+                Point::new(x, pawns_row),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libtchess::castle_points::CastlePoints;
+    use libtchess::game_board::GameBoard;
+    use libtchess::move_result::MoveResult;
+    use libtchess::piece_id::PieceId;
+    use libtchess::piece_move::PieceMove;
+    use libtchess::utils::pretty_print::PrettyPrint;
+
+    fn destinations_of(omega_game: &OmegaGame, piece_id: &PieceId) -> Vec<Point> {
+        let mut destinations = omega_game
+            .board()
+            .moves_of(piece_id)
+            .unwrap()
+            .keys()
+            .map(|piece_move| *piece_move.destination())
+            .collect::<Vec<_>>();
+        destinations.sort();
+        destinations
+    }
+
+    mod initial_position {
+        use super::*;
+
+        #[test]
+        fn it_has_forty_moves() {
+            let omega_game = omega_board();
+            println!("{}", omega_game.board().pp());
+            assert_eq!(omega_game.board().legal_moves(&Color::White).len(), 40);
+            assert_eq!(omega_game.board().legal_moves(&Color::Black).len(), 40);
+        }
+
+        #[test]
+        fn wizards_stand_on_the_wizard_squares() {
+            let omega_game = omega_board();
+            for point in [
+                Point::new(0, 0),
+                Point::new(11, 0),
+                Point::new(0, 11),
+                Point::new(11, 11),
+            ] {
+                assert!(OmegaSquaresMap::is_wizard_square(&point));
+                assert_eq!(
+                    omega_game.board().piece_at(&point).unwrap().name(),
+                    "Wizard"
+                );
+            }
+        }
+    }
+
+    mod wizard_squares {
+        use super::*;
+
+        #[test]
+        fn they_are_reachable_diagonally_only() {
+            let mut omega_game = ClassicRulesGame::with_setup(omega_config(), |board| {
+                board.add_piece("King", Color::White, vec![], vec![], Point::new(6, 1));
+                board.add_piece("King", Color::Black, vec![], vec![], Point::new(6, 10));
+                board.add_piece("Bishop", Color::White, vec![], vec![], Point::new(3, 3));
+                board.add_piece("Rook", Color::White, vec![], vec![], Point::new(10, 3));
+            });
+            let bishop_id = *omega_game.board().piece_id_at(&Point::new(3, 3)).unwrap();
+            let rook_id = *omega_game.board().piece_id_at(&Point::new(10, 3)).unwrap();
+
+            let bishop_destinations = destinations_of(&omega_game, &bishop_id);
+            assert!(bishop_destinations.contains(&Point::new(0, 0)));
+            // The void ring stops the rook at the edge of the field
+            let rook_destinations = destinations_of(&omega_game, &rook_id);
+            assert!(rook_destinations.contains(&Point::new(10, 1)));
+            assert!(!rook_destinations.contains(&Point::new(10, 0)));
+            assert!(!rook_destinations.contains(&Point::new(11, 3)));
+
+            assert_eq!(
+                omega_game.move_piece_at(&Point::new(3, 3), &PieceMove::Point(Point::new(0, 0))),
+                MoveResult::PieceMoved
+            );
+            println!("{}", omega_game.board().pp());
+            // The long diagonal leads to the opposite wizard square
+            assert_eq!(
+                destinations_of(&omega_game, &bishop_id),
+                (1..=11).map(|xy| Point::new(xy, xy)).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    mod pawn {
+        use super::*;
+
+        #[test]
+        fn it_goes_up_to_three_points_on_its_first_move() {
+            let omega_game = omega_board();
+            let pawn_id = *omega_game.board().piece_id_at(&Point::new(5, 2)).unwrap();
+            assert_eq!(
+                destinations_of(&omega_game, &pawn_id),
+                vec![Point::new(5, 3), Point::new(5, 4), Point::new(5, 5)]
+            );
+        }
+    }
+
+    mod castle {
+        use super::*;
+
+        #[test]
+        fn king_castles_with_the_rook_next_to_the_champion() {
+            let mut omega_game = ClassicRulesGame::with_setup(omega_config(), |board| {
+                board.add_piece("Champion", Color::White, vec![], vec![], Point::new(10, 1));
+                board.add_piece(
+                    "Rook",
+                    Color::White,
+                    vec![Buff::Castle],
+                    vec![],
+                    Point::new(9, 1),
+                );
+                board.add_piece(
+                    "King",
+                    Color::White,
+                    vec![Buff::Castle],
+                    vec![],
+                    Point::new(6, 1),
+                );
+                board.add_piece("King", Color::Black, vec![], vec![], Point::new(6, 10));
+            });
+
+            let castle_points = CastlePoints::new(
+                Point::new(8, 1),
+                Point::new(7, 1),
+                Point::new(6, 1),
+                Point::new(9, 1),
+            );
+            assert_eq!(
+                omega_game.move_piece_at(&Point::new(6, 1), &PieceMove::Castle(castle_points)),
+                MoveResult::PieceMoved
+            );
+            let board = omega_game.board();
+            println!("{}", board.pp());
+            assert_eq!(board.piece_at(&Point::new(8, 1)).unwrap().name(), "King");
+            assert_eq!(board.piece_at(&Point::new(7, 1)).unwrap().name(), "Rook");
+            assert_eq!(
+                board.piece_at(&Point::new(10, 1)).unwrap().name(),
+                "Champion"
+            );
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use libtchess::heat_map::HeatMap;
+use libtchess::piece::Piece;
+use libtchess::point::Point;
+
+// Only material value of the pieces is taken into account
+#[derive(Clone)]
+pub struct OmegaHeatMap;
+
+impl OmegaHeatMap {
+    pub fn init() -> Self {
+        Self {}
+    }
+}
+
+impl HeatMap for OmegaHeatMap {
+    fn positional_value(&self, piece: &Piece, _position: &Point) -> i16 {
+        match piece {
+            Piece::Pawn(_) => 100,
+            Piece::Knight(_) => 300,
+            Piece::Bishop(_) => 325,
+            Piece::Rook(_) => 500,
+            Piece::Queen(_) => 900,
+            Piece::King(_) => 0,
+            Piece::Custom(p) => p.definition().value(),
+            Piece::UnknownPiece(_) => panic!("Unknown piece can't be evaluated!"),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use libtchess::betza::BetzaPiece;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::piece_registry::PieceRegistry;
+
+// Steps one point orthogonally, or leaps two points orthogonally or diagonally
+pub fn champion() -> BetzaPiece {
+    BetzaPiece::new("Champion", ColoredProperty::from(['C', 'c']), 475, "WAD")
+        .expect("Champion notation must be valid!")
+}
+
+// Steps one point diagonally or leaps like the camel. It never leaves the squares of its color.
+pub fn wizard() -> BetzaPiece {
+    BetzaPiece::new("Wizard", ColoredProperty::from(['W', 'w']), 460, "FC")
+        .expect("Wizard notation must be valid!")
+}
+
+// Classic pieces along with the champion and the wizard
+pub fn omega_piece_registry() -> PieceRegistry {
+    let mut piece_registry = PieceRegistry::classic();
+    piece_registry.register(champion());
+    piece_registry.register(wizard());
+    piece_registry
+}
@@ -0,0 +1,45 @@
+use libtchess::board_square::BoardSquare;
+use libtchess::color::Color;
+use libtchess::point::Point;
+use libtchess::square::Square;
+use libtchess::squares_map::SquaresMap;
+
+// First and last files and ranks of the 10x10 field
+const FIELD_MIN: i16 = 1;
+const FIELD_MAX: i16 = 10;
+
+// The 10x10 field, surrounded by the void ring of points, with the wizard squares in the corners
+// of the ring. Each wizard square is diagonally adjacent to the corner of the field, thus it is
+// only reachable diagonally or by a leap.
+#[derive(Clone)]
+pub struct OmegaSquaresMap;
+
+impl OmegaSquaresMap {
+    pub fn init() -> Self {
+        Self {}
+    }
+
+    pub fn is_wizard_square(point: &Point) -> bool {
+        let is_ring = |value: i16| value == FIELD_MIN - 1 || value == FIELD_MAX + 1;
+        is_ring(*point.x().value()) && is_ring(*point.y().value())
+    }
+
+    fn is_field_square(point: &Point) -> bool {
+        let is_field = |value: i16| (FIELD_MIN..=FIELD_MAX).contains(&value);
+        is_field(*point.x().value()) && is_field(*point.y().value())
+    }
+}
+
+impl SquaresMap for OmegaSquaresMap {
+    fn square(&self, point: &Point) -> Option<BoardSquare> {
+        if !Self::is_field_square(point) && !Self::is_wizard_square(point) {
+            return None;
+        }
+        let color = if point.x().value().wrapping_add(*point.y().value()) % 2 == 0 {
+            Color::Black
+        } else {
+            Color::White
+        };
+        Some(BoardSquare::Square(Square::new(color, None)))
+    }
+}
@@ -0,0 +1,14 @@
+use crate::shogi_game::ShogiGame;
+use libtchess::utils::pretty_print::PrettyPrint;
+
+mod game_result;
+mod move_result;
+mod shogi_game;
+mod shogi_heat_map;
+mod shogi_pieces;
+mod shogi_squares_map;
+
+fn main() {
+    let shogi_game = ShogiGame::shogi_board();
+    println!("{}", shogi_game.board().pp());
+}
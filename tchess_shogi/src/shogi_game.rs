@@ -0,0 +1,383 @@
+use crate::game_result::GameResult;
+use crate::move_result::MoveResult;
+use crate::shogi_heat_map::ShogiHeatMap;
+use crate::shogi_pieces::{PROMOTIONS, shogi_piece_registry};
+use crate::shogi_squares_map::ShogiSquaresMap;
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::board_positions::BoardPositions;
+use libtchess::castle_x_points::{CastleXPoints, KingCastleXPoint, RookCastleXPoint};
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_id::PieceId;
+use libtchess::piece_move::PieceMove;
+use libtchess::player::Player;
+use libtchess::point::Point;
+use libtchess::promotion_zone::PromotionZone;
+
+const MAX_NUMBER_OF_EQUAL_POSITIONS: u8 = 4;
+
+// Back rank of the shogi, from the first to the ninth file
+pub const BACK_RANK: [&str; 9] = [
+    "Lance", "Knight", "Silver", "Gold", "King", "Gold", "Silver", "Knight", "Lance",
+];
+
+// Sente is the white side, gote is the black one
+pub struct ShogiGame {
+    board_positions: BoardPositions,
+    board: Board<ShogiHeatMap, ShogiSquaresMap>,
+    game_result: Option<GameResult>,
+}
+
+impl ShogiGame {
+    pub fn shogi_board() -> Self {
+        Self::with_config(Self::shogi_config())
+    }
+
+    pub fn shogi_config() -> BoardConfig<ShogiHeatMap, ShogiSquaresMap> {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(9, 9));
+        let mut config = BoardConfig::new(
+            CastleXPoints(KingCastleXPoint(7), RookCastleXPoint(6)),
+            CastleXPoints(KingCastleXPoint(3), RookCastleXPoint(4)),
+            ShogiHeatMap::init(),
+            ShogiSquaresMap::init(),
+            dimension,
+            Player::Human,
+            Player::Human,
+        );
+        config.set_castle_rules(vec![]);
+        config.set_piece_registry(shogi_piece_registry());
+        config.set_promotion_zone(&Color::White, PromotionZone::Ranks(7..=9));
+        config.set_promotion_zone(&Color::Black, PromotionZone::Ranks(1..=3));
+        for (piece_name, promoted_name) in PROMOTIONS {
+            config.set_promotion(piece_name, promoted_name);
+        }
+        config.set_drops(true);
+        config.set_drop_restricted_pieces(&["Pawn"]);
+        config
+    }
+
+    // Places the back rank, the rook and the bishop in front of it and a row of pawns for each
+    // side
+    pub fn with_config(config: BoardConfig<ShogiHeatMap, ShogiSquaresMap>) -> Self {
+        let mut board = Board::empty(config);
+
+        for (color, row, pieces_row, pawns_row, [bishop_x, rook_x]) in [
+            (Color::White, 1, 2, 3, [2, 8]),
+            (Color::Black, 9, 8, 7, [8, 2]),
+        ] {
+            for (x, name) in (1..).zip(BACK_RANK.iter()) {
+                board.add_piece(name, color, vec![], vec![], Point::new(x, row));
+            }
+            board.add_piece(
+                "Bishop",
+                color,
+                vec![],
+                vec![],
+                Point::new(bishop_x, pieces_row),
+            );
+            board.add_piece(
+                "Rook",
+                color,
+                vec![],
+                vec![],
+                Point::new(rook_x, pieces_row),
+            );
+            for x in board.dimension().get_columns_range() {
+                board.add_piece("Pawn", color, vec![], vec![], Point::new(x, pawns_row));
+            }
+        }
+
+        let mut board_positions = BoardPositions::empty();
+        board_positions.persist_position(&board.stats().zposition.0, false);
+        Self {
+            board,
+            board_positions,
+            game_result: None,
+        }
+    }
+
+    pub fn move_piece_at(&mut self, position: &Point, piece_move: &PieceMove) -> MoveResult {
+        let &piece_id = self
+            .board
+            .piece_id_at(position)
+            .unwrap_or_else(|| panic!("Could not find piece at {} position", position));
+        self.move_piece(&piece_id, piece_move)
+    }
+
+    // Drops a piece of the given name from the pocket of the side to move
+    pub fn drop_piece(&mut self, name: &str, position: &Point) -> MoveResult {
+        let color = *self.board.current_turn();
+        let Some(piece_id) = self
+            .board
+            .pocket(&color)
+            .iter()
+            .find(|(_, piece_name)| *piece_name == name)
+            .map(|(&piece_id, _)| piece_id)
+        else {
+            return MoveResult::IllegalMove;
+        };
+        self.move_piece(&piece_id, &PieceMove::Drop(*position))
+    }
+
+    // Pieces from the pocket are moved by their ids in the pocket
+    pub fn move_piece(&mut self, piece_id: &PieceId, piece_move: &PieceMove) -> MoveResult {
+        if let Some(game_result) = self.game_result {
+            return MoveResult::GameEnded(game_result);
+        }
+        if self.board.move_piece(piece_id, piece_move).is_none() {
+            return MoveResult::IllegalMove;
+        }
+
+        let is_check = self.board.is_checked(self.board.current_turn());
+        let occurrences_num = self
+            .board_positions
+            .persist_position(&self.board.stats().zposition.0, is_check);
+        self.calculate_game_result(occurrences_num);
+
+        match self.game_result {
+            Some(game_result) => MoveResult::GameEnded(game_result),
+            None => MoveResult::PieceMoved,
+        }
+    }
+
+    pub fn board(&self) -> &Board<ShogiHeatMap, ShogiSquaresMap> {
+        &self.board
+    }
+
+    pub fn game_result(&self) -> Option<&GameResult> {
+        self.game_result.as_ref()
+    }
+
+    fn calculate_game_result(&mut self, occurrences_num: u8) {
+        let current_turn = *self.board.current_turn();
+        if self.board.has_no_moves(&current_turn) {
+            self.game_result = Some(GameResult::Checkmate(current_turn));
+            return;
+        }
+        if occurrences_num < MAX_NUMBER_OF_EQUAL_POSITIONS {
+            return;
+        }
+        // The side, which has been checking all the time, loses instead of drawing
+        if self.board_positions.is_perpetual_check(0) {
+            self.game_result = Some(GameResult::PerpetualCheck(current_turn.inverse()));
+        } else if self.board_positions.is_perpetual_check(1) {
+            self.game_result = Some(GameResult::PerpetualCheck(current_turn));
+        } else {
+            self.game_result = Some(GameResult::Sennichite);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libtchess::promote_piece::PromotePiece;
+    use libtchess::utils::pretty_print::PrettyPrint;
+
+    fn move_piece(shogi_game: &mut ShogiGame, point: Point, piece_move: PieceMove) {
+        assert_eq!(
+            shogi_game.move_piece_at(&point, &piece_move),
+            MoveResult::PieceMoved
+        );
+        println!("{}", shogi_game.board().pp());
+    }
+
+    fn empty_game() -> ShogiGame {
+        ShogiGame {
+            board: Board::empty(ShogiGame::shogi_config()),
+            board_positions: BoardPositions::empty(),
+            game_result: None,
+        }
+    }
+
+    mod initial_position {
+        use super::*;
+
+        #[test]
+        fn has_thirty_moves() {
+            let shogi_game = ShogiGame::shogi_board();
+            println!("{}", shogi_game.board().pp());
+            assert_eq!(shogi_game.board().legal_moves(&Color::White).len(), 30);
+            assert_eq!(shogi_game.board().legal_moves(&Color::Black).len(), 30);
+        }
+    }
+
+    mod drops {
+        use super::*;
+
+        #[test]
+        fn captured_bishops_are_dropped() {
+            let mut shogi_game = ShogiGame::shogi_board();
+            move_piece(
+                &mut shogi_game,
+                Point::new(3, 3),
+                PieceMove::Point(Point::new(3, 4)),
+            );
+            move_piece(
+                &mut shogi_game,
+                Point::new(7, 7),
+                PieceMove::Point(Point::new(7, 6)),
+            );
+            move_piece(
+                &mut shogi_game,
+                Point::new(2, 2),
+                PieceMove::Promote(Point::new(8, 8), PromotePiece::Promoted),
+            );
+            assert_eq!(
+                shogi_game
+                    .board()
+                    .piece_at(&Point::new(8, 8))
+                    .unwrap()
+                    .name(),
+                "DragonHorse"
+            );
+            move_piece(
+                &mut shogi_game,
+                Point::new(7, 9),
+                PieceMove::Point(Point::new(8, 8)),
+            );
+
+            // The dragon horse turns back into the bishop in the pocket
+            assert_eq!(shogi_game.board().pocket(&Color::White).count("Bishop"), 1);
+            assert_eq!(shogi_game.board().pocket(&Color::Black).count("Bishop"), 1);
+            assert_eq!(
+                shogi_game.drop_piece("Bishop", &Point::new(5, 5)),
+                MoveResult::PieceMoved
+            );
+            let bishop = shogi_game.board().piece_at(&Point::new(5, 5)).unwrap();
+            assert_eq!(bishop.name(), "Bishop");
+            assert_eq!(bishop.color(), &Color::White);
+            assert!(shogi_game.board().pocket(&Color::White).is_empty());
+        }
+
+        #[test]
+        fn pieces_absent_in_the_pocket_are_not_dropped() {
+            let mut shogi_game = ShogiGame::shogi_board();
+            assert_eq!(
+                shogi_game.drop_piece("Bishop", &Point::new(5, 5)),
+                MoveResult::IllegalMove
+            );
+        }
+    }
+
+    mod game_result {
+        use super::*;
+
+        #[test]
+        fn sennichite_after_four_equal_positions() {
+            let mut shogi_game = ShogiGame::shogi_board();
+            for _ in 0..2 {
+                move_piece(
+                    &mut shogi_game,
+                    Point::new(8, 2),
+                    PieceMove::Point(Point::new(7, 2)),
+                );
+                move_piece(
+                    &mut shogi_game,
+                    Point::new(2, 8),
+                    PieceMove::Point(Point::new(3, 8)),
+                );
+                move_piece(
+                    &mut shogi_game,
+                    Point::new(7, 2),
+                    PieceMove::Point(Point::new(8, 2)),
+                );
+                move_piece(
+                    &mut shogi_game,
+                    Point::new(3, 8),
+                    PieceMove::Point(Point::new(2, 8)),
+                );
+            }
+            move_piece(
+                &mut shogi_game,
+                Point::new(8, 2),
+                PieceMove::Point(Point::new(7, 2)),
+            );
+            move_piece(
+                &mut shogi_game,
+                Point::new(2, 8),
+                PieceMove::Point(Point::new(3, 8)),
+            );
+            move_piece(
+                &mut shogi_game,
+                Point::new(7, 2),
+                PieceMove::Point(Point::new(8, 2)),
+            );
+            assert_eq!(
+                shogi_game.move_piece_at(&Point::new(3, 8), &PieceMove::Point(Point::new(2, 8))),
+                MoveResult::GameEnded(GameResult::Sennichite)
+            );
+        }
+
+        #[test]
+        fn perpetual_check_loses() {
+            let mut shogi_game = empty_game();
+            let board = &mut shogi_game.board;
+            board.add_piece("King", Color::White, vec![], vec![], Point::new(5, 1));
+            board.add_piece("Rook", Color::White, vec![], vec![], Point::new(1, 8));
+            board.add_piece("King", Color::Black, vec![], vec![], Point::new(9, 9));
+            shogi_game
+                .board_positions
+                .persist_position(&shogi_game.board.stats().zposition.0, false);
+
+            for _ in 0..2 {
+                move_piece(
+                    &mut shogi_game,
+                    Point::new(1, 8),
+                    PieceMove::Point(Point::new(1, 9)),
+                );
+                move_piece(
+                    &mut shogi_game,
+                    Point::new(9, 9),
+                    PieceMove::Point(Point::new(9, 8)),
+                );
+                move_piece(
+                    &mut shogi_game,
+                    Point::new(1, 9),
+                    PieceMove::Point(Point::new(1, 8)),
+                );
+                move_piece(
+                    &mut shogi_game,
+                    Point::new(9, 8),
+                    PieceMove::Point(Point::new(9, 9)),
+                );
+            }
+            move_piece(
+                &mut shogi_game,
+                Point::new(1, 8),
+                PieceMove::Point(Point::new(1, 9)),
+            );
+            move_piece(
+                &mut shogi_game,
+                Point::new(9, 9),
+                PieceMove::Point(Point::new(9, 8)),
+            );
+            move_piece(
+                &mut shogi_game,
+                Point::new(1, 9),
+                PieceMove::Point(Point::new(1, 8)),
+            );
+            assert_eq!(
+                shogi_game.move_piece_at(&Point::new(9, 8), &PieceMove::Point(Point::new(9, 9))),
+                MoveResult::GameEnded(GameResult::PerpetualCheck(Color::White))
+            );
+        }
+
+        #[test]
+        fn side_without_moves_loses() {
+            let mut shogi_game = empty_game();
+            let board = &mut shogi_game.board;
+            board.add_piece("King", Color::White, vec![], vec![], Point::new(5, 1));
+            board.add_piece("Gold", Color::White, vec![], vec![], Point::new(5, 7));
+            board.add_piece("King", Color::Black, vec![], vec![], Point::new(5, 9));
+            board.add_to_pocket(&Color::White, "Gold");
+
+            assert_eq!(
+                shogi_game.drop_piece("Gold", &Point::new(5, 8)),
+                MoveResult::GameEnded(GameResult::Checkmate(Color::Black))
+            );
+        }
+    }
+}
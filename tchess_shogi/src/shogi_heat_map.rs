@@ -0,0 +1,24 @@
+use libtchess::heat_map::HeatMap;
+use libtchess::piece::Piece;
+use libtchess::point::Point;
+
+// Pieces are dropped anywhere in shogi, thus only their material value is taken into account
+#[derive(Clone)]
+pub struct ShogiHeatMap;
+
+impl ShogiHeatMap {
+    pub fn init() -> Self {
+        Self {}
+    }
+}
+
+impl HeatMap for ShogiHeatMap {
+    fn positional_value(&self, piece: &Piece, _position: &Point) -> i16 {
+        match piece {
+            Piece::Bishop(_) => 850,
+            Piece::Rook(_) => 1000,
+            Piece::Custom(p) => p.definition().value(),
+            _ => 0,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use libtchess::betza::BetzaPiece;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::piece_registry::PieceRegistry;
+
+// Pieces which promote, along with their promoted forms. The rook and the bishop are the
+// built-in ones.
+pub const PROMOTIONS: [(&str, &str); 6] = [
+    ("Pawn", "Tokin"),
+    ("Lance", "PromotedLance"),
+    ("Knight", "PromotedKnight"),
+    ("Silver", "PromotedSilver"),
+    ("Bishop", "DragonHorse"),
+    ("Rook", "DragonKing"),
+];
+
+// Unlike the chess pawn, captures straight forward
+pub fn pawn() -> BetzaPiece {
+    BetzaPiece::new("Pawn", ColoredProperty::from(['P', 'p']), 100, "fW")
+        .expect("Pawn notation must be valid!")
+}
+
+// Rides straight forward
+pub fn lance() -> BetzaPiece {
+    BetzaPiece::new("Lance", ColoredProperty::from(['L', 'l']), 350, "fR")
+        .expect("Lance notation must be valid!")
+}
+
+// Jumps to the two narrow forward points of the chess knight only
+pub fn knight() -> BetzaPiece {
+    BetzaPiece::new("Knight", ColoredProperty::from(['N', 'n']), 400, "ffN")
+        .expect("Knight notation must be valid!")
+}
+
+// Steps diagonally or straight forward
+pub fn silver() -> BetzaPiece {
+    BetzaPiece::new("Silver", ColoredProperty::from(['S', 's']), 500, "FfW")
+        .expect("Silver notation must be valid!")
+}
+
+// Steps orthogonally or diagonally forward. Promoted minor pieces move the same way.
+pub fn gold(name: &str, symbols: ColoredProperty<char>) -> BetzaPiece {
+    BetzaPiece::new(name, symbols, 550, "WfF").expect("Gold notation must be valid!")
+}
+
+// Promoted bishop, which can step orthogonally as well
+pub fn dragon_horse() -> BetzaPiece {
+    BetzaPiece::new("DragonHorse", ColoredProperty::from(['H', 'h']), 1150, "BW")
+        .expect("Dragon horse notation must be valid!")
+}
+
+// Promoted rook, which can step diagonally as well
+pub fn dragon_king() -> BetzaPiece {
+    BetzaPiece::new("DragonKing", ColoredProperty::from(['D', 'd']), 1300, "RF")
+        .expect("Dragon king notation must be valid!")
+}
+
+// The built-in king, rook and bishop along with the shogi pieces. The shogi pawn and knight
+// replace the chess ones.
+pub fn shogi_piece_registry() -> PieceRegistry {
+    let mut piece_registry = PieceRegistry::classic();
+    piece_registry.register(pawn());
+    piece_registry.register(lance());
+    piece_registry.register(knight());
+    piece_registry.register(silver());
+    piece_registry.register(gold("Gold", ColoredProperty::from(['G', 'g'])));
+    piece_registry.register(gold("Tokin", ColoredProperty::from(['T', 't'])));
+    piece_registry.register(gold("PromotedLance", ColoredProperty::from(['Y', 'y'])));
+    piece_registry.register(gold("PromotedKnight", ColoredProperty::from(['V', 'v'])));
+    piece_registry.register(gold("PromotedSilver", ColoredProperty::from(['E', 'e'])));
+    piece_registry.register(dragon_horse());
+    piece_registry.register(dragon_king());
+    piece_registry
+}
@@ -0,0 +1,21 @@
+use libtchess::board_square::BoardSquare;
+use libtchess::color::Color;
+use libtchess::point::Point;
+use libtchess::square::Square;
+use libtchess::squares_map::SquaresMap;
+
+// Squares of the shogi board are not colored
+#[derive(Clone)]
+pub struct ShogiSquaresMap;
+
+impl ShogiSquaresMap {
+    pub fn init() -> Self {
+        Self {}
+    }
+}
+
+impl SquaresMap for ShogiSquaresMap {
+    fn square(&self, _point: &Point) -> Option<BoardSquare> {
+        Some(BoardSquare::Square(Square::new(Color::White, None)))
+    }
+}
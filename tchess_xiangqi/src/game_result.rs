@@ -0,0 +1,11 @@
+use libtchess::color::Color;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GameResult {
+    // The given color has no moves. It loses even when it is not in check.
+    Checkmate(Color),
+    // The given color has repeated the position by checking all the time and loses
+    PerpetualCheck(Color),
+    // The same position has occurred three times without perpetual check
+    DrawByRepetition,
+}
@@ -0,0 +1,14 @@
+use crate::xiangqi_game::XiangqiGame;
+use libtchess::utils::pretty_print::PrettyPrint;
+
+mod game_result;
+mod move_result;
+mod xiangqi_game;
+mod xiangqi_heat_map;
+mod xiangqi_pieces;
+mod xiangqi_squares_map;
+
+fn main() {
+    let xiangqi_game = XiangqiGame::xiangqi_board();
+    println!("{}", xiangqi_game.board().pp());
+}
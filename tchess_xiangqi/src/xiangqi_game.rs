@@ -0,0 +1,458 @@
+use crate::game_result::GameResult;
+use crate::move_result::MoveResult;
+use crate::xiangqi_heat_map::XiangqiHeatMap;
+use crate::xiangqi_pieces::xiangqi_piece_registry;
+use crate::xiangqi_squares_map::XiangqiSquaresMap;
+use libtchess::board::Board;
+use libtchess::board_config::BoardConfig;
+use libtchess::board_positions::BoardPositions;
+use libtchess::board_region::BoardRegion;
+use libtchess::castle_x_points::{CastleXPoints, KingCastleXPoint, RookCastleXPoint};
+use libtchess::color::Color;
+use libtchess::dimension::Dimension;
+use libtchess::game_board::GameBoard;
+use libtchess::piece_id::PieceId;
+use libtchess::piece_move::PieceMove;
+use libtchess::player::Player;
+use libtchess::point::Point;
+use libtchess::promotion_zone::PromotionZone;
+
+const MAX_NUMBER_OF_EQUAL_POSITIONS: u8 = 3;
+
+// Back rank of the xiangqi, from the first to the ninth file
+pub const BACK_RANK: [&str; 9] = [
+    "Chariot", "Horse", "Elephant", "Advisor", "General", "Advisor", "Elephant", "Horse", "Chariot",
+];
+
+// Red is the white side, black is the black one
+pub struct XiangqiGame {
+    board_positions: BoardPositions,
+    board: Board<XiangqiHeatMap, XiangqiSquaresMap>,
+    game_result: Option<GameResult>,
+}
+
+impl XiangqiGame {
+    pub fn xiangqi_board() -> Self {
+        Self::with_config(Self::xiangqi_config())
+    }
+
+    pub fn xiangqi_config() -> BoardConfig<XiangqiHeatMap, XiangqiSquaresMap> {
+        let dimension = Dimension::new(Point::new(1, 1), Point::new(9, 10));
+        let mut config = BoardConfig::new(
+            CastleXPoints(KingCastleXPoint(7), RookCastleXPoint(6)),
+            CastleXPoints(KingCastleXPoint(3), RookCastleXPoint(4)),
+            XiangqiHeatMap::init(),
+            XiangqiSquaresMap::init(),
+            dimension,
+            Player::Human,
+            Player::Human,
+        );
+        config.set_castle_rules(vec![]);
+        config.set_piece_registry(xiangqi_piece_registry());
+        config.set_royal_pieces(&["General"]);
+        config.set_flying_general(true);
+        config.set_piece_region("General", BoardRegion::Palace);
+        config.set_piece_region("Advisor", BoardRegion::Palace);
+        config.set_piece_region("Elephant", BoardRegion::OwnHalf);
+        // The soldier gains the sideways step as soon as it crosses the river
+        config.set_promotion_zone(&Color::White, PromotionZone::Ranks(6..=10));
+        config.set_promotion_zone(&Color::Black, PromotionZone::Ranks(1..=5));
+        config.set_promotion("Soldier", "CrossedSoldier");
+        config.set_compulsory_promotions(&["Soldier"]);
+        config
+    }
+
+    // Places the back rank, the cannons and the soldiers for each side
+    pub fn with_config(config: BoardConfig<XiangqiHeatMap, XiangqiSquaresMap>) -> Self {
+        let mut board = Board::empty(config);
+
+        for (color, row, cannons_row, soldiers_row) in
+            [(Color::White, 1, 3, 4), (Color::Black, 10, 8, 7)]
+        {
+            for (x, name) in (1..).zip(BACK_RANK.iter()) {
+                board.add_piece(name, color, vec![], vec![], Point::new(x, row));
+            }
+            for x in [2, 8] {
+                board.add_piece("Cannon", color, vec![], vec![], Point::new(x, cannons_row));
+            }
+            for x in [1, 3, 5, 7, 9] {
+                board.add_piece(
+                    "Soldier",
+                    color,
+                    vec![],
+                    vec![],
+                    Point::new(x, soldiers_row),
+                );
+            }
+        }
+
+        let mut board_positions = BoardPositions::empty();
+        board_positions.persist_position(&board.stats().zposition.0, false);
+        Self {
+            board,
+            board_positions,
+            game_result: None,
+        }
+    }
+
+    pub fn move_piece_at(&mut self, position: &Point, piece_move: &PieceMove) -> MoveResult {
+        let &piece_id = self
+            .board
+            .piece_id_at(position)
+            .unwrap_or_else(|| panic!("Could not find piece at {} position", position));
+        self.move_piece(&piece_id, piece_move)
+    }
+
+    pub fn move_piece(&mut self, piece_id: &PieceId, piece_move: &PieceMove) -> MoveResult {
+        if let Some(game_result) = self.game_result {
+            return MoveResult::GameEnded(game_result);
+        }
+        if self.board.move_piece(piece_id, piece_move).is_none() {
+            return MoveResult::IllegalMove;
+        }
+
+        let is_check = self.board.is_checked(self.board.current_turn());
+        let occurrences_num = self
+            .board_positions
+            .persist_position(&self.board.stats().zposition.0, is_check);
+        self.calculate_game_result(occurrences_num);
+
+        match self.game_result {
+            Some(game_result) => MoveResult::GameEnded(game_result),
+            None => MoveResult::PieceMoved,
+        }
+    }
+
+    pub fn board(&self) -> &Board<XiangqiHeatMap, XiangqiSquaresMap> {
+        &self.board
+    }
+
+    pub fn game_result(&self) -> Option<&GameResult> {
+        self.game_result.as_ref()
+    }
+
+    fn calculate_game_result(&mut self, occurrences_num: u8) {
+        let current_turn = *self.board.current_turn();
+        if self.board.has_no_moves(&current_turn) {
+            self.game_result = Some(GameResult::Checkmate(current_turn));
+            return;
+        }
+        if occurrences_num < MAX_NUMBER_OF_EQUAL_POSITIONS {
+            return;
+        }
+        // The side, which has been checking all the time, loses instead of drawing
+        if self.board_positions.is_perpetual_check(0) {
+            self.game_result = Some(GameResult::PerpetualCheck(current_turn.inverse()));
+        } else if self.board_positions.is_perpetual_check(1) {
+            self.game_result = Some(GameResult::PerpetualCheck(current_turn));
+        } else {
+            self.game_result = Some(GameResult::DrawByRepetition);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libtchess::promote_piece::PromotePiece;
+    use libtchess::utils::pretty_print::PrettyPrint;
+
+    fn move_piece(xiangqi_game: &mut XiangqiGame, point: Point, piece_move: PieceMove) {
+        assert_eq!(
+            xiangqi_game.move_piece_at(&point, &piece_move),
+            MoveResult::PieceMoved
+        );
+        println!("{}", xiangqi_game.board().pp());
+    }
+
+    fn empty_game() -> XiangqiGame {
+        XiangqiGame {
+            board: Board::empty(XiangqiGame::xiangqi_config()),
+            board_positions: BoardPositions::empty(),
+            game_result: None,
+        }
+    }
+
+    // Legal destinations of the piece at the given point, sorted by files and then by ranks
+    fn destinations(xiangqi_game: &XiangqiGame, point: Point) -> Vec<Point> {
+        let board = xiangqi_game.board();
+        let piece_id = board.piece_id_at(&point).unwrap();
+        let mut points = board
+            .legal_moves(&piece_id.color())
+            .iter()
+            .filter(|(id, _)| id == piece_id)
+            .map(|(_, piece_move)| *piece_move.destination())
+            .collect::<Vec<_>>();
+        points.sort_by_key(|point| (*point.x().value(), *point.y().value()));
+        points
+    }
+
+    mod initial_position {
+        use super::*;
+
+        #[test]
+        fn has_forty_four_moves() {
+            let xiangqi_game = XiangqiGame::xiangqi_board();
+            println!("{}", xiangqi_game.board().pp());
+            assert_eq!(xiangqi_game.board().legal_moves(&Color::White).len(), 44);
+            assert_eq!(xiangqi_game.board().legal_moves(&Color::Black).len(), 44);
+        }
+    }
+
+    mod palace {
+        use super::*;
+
+        #[test]
+        fn general_and_advisors_do_not_leave_it() {
+            let mut xiangqi_game = empty_game();
+            let board = &mut xiangqi_game.board;
+            board.add_piece("General", Color::White, vec![], vec![], Point::new(4, 1));
+            board.add_piece("Advisor", Color::White, vec![], vec![], Point::new(6, 3));
+            board.add_piece("General", Color::Black, vec![], vec![], Point::new(6, 10));
+
+            assert_eq!(
+                destinations(&xiangqi_game, Point::new(4, 1)),
+                vec![Point::new(4, 2), Point::new(5, 1)]
+            );
+            assert_eq!(
+                destinations(&xiangqi_game, Point::new(6, 3)),
+                vec![Point::new(5, 2)]
+            );
+        }
+    }
+
+    mod elephant {
+        use super::*;
+
+        #[test]
+        fn it_is_blocked_at_the_eye_and_at_the_river() {
+            let mut xiangqi_game = empty_game();
+            let board = &mut xiangqi_game.board;
+            board.add_piece("General", Color::White, vec![], vec![], Point::new(5, 1));
+            board.add_piece("Elephant", Color::White, vec![], vec![], Point::new(3, 5));
+            board.add_piece("Soldier", Color::White, vec![], vec![], Point::new(2, 4));
+            board.add_piece("General", Color::Black, vec![], vec![], Point::new(4, 10));
+
+            assert_eq!(
+                destinations(&xiangqi_game, Point::new(3, 5)),
+                vec![Point::new(5, 3)]
+            );
+        }
+    }
+
+    mod horse {
+        use super::*;
+
+        #[test]
+        fn it_is_blocked_by_the_adjacent_piece() {
+            let mut xiangqi_game = empty_game();
+            let board = &mut xiangqi_game.board;
+            board.add_piece("General", Color::White, vec![], vec![], Point::new(5, 1));
+            board.add_piece("Horse", Color::White, vec![], vec![], Point::new(5, 5));
+            board.add_piece("Soldier", Color::White, vec![], vec![], Point::new(5, 6));
+            board.add_piece("Soldier", Color::Black, vec![], vec![], Point::new(4, 5));
+            board.add_piece("General", Color::Black, vec![], vec![], Point::new(4, 10));
+
+            assert_eq!(
+                destinations(&xiangqi_game, Point::new(5, 5)),
+                vec![
+                    Point::new(4, 3),
+                    Point::new(6, 3),
+                    Point::new(7, 4),
+                    Point::new(7, 6),
+                ]
+            );
+        }
+    }
+
+    mod cannon {
+        use super::*;
+
+        #[test]
+        fn it_captures_over_exactly_one_screen() {
+            let mut xiangqi_game = empty_game();
+            let board = &mut xiangqi_game.board;
+            board.add_piece("General", Color::White, vec![], vec![], Point::new(4, 1));
+            board.add_piece("Cannon", Color::White, vec![], vec![], Point::new(5, 3));
+            board.add_piece("Soldier", Color::Black, vec![], vec![], Point::new(5, 5));
+            board.add_piece("Horse", Color::Black, vec![], vec![], Point::new(5, 8));
+            board.add_piece("Chariot", Color::Black, vec![], vec![], Point::new(7, 3));
+            board.add_piece("General", Color::Black, vec![], vec![], Point::new(6, 10));
+
+            println!("{}", xiangqi_game.board().pp());
+            let points = destinations(&xiangqi_game, Point::new(5, 3));
+            // The soldier is the screen, the horse behind it is captured
+            assert!(points.contains(&Point::new(5, 8)));
+            assert!(!points.contains(&Point::new(5, 5)));
+            assert!(!points.contains(&Point::new(5, 6)));
+            // There is no screen in front of the chariot
+            assert!(points.contains(&Point::new(6, 3)));
+            assert!(!points.contains(&Point::new(7, 3)));
+        }
+
+        #[test]
+        fn it_checks_over_the_screen() {
+            let mut xiangqi_game = empty_game();
+            let board = &mut xiangqi_game.board;
+            board.add_piece("General", Color::White, vec![], vec![], Point::new(4, 1));
+            board.add_piece("Cannon", Color::White, vec![], vec![], Point::new(1, 9));
+            board.add_piece("Horse", Color::White, vec![], vec![], Point::new(2, 10));
+            board.add_piece("General", Color::Black, vec![], vec![], Point::new(5, 10));
+
+            move_piece(
+                &mut xiangqi_game,
+                Point::new(1, 9),
+                PieceMove::Point(Point::new(1, 10)),
+            );
+            assert!(xiangqi_game.board().is_checked(&Color::Black));
+            // The general can't stay on the rank behind the screen
+            assert_eq!(
+                destinations(&xiangqi_game, Point::new(5, 10)),
+                vec![Point::new(5, 9)]
+            );
+        }
+    }
+
+    mod flying_general {
+        use super::*;
+
+        #[test]
+        fn piece_between_the_generals_is_pinned() {
+            let mut xiangqi_game = empty_game();
+            let board = &mut xiangqi_game.board;
+            board.add_piece("General", Color::White, vec![], vec![], Point::new(5, 1));
+            board.add_piece("Horse", Color::White, vec![], vec![], Point::new(5, 5));
+            board.add_piece("General", Color::Black, vec![], vec![], Point::new(5, 10));
+
+            // Any move of the horse leaves the file
+            assert!(destinations(&xiangqi_game, Point::new(5, 5)).is_empty());
+            assert!(!xiangqi_game.board().is_checked(&Color::White));
+        }
+
+        #[test]
+        fn general_does_not_step_onto_the_open_file() {
+            let mut xiangqi_game = empty_game();
+            let board = &mut xiangqi_game.board;
+            board.add_piece("General", Color::White, vec![], vec![], Point::new(4, 1));
+            board.add_piece("General", Color::Black, vec![], vec![], Point::new(5, 10));
+
+            assert_eq!(
+                destinations(&xiangqi_game, Point::new(4, 1)),
+                vec![Point::new(4, 2)]
+            );
+        }
+    }
+
+    mod soldier {
+        use super::*;
+
+        #[test]
+        fn it_steps_sideways_after_crossing_the_river() {
+            let mut xiangqi_game = empty_game();
+            let board = &mut xiangqi_game.board;
+            board.add_piece("General", Color::White, vec![], vec![], Point::new(5, 1));
+            board.add_piece("Soldier", Color::White, vec![], vec![], Point::new(3, 5));
+            board.add_piece("General", Color::Black, vec![], vec![], Point::new(4, 10));
+
+            let soldier_id = *xiangqi_game.board().piece_id_at(&Point::new(3, 5)).unwrap();
+            assert_eq!(
+                xiangqi_game
+                    .board()
+                    .moves_of(&soldier_id)
+                    .unwrap()
+                    .keys()
+                    .collect::<Vec<_>>(),
+                vec![&PieceMove::Promote(
+                    Point::new(3, 6),
+                    PromotePiece::Promoted
+                )]
+            );
+            move_piece(
+                &mut xiangqi_game,
+                Point::new(3, 5),
+                PieceMove::Promote(Point::new(3, 6), PromotePiece::Promoted),
+            );
+            move_piece(
+                &mut xiangqi_game,
+                Point::new(4, 10),
+                PieceMove::Point(Point::new(4, 9)),
+            );
+            assert_eq!(
+                destinations(&xiangqi_game, Point::new(3, 6)),
+                vec![Point::new(2, 6), Point::new(3, 7), Point::new(4, 6)]
+            );
+        }
+    }
+
+    mod game_result {
+        use super::*;
+
+        #[test]
+        fn side_without_moves_loses() {
+            let mut xiangqi_game = empty_game();
+            let board = &mut xiangqi_game.board;
+            board.add_piece("General", Color::White, vec![], vec![], Point::new(4, 1));
+            board.add_piece("Chariot", Color::White, vec![], vec![], Point::new(1, 9));
+            board.add_piece("Chariot", Color::White, vec![], vec![], Point::new(9, 2));
+            board.add_piece("General", Color::Black, vec![], vec![], Point::new(5, 10));
+
+            assert_eq!(
+                xiangqi_game.move_piece_at(&Point::new(9, 2), &PieceMove::Point(Point::new(9, 10))),
+                MoveResult::GameEnded(GameResult::Checkmate(Color::Black))
+            );
+        }
+
+        #[test]
+        fn perpetual_check_loses() {
+            let mut xiangqi_game = empty_game();
+            let board = &mut xiangqi_game.board;
+            board.add_piece("General", Color::White, vec![], vec![], Point::new(4, 1));
+            board.add_piece("Chariot", Color::White, vec![], vec![], Point::new(1, 8));
+            board.add_piece("General", Color::Black, vec![], vec![], Point::new(5, 9));
+            xiangqi_game
+                .board_positions
+                .persist_position(&xiangqi_game.board.stats().zposition.0, false);
+
+            // The chariot checks along the ranks, while the general steps up and down
+            let cycle = [
+                (Point::new(1, 8), Point::new(1, 9)),
+                (Point::new(5, 9), Point::new(5, 8)),
+                (Point::new(1, 9), Point::new(1, 8)),
+                (Point::new(5, 8), Point::new(5, 9)),
+            ];
+            for (from, to) in cycle.iter().chain(cycle[..3].iter()) {
+                move_piece(&mut xiangqi_game, *from, PieceMove::Point(*to));
+            }
+            assert_eq!(
+                xiangqi_game.move_piece_at(&Point::new(5, 8), &PieceMove::Point(Point::new(5, 9))),
+                MoveResult::GameEnded(GameResult::PerpetualCheck(Color::White))
+            );
+        }
+
+        #[test]
+        fn repetition_without_checks_is_a_draw() {
+            let mut xiangqi_game = empty_game();
+            let board = &mut xiangqi_game.board;
+            board.add_piece("General", Color::White, vec![], vec![], Point::new(4, 1));
+            board.add_piece("Chariot", Color::White, vec![], vec![], Point::new(1, 1));
+            board.add_piece("General", Color::Black, vec![], vec![], Point::new(5, 10));
+            xiangqi_game
+                .board_positions
+                .persist_position(&xiangqi_game.board.stats().zposition.0, false);
+
+            let cycle = [
+                (Point::new(1, 1), Point::new(1, 2)),
+                (Point::new(5, 10), Point::new(5, 9)),
+                (Point::new(1, 2), Point::new(1, 1)),
+                (Point::new(5, 9), Point::new(5, 10)),
+            ];
+            for (from, to) in cycle.iter().chain(cycle[..3].iter()) {
+                move_piece(&mut xiangqi_game, *from, PieceMove::Point(*to));
+            }
+            assert_eq!(
+                xiangqi_game.move_piece_at(&Point::new(5, 9), &PieceMove::Point(Point::new(5, 10))),
+                MoveResult::GameEnded(GameResult::DrawByRepetition)
+            );
+        }
+    }
+}
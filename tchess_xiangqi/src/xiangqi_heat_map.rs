@@ -0,0 +1,22 @@
+use libtchess::heat_map::HeatMap;
+use libtchess::piece::Piece;
+use libtchess::point::Point;
+
+// Only material value of the pieces is taken into account
+#[derive(Clone)]
+pub struct XiangqiHeatMap;
+
+impl XiangqiHeatMap {
+    pub fn init() -> Self {
+        Self {}
+    }
+}
+
+impl HeatMap for XiangqiHeatMap {
+    fn positional_value(&self, piece: &Piece, _position: &Point) -> i16 {
+        match piece {
+            Piece::Custom(p) => p.definition().value(),
+            _ => 0,
+        }
+    }
+}
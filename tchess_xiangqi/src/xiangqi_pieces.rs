@@ -0,0 +1,63 @@
+use libtchess::betza::BetzaPiece;
+use libtchess::colored_property::ColoredProperty;
+use libtchess::piece_registry::PieceRegistry;
+
+// Royal piece, which steps orthogonally within the palace
+pub fn general() -> BetzaPiece {
+    BetzaPiece::new("General", ColoredProperty::from(['K', 'k']), 0, "W")
+        .expect("General notation must be valid!")
+}
+
+// Steps diagonally within the palace
+pub fn advisor() -> BetzaPiece {
+    BetzaPiece::new("Advisor", ColoredProperty::from(['A', 'a']), 200, "F")
+        .expect("Advisor notation must be valid!")
+}
+
+// Leaps two points diagonally unless the point in between is occupied. It can't cross the river.
+pub fn elephant() -> BetzaPiece {
+    BetzaPiece::new("Elephant", ColoredProperty::from(['E', 'e']), 200, "nA")
+        .expect("Elephant notation must be valid!")
+}
+
+// Leaps like the knight unless the orthogonally adjacent point on the way is occupied
+pub fn horse() -> BetzaPiece {
+    BetzaPiece::new("Horse", ColoredProperty::from(['H', 'h']), 400, "nN")
+        .expect("Horse notation must be valid!")
+}
+
+pub fn chariot() -> BetzaPiece {
+    BetzaPiece::new("Chariot", ColoredProperty::from(['R', 'r']), 900, "R")
+        .expect("Chariot notation must be valid!")
+}
+
+// Moves like the chariot, but captures by jumping over exactly one piece
+pub fn cannon() -> BetzaPiece {
+    BetzaPiece::new("Cannon", ColoredProperty::from(['C', 'c']), 450, "mRcpR")
+        .expect("Cannon notation must be valid!")
+}
+
+pub fn soldier() -> BetzaPiece {
+    BetzaPiece::new("Soldier", ColoredProperty::from(['P', 'p']), 100, "fW")
+        .expect("Soldier notation must be valid!")
+}
+
+// The soldier, which has crossed the river, steps sideways as well
+pub fn crossed_soldier() -> BetzaPiece {
+    BetzaPiece::new("CrossedSoldier", ColoredProperty::from(['P', 'p']), 200, "fsW")
+        .expect("Crossed soldier notation must be valid!")
+}
+
+// Xiangqi has none of the chess pieces
+pub fn xiangqi_piece_registry() -> PieceRegistry {
+    let mut piece_registry = PieceRegistry::empty();
+    piece_registry.register(general());
+    piece_registry.register(advisor());
+    piece_registry.register(elephant());
+    piece_registry.register(horse());
+    piece_registry.register(chariot());
+    piece_registry.register(cannon());
+    piece_registry.register(soldier());
+    piece_registry.register(crossed_soldier());
+    piece_registry
+}
@@ -0,0 +1,33 @@
+use libtchess::board_region::BoardRegion;
+use libtchess::board_square::BoardSquare;
+use libtchess::color::Color;
+use libtchess::point::Point;
+use libtchess::square::Square;
+use libtchess::squares_map::SquaresMap;
+
+// Pieces stand on the intersections of the 9x10 board, thus the squares are not colored. The river
+// runs between the fifth and the sixth ranks.
+#[derive(Clone)]
+pub struct XiangqiSquaresMap;
+
+impl XiangqiSquaresMap {
+    pub fn init() -> Self {
+        Self {}
+    }
+}
+
+impl SquaresMap for XiangqiSquaresMap {
+    fn square(&self, _point: &Point) -> Option<BoardSquare> {
+        Some(BoardSquare::Square(Square::new(Color::White, None)))
+    }
+
+    fn is_in_region(&self, point: &Point, region: &BoardRegion, color: &Color) -> bool {
+        let (x, y) = point.to_tuple();
+        match (region, *color == Color::White) {
+            (BoardRegion::Palace, true) => (4..=6).contains(x) && (1..=3).contains(y),
+            (BoardRegion::Palace, false) => (4..=6).contains(x) && (8..=10).contains(y),
+            (BoardRegion::OwnHalf, true) => (1..=5).contains(y),
+            (BoardRegion::OwnHalf, false) => (6..=10).contains(y),
+        }
+    }
+}